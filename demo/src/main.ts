@@ -1,5 +1,5 @@
 import "./style.css";
-import init, { format_sql as format } from "sql-lint";
+import init, { format_sql as format, format_sql_range as formatRange } from "sql-lint";
 
 const DEFAULT_QUERY = `SELECT f.species_name,
 AVG(f.height) AS average_height, AVG(f.diameter) AS average_diameter
@@ -28,7 +28,14 @@ async function main() {
     url.searchParams.set("q", editor.value);
     history.pushState({}, editor.value, url);
     try {
-      editor.value = format(editor.value);
+      const { selectionStart, selectionEnd } = editor;
+      if (selectionStart === selectionEnd) {
+        editor.value = format(editor.value);
+      } else {
+        for (const edit of formatRange(editor.value, selectionStart, selectionEnd).reverse()) {
+          editor.setRangeText(edit.text, edit.start, edit.end);
+        }
+      }
     } catch (err) {
       error.innerHTML = err.toString();
     }
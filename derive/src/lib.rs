@@ -0,0 +1,119 @@
+//! `#[derive(Node)]` for the AST of sql-lint.
+//!
+//! The derive implements `sql_lint::visitor::Node`: `accept` calls the `visit_<type>`
+//! method of the visitor and `walk` accepts every field, so a new node only needs the
+//! derive and a line in the `visitor!` list.
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use quote::quote;
+use syn::parse_macro_input;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+use syn::Ident;
+use syn::Index;
+
+#[proc_macro_derive(Node)]
+pub fn derive_node(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let snake = snake_case(&name.to_string());
+    let visit = format_ident!("visit_{}", snake);
+    let visit_mut = format_ident!("visit_{}_mut", snake);
+    let walk = walk_body(&input.data, false);
+    let walk_mut = walk_body(&input.data, true);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics crate::visitor::Node for #name #ty_generics #where_clause {
+            fn accept<V: crate::visitor::Visit + ?Sized>(&self, visitor: &mut V) {
+                visitor.#visit(self)
+            }
+
+            fn accept_mut<V: crate::visitor::VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+                visitor.#visit_mut(self)
+            }
+
+            #[allow(unused_variables)]
+            fn walk<V: crate::visitor::Visit + ?Sized>(&self, visitor: &mut V) {
+                #walk
+            }
+
+            #[allow(unused_variables)]
+            fn walk_mut<V: crate::visitor::VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+                #walk_mut
+            }
+        }
+    }
+    .into()
+}
+
+fn accept(field: TokenStream2, mutable: bool) -> TokenStream2 {
+    match mutable {
+        true => quote! { crate::visitor::Node::accept_mut(#field, visitor); },
+        false => quote! { crate::visitor::Node::accept(#field, visitor); },
+    }
+}
+
+fn walk_body(data: &Data, mutable: bool) -> TokenStream2 {
+    let reference = match mutable {
+        true => quote! { &mut },
+        false => quote! { & },
+    };
+    match data {
+        Data::Struct(s) => {
+            let fields = s.fields.iter().enumerate().map(|(i, f)| {
+                let member = match &f.ident {
+                    Some(ident) => quote! { #ident },
+                    None => {
+                        let index = Index::from(i);
+                        quote! { #index }
+                    }
+                };
+                accept(quote! { #reference self.#member }, mutable)
+            });
+            quote! { #(#fields)* }
+        }
+        Data::Enum(e) => {
+            let arms = e.variants.iter().map(|v| {
+                let variant = &v.ident;
+                let bindings: Vec<Ident> = (0..v.fields.len())
+                    .map(|i| Ident::new(&format!("field{}", i), Span::call_site()))
+                    .collect();
+                let accepts = bindings.iter().map(|b| accept(quote! { #b }, mutable));
+                let pattern = match &v.fields {
+                    Fields::Named(named) => {
+                        let names = named.named.iter().map(|f| &f.ident);
+                        quote! { Self::#variant { #(#names: #bindings),* } }
+                    }
+                    Fields::Unnamed(_) => quote! { Self::#variant(#(#bindings),*) },
+                    Fields::Unit => quote! { Self::#variant },
+                };
+                quote! { #pattern => { #(#accepts)* } }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => panic!("#[derive(Node)] does not support unions"),
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
@@ -0,0 +1,10 @@
+create extension if not exists pgcrypto with schema public;
+create schema if not exists staging authorization admin;
+comment on table movies is 'All the movies';
+comment on column public.movies.title is 'Original title';
+truncate table movies, ratings restart identity cascade;
+alter table movies rename to films;
+alter sequence movie_ids owner to admin;
+create domain postcode as varchar(5) default '00000' constraint valid_postcode check (length(value) = 5) not null;
+drop extension if exists pgcrypto;
+drop schema staging cascade;
@@ -0,0 +1,9 @@
+create or replace function add_numbers(a integer, b integer default 0) returns integer language sql immutable as $$ select a from movies where id = b; $$;
+create function touch() returns trigger language plpgsql as $body$
+BEGIN
+  NEW.updated_at := now();
+  RETURN NEW;
+END;
+$body$;
+create procedure archive(in days integer) language sql as $$ delete from movies where age > days $$;
+create trigger touch_movies before insert or update of title on movies for each row when (new.title is not null) execute function touch();
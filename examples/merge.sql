@@ -0,0 +1 @@
+merge into customers as c using new_customers n on c.id = n.id and c.region = n.region when matched and n.deleted = true then delete when matched then update set name = n.name, email = n.email when not matched then insert (id, name) values (n.id, n.name);
@@ -0,0 +1,6 @@
+create role reader with login password 'secret';
+grant select, insert on table movies to reader with grant option;
+grant usage on all sequences in schema public to public;
+revoke all on movies from reader cascade;
+alter default privileges in schema public grant select on tables to reader;
+drop role if exists reader;
@@ -0,0 +1,5 @@
+SELECT m.title, m.release_year FROM "Movies" AS m WHERE m.title = 'Matrix';
+
+CREATE TYPE mood AS ENUM ('sad', 'happy');
+
+CREATE SEQUENCE stars START WITH 1;
@@ -0,0 +1,4 @@
+(select title, year from movies where year > 2000 order by year limit 10)
+union
+(select title, year from series where year > 2000 union select title, year from shorts where year > 2010)
+order by title;
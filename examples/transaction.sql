@@ -0,0 +1,4 @@
+BEGIN;
+SET LOCAL search_path TO public;
+UPDATE t SET a = 1;
+COMMIT;
@@ -0,0 +1,7 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use sql_lint::format;
+
+fuzz_target!(|data: &str| {
+    let _ = format(data);
+});
@@ -0,0 +1,7 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use sql_lint::query::parse_statements;
+
+fuzz_target!(|data: &str| {
+    let _ = parse_statements(data);
+});
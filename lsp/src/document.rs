@@ -0,0 +1,65 @@
+use lsp_types::Position;
+use lsp_types::Range;
+use sql_lint::range::statement_spans;
+use sql_lint::range::StatementSpan;
+
+pub struct Document {
+    pub text: String,
+}
+
+impl Document {
+    pub fn new(text: String) -> Self {
+        Document { text }
+    }
+
+    // Positions are in UTF-16 code units, the default encoding of the protocol.
+    pub fn position(&self, offset: usize) -> Position {
+        let before = &self.text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Position::new(
+            before.matches('\n').count() as u32,
+            before[line_start..].encode_utf16().count() as u32,
+        )
+    }
+
+    pub fn offset(&self, position: Position) -> usize {
+        let mut offset = 0;
+        for (i, line) in self.text.split_inclusive('\n').enumerate() {
+            if i == position.line as usize {
+                let mut units = 0;
+                for (pos, c) in line.char_indices() {
+                    if units >= position.character as usize || c == '\n' {
+                        return offset + pos;
+                    }
+                    units += c.len_utf16();
+                }
+                return offset + line.len();
+            }
+            offset += line.len();
+        }
+        self.text.len()
+    }
+
+    pub fn range(&self, start: usize, end: usize) -> Range {
+        Range::new(self.position(start), self.position(end))
+    }
+
+    pub fn word_at(&self, offset: usize) -> Option<(usize, usize)> {
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        let start = self.text[..offset]
+            .char_indices()
+            .rfind(|(_, c)| !is_word(*c))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        let end = self.text[offset..]
+            .find(|c: char| !is_word(c))
+            .map_or(self.text.len(), |i| offset + i);
+        match start < end {
+            true => Some((start, end)),
+            false => None,
+        }
+    }
+
+    pub fn statements(&self) -> Vec<StatementSpan> {
+        statement_spans(&self.text)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::document::Document;
+use lsp_types::Position;
+
+#[test]
+fn test_position() {
+    let document = Document::new("SELECT 'é😀';\nSELECT id;".to_string());
+    assert_eq!(document.position(0), Position::new(0, 0));
+    assert_eq!(document.position(14), Position::new(0, 11));
+    assert_eq!(document.position(17), Position::new(1, 0));
+    assert_eq!(document.position(document.text.len()), Position::new(1, 10));
+}
+
+#[test]
+fn test_offset() {
+    let document = Document::new("SELECT 'é😀';\nSELECT id;".to_string());
+    assert_eq!(document.offset(Position::new(0, 11)), 14);
+    assert_eq!(document.offset(Position::new(0, 42)), 16);
+    assert_eq!(document.offset(Position::new(1, 7)), 24);
+    assert_eq!(document.offset(Position::new(5, 0)), document.text.len());
+}
+
+#[test]
+fn test_word_at() {
+    let document = Document::new("SELECT title_fr FROM movies ;".to_string());
+    assert_eq!(document.word_at(9), Some((7, 15)));
+    assert_eq!(document.word_at(7), Some((7, 15)));
+    assert_eq!(document.word_at(28), None);
+}
+
+#[test]
+fn test_statements() {
+    let document = Document::new(
+        "SELECT id FROM movies ;\n\nCREATE VIEW v AS x;\n UPDATE movies SET year = 1;\n"
+            .to_string(),
+    );
+    let spans: Vec<(&str, bool)> = document
+        .statements()
+        .iter()
+        .map(|s| (&document.text[s.start..s.end], s.query.is_some()))
+        .collect();
+    assert_eq!(
+        spans,
+        vec!(
+            ("SELECT id FROM movies ;", true),
+            ("CREATE VIEW v AS x;", false),
+            ("UPDATE movies SET year = 1;", true)
+        )
+    );
+}
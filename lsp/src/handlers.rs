@@ -0,0 +1,125 @@
+use crate::document::Document;
+use lsp_types::Diagnostic;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::DocumentSymbol;
+use lsp_types::Hover;
+use lsp_types::HoverContents;
+use lsp_types::MarkupContent;
+use lsp_types::MarkupKind;
+use lsp_types::NumberOrString;
+use lsp_types::Position;
+use lsp_types::Range;
+use lsp_types::SymbolKind;
+use lsp_types::TextEdit;
+use sql_lint::formatter::Format;
+use sql_lint::keyword::Keyword;
+use sql_lint::query::Query;
+use sql_lint::table::Table;
+use sql_lint::FormatOptions;
+use std::str::FromStr;
+
+const SOURCE: &str = "sql-lint";
+
+fn format_query(query: &Query) -> String {
+    format!("{};", query.output())
+}
+
+pub fn formatting(document: &Document) -> Vec<TextEdit> {
+    let formatted = match sql_lint::format(&document.text) {
+        Ok(f) => f,
+        Err(_) => return vec![],
+    };
+    let trimmed = document.text.trim_end();
+    match formatted == trimmed {
+        true => vec![],
+        false => vec![TextEdit::new(document.range(0, trimmed.len()), formatted)],
+    }
+}
+
+// Only the statements overlapping the range are formatted.
+pub fn range_formatting(document: &Document, range: Range) -> Vec<TextEdit> {
+    let range = document.offset(range.start)..document.offset(range.end);
+    match sql_lint::format_range(&document.text, range, FormatOptions::default()) {
+        Ok(edits) => edits
+            .into_iter()
+            .map(|e| TextEdit::new(document.range(e.start, e.end), e.text))
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+pub fn diagnostics(document: &Document) -> Vec<Diagnostic> {
+    document
+        .statements()
+        .into_iter()
+        .filter_map(|s| {
+            let (severity, code, message) = match &s.query {
+                None => (
+                    DiagnosticSeverity::ERROR,
+                    "parse-error",
+                    "Unable to parse SQL statement",
+                ),
+                Some(q) if format_query(q) != document.text[s.start..s.end] => (
+                    DiagnosticSeverity::INFORMATION,
+                    "unformatted",
+                    "Statement does not follow the SQL style guide",
+                ),
+                Some(_) => return None,
+            };
+            Some(Diagnostic {
+                range: document.range(s.start, s.end),
+                severity: Some(severity),
+                code: Some(NumberOrString::String(code.to_string())),
+                source: Some(SOURCE.to_string()),
+                message: message.to_string(),
+                ..Diagnostic::default()
+            })
+        })
+        .collect()
+}
+
+pub fn hover(document: &Document, position: Position) -> Option<Hover> {
+    let (start, end) = document.word_at(document.offset(position))?;
+    let keyword = Keyword::from_str(&document.text[start..end].to_lowercase()).ok()?;
+    let value = match keyword.is_reserved() {
+        true => format!("`{}` reserved keyword", keyword),
+        false => format!(
+            "`{}` non-reserved keyword, it can be used as an identifier",
+            keyword
+        ),
+    };
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(document.range(start, end)),
+    })
+}
+
+// Objects created by the document, views are not parsed yet.
+pub fn document_symbols(document: &Document) -> Vec<DocumentSymbol> {
+    document
+        .statements()
+        .into_iter()
+        .filter_map(|s| {
+            let (name, kind, detail) = match s.query? {
+                Query::Table(Table::Create(c)) => (c.2.to_string(), SymbolKind::STRUCT, "TABLE"),
+                Query::Sequence(c) => (c.0.to_string(), SymbolKind::NUMBER, "SEQUENCE"),
+                _ => return None,
+            };
+            let range = document.range(s.start, s.end);
+            #[allow(deprecated)]
+            Some(DocumentSymbol {
+                name,
+                detail: Some(detail.to_string()),
+                kind,
+                tags: None,
+                deprecated: None,
+                range,
+                selection_range: range,
+                children: None,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,198 @@
+//! Language server of sql-lint.
+//!
+//! Documents are synchronized in full, formatted on `textDocument/formatting` and
+//! `textDocument/rangeFormatting`, and diagnosed every time they change.
+use crate::document::Document;
+use lsp_server::Connection;
+use lsp_server::ErrorCode;
+use lsp_server::Message;
+use lsp_server::Notification;
+use lsp_server::Request;
+use lsp_server::Response;
+use lsp_types::notification::DidChangeTextDocument;
+use lsp_types::notification::DidCloseTextDocument;
+use lsp_types::notification::DidOpenTextDocument;
+use lsp_types::notification::Notification as _;
+use lsp_types::notification::PublishDiagnostics;
+use lsp_types::request::DocumentSymbolRequest;
+use lsp_types::request::Formatting;
+use lsp_types::request::HoverRequest;
+use lsp_types::request::RangeFormatting;
+use lsp_types::request::Request as _;
+use lsp_types::DocumentSymbolResponse;
+use lsp_types::HoverProviderCapability;
+use lsp_types::OneOf;
+use lsp_types::PublishDiagnosticsParams;
+use lsp_types::ServerCapabilities;
+use lsp_types::TextDocumentSyncCapability;
+use lsp_types::TextDocumentSyncKind;
+use lsp_types::Url;
+use std::collections::HashMap;
+use std::error::Error;
+
+pub mod document;
+pub mod handlers;
+
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        document_range_formatting_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        ..ServerCapabilities::default()
+    }
+}
+
+pub fn run(connection: &Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
+    connection.initialize(serde_json::to_value(capabilities())?)?;
+    let mut server = Server::default();
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    return Ok(());
+                }
+                connection
+                    .sender
+                    .send(Message::Response(server.request(request)))?;
+            }
+            Message::Notification(notification) => {
+                if let Some(n) = server.notification(notification) {
+                    connection.sender.send(Message::Notification(n))?;
+                }
+            }
+            Message::Response(_) => (),
+        }
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct Server {
+    documents: HashMap<Url, Document>,
+}
+
+impl Server {
+    fn request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            Formatting::METHOD => {
+                self.handle::<Formatting>(request, |d, _| Some(handlers::formatting(d)))
+            }
+            RangeFormatting::METHOD => self.handle::<RangeFormatting>(request, |d, p| {
+                Some(handlers::range_formatting(d, p.range))
+            }),
+            HoverRequest::METHOD => self.handle::<HoverRequest>(request, |d, p| {
+                handlers::hover(d, p.text_document_position_params.position)
+            }),
+            DocumentSymbolRequest::METHOD => {
+                self.handle::<DocumentSymbolRequest>(request, |d, _| {
+                    Some(DocumentSymbolResponse::Nested(handlers::document_symbols(
+                        d,
+                    )))
+                })
+            }
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported method '{}'", request.method),
+            ),
+        }
+    }
+
+    fn handle<R>(&self, request: Request, f: impl Fn(&Document, R::Params) -> R::Result) -> Response
+    where
+        R: lsp_types::request::Request,
+        R::Params: DocumentParams,
+    {
+        let params: R::Params = match serde_json::from_value(request.params) {
+            Ok(p) => p,
+            Err(e) => {
+                return Response::new_err(
+                    request.id,
+                    ErrorCode::InvalidParams as i32,
+                    e.to_string(),
+                )
+            }
+        };
+        match self.documents.get(params.uri()) {
+            Some(document) => Response::new_ok(request.id, f(document, params)),
+            None => Response::new_err(
+                request.id,
+                ErrorCode::InvalidParams as i32,
+                format!("Unknown document '{}'", params.uri()),
+            ),
+        }
+    }
+
+    fn notification(&mut self, notification: Notification) -> Option<Notification> {
+        let uri = match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: <DidOpenTextDocument as lsp_types::notification::Notification>::Params =
+                    serde_json::from_value(notification.params).ok()?;
+                let uri = params.text_document.uri;
+                self.documents
+                    .insert(uri.clone(), Document::new(params.text_document.text));
+                uri
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: <DidChangeTextDocument as lsp_types::notification::Notification>::Params =
+                    serde_json::from_value(notification.params).ok()?;
+                let uri = params.text_document.uri;
+                let text = params.content_changes.into_iter().last()?.text;
+                self.documents.insert(uri.clone(), Document::new(text));
+                uri
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: <DidCloseTextDocument as lsp_types::notification::Notification>::Params =
+                    serde_json::from_value(notification.params).ok()?;
+                self.documents.remove(&params.text_document.uri);
+                return Some(publish_diagnostics(params.text_document.uri, vec![]));
+            }
+            _ => return None,
+        };
+        let diagnostics = handlers::diagnostics(&self.documents[&uri]);
+        Some(publish_diagnostics(uri, diagnostics))
+    }
+}
+
+fn publish_diagnostics(uri: Url, diagnostics: Vec<lsp_types::Diagnostic>) -> Notification {
+    Notification::new(
+        PublishDiagnostics::METHOD.to_string(),
+        PublishDiagnosticsParams::new(uri, diagnostics, None),
+    )
+}
+
+// Parameters of the requests targeting a document.
+trait DocumentParams: serde::de::DeserializeOwned {
+    fn uri(&self) -> &Url;
+}
+
+impl DocumentParams for lsp_types::DocumentFormattingParams {
+    fn uri(&self) -> &Url {
+        &self.text_document.uri
+    }
+}
+
+impl DocumentParams for lsp_types::DocumentRangeFormattingParams {
+    fn uri(&self) -> &Url {
+        &self.text_document.uri
+    }
+}
+
+impl DocumentParams for lsp_types::HoverParams {
+    fn uri(&self) -> &Url {
+        &self.text_document_position_params.text_document.uri
+    }
+}
+
+impl DocumentParams for lsp_types::DocumentSymbolParams {
+    fn uri(&self) -> &Url {
+        &self.text_document.uri
+    }
+}
+
+#[cfg(test)]
+mod document_tests;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,155 @@
+use crate::document::Document;
+use crate::handlers;
+use lsp_server::Connection;
+use lsp_server::Message;
+use lsp_server::Notification;
+use lsp_server::Request;
+use lsp_server::RequestId;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::HoverContents;
+use lsp_types::Position;
+use lsp_types::Range;
+use lsp_types::SymbolKind;
+use serde_json::json;
+
+#[test]
+fn test_formatting() {
+    let document = Document::new("select id from movies;\n".to_string());
+    let edits = handlers::formatting(&document);
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].new_text, "SELECT id\n  FROM movies;");
+    assert_eq!(
+        edits[0].range,
+        Range::new(Position::new(0, 0), Position::new(0, 22))
+    );
+    let document = Document::new("SELECT id\n  FROM movies;\n".to_string());
+    assert_eq!(handlers::formatting(&document), vec!());
+}
+
+#[test]
+fn test_range_formatting() {
+    let document = Document::new("select id from movies;\nselect title from movies;\n".to_string());
+    let range = Range::new(Position::new(1, 3), Position::new(1, 4));
+    let edits = handlers::range_formatting(&document, range);
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].new_text, "SELECT title\n  FROM movies;");
+    assert_eq!(
+        edits[0].range,
+        Range::new(Position::new(1, 0), Position::new(1, 25))
+    );
+}
+
+#[test]
+fn test_diagnostics() {
+    let document = Document::new("SELECT id\n  FROM movies;\nSELEC id;\nselect id;".to_string());
+    let diagnostics = handlers::diagnostics(&document);
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+    assert_eq!(
+        diagnostics[0].range,
+        Range::new(Position::new(2, 0), Position::new(2, 9))
+    );
+    assert_eq!(
+        diagnostics[1].severity,
+        Some(DiagnosticSeverity::INFORMATION)
+    );
+    assert_eq!(
+        diagnostics[1].range,
+        Range::new(Position::new(3, 0), Position::new(3, 10))
+    );
+}
+
+#[test]
+fn test_hover() {
+    let document = Document::new("select title from movies;".to_string());
+    let hover = handlers::hover(&document, Position::new(0, 16)).unwrap();
+    match hover.contents {
+        HoverContents::Markup(m) => assert_eq!(m.value, "`FROM` reserved keyword"),
+        c => panic!("unexpected hover {:?}", c),
+    }
+    assert_eq!(
+        hover.range,
+        Some(Range::new(Position::new(0, 13), Position::new(0, 17)))
+    );
+    assert_eq!(handlers::hover(&document, Position::new(0, 8)), None);
+}
+
+#[test]
+fn test_hover_after_non_ascii() {
+    let document = Document::new("select “title”from movies;".to_string());
+    let hover = handlers::hover(&document, Position::new(0, 16)).unwrap();
+    assert_eq!(
+        hover.range,
+        Some(Range::new(Position::new(0, 14), Position::new(0, 18)))
+    );
+}
+
+#[test]
+fn test_document_symbols() {
+    let document = Document::new(
+        "CREATE TABLE public.movies (id INT);\nSELECT 1;\nCREATE SEQUENCE ids START WITH 1;"
+            .to_string(),
+    );
+    let symbols: Vec<(String, SymbolKind, u32)> = handlers::document_symbols(&document)
+        .into_iter()
+        .map(|s| (s.name, s.kind, s.range.start.line))
+        .collect();
+    assert_eq!(
+        symbols,
+        vec!(
+            ("public.movies".to_string(), SymbolKind::STRUCT, 0),
+            ("ids".to_string(), SymbolKind::NUMBER, 2)
+        )
+    );
+}
+
+#[test]
+fn test_session() {
+    let (server, client) = Connection::memory();
+    let thread = std::thread::spawn(move || crate::run(&server).unwrap());
+    let send = |m: Message| client.sender.send(m).unwrap();
+    let request = |id: i32, method: &str, params| {
+        Message::Request(Request::new(
+            RequestId::from(id),
+            method.to_string(),
+            params,
+        ))
+    };
+    let notification =
+        |method: &str, params| Message::Notification(Notification::new(method.to_string(), params));
+
+    send(request(1, "initialize", json!({ "capabilities": {} })));
+    match client.receiver.recv().unwrap() {
+        Message::Response(r) => {
+            assert!(r.result.unwrap()["capabilities"]["documentFormattingProvider"] == true)
+        }
+        m => panic!("unexpected message {:?}", m),
+    }
+    send(notification("initialized", json!({})));
+    send(notification(
+        "textDocument/didOpen",
+        json!({ "textDocument": { "uri": "file:///a.sql", "languageId": "sql", "version": 1, "text": "select id from movies;" } }),
+    ));
+    match client.receiver.recv().unwrap() {
+        Message::Notification(n) => {
+            assert_eq!(n.method, "textDocument/publishDiagnostics");
+            assert_eq!(n.params["diagnostics"][0]["code"], "unformatted");
+        }
+        m => panic!("unexpected message {:?}", m),
+    }
+    send(request(
+        2,
+        "textDocument/formatting",
+        json!({ "textDocument": { "uri": "file:///a.sql" }, "options": { "tabSize": 2, "insertSpaces": true } }),
+    ));
+    match client.receiver.recv().unwrap() {
+        Message::Response(r) => {
+            assert_eq!(r.result.unwrap()[0]["newText"], "SELECT id\n  FROM movies;")
+        }
+        m => panic!("unexpected message {:?}", m),
+    }
+    send(request(3, "shutdown", json!(null)));
+    client.receiver.recv().unwrap();
+    send(notification("exit", json!(null)));
+    thread.join().unwrap();
+}
@@ -0,0 +1,132 @@
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::privilege::parser::parse_object_type;
+use crate::privilege::ObjectType;
+use crate::table::create::parse_table_ref;
+use crate::table::create::TableRef;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::value;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::tuple;
+use nom::IResult;
+
+// ALTER TABLE movies RENAME TO films, ALTER TRIGGER touch ON movies RENAME TO touch_movie
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterObjectStatement(
+    pub ObjectType,
+    pub TableRef,
+    pub Option<TableRef>,
+    pub AlterObjectAction,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterObjectAction {
+    RenameTo(Name),
+    OwnerTo(Name),
+}
+
+fn parse_rename_to(input: &str) -> IResult<&str, AlterObjectAction> {
+    map(
+        preceded(
+            pair(parse_keyword(Keyword::Rename), parse_keyword(Keyword::To)),
+            ws(parse_name),
+        ),
+        AlterObjectAction::RenameTo,
+    )(input)
+}
+
+fn parse_alter_object_action(input: &str) -> IResult<&str, AlterObjectAction> {
+    alt((
+        parse_rename_to,
+        map(
+            preceded(
+                pair(parse_keyword(Keyword::Owner), parse_keyword(Keyword::To)),
+                ws(parse_name),
+            ),
+            AlterObjectAction::OwnerTo,
+        ),
+    ))(input)
+}
+
+fn parse_alter_object_type(input: &str) -> IResult<&str, ObjectType> {
+    alt((
+        parse_object_type,
+        value(ObjectType::View, parse_keyword(Keyword::View)),
+        value(ObjectType::Procedure, parse_keyword(Keyword::Procedure)),
+        value(ObjectType::Role, parse_keyword(Keyword::Role)),
+    ))(input)
+}
+
+// Triggers are named within their table and can only be renamed.
+pub fn parse_alter_object_statement(input: &str) -> IResult<&str, AlterObjectStatement> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::Alter),
+                parse_keyword(Keyword::Trigger),
+                ws(parse_name),
+                parse_keyword(Keyword::On),
+                ws(parse_table_ref),
+                parse_rename_to,
+            )),
+            |(_, _, n, _, t, a)| {
+                AlterObjectStatement(ObjectType::Trigger, TableRef(None, n), Some(t), a)
+            },
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Alter),
+                parse_alter_object_type,
+                ws(parse_table_ref),
+                parse_alter_object_action,
+            )),
+            |(_, o, t, a)| AlterObjectStatement(o, t, None, a),
+        ),
+    ))(input)
+}
+
+impl Format for AlterObjectStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Alter)
+            .ws()
+            .append(&self.0.keyword())
+            .ws()
+            .append(&self.1)
+            .ws();
+        if let Some(t) = &self.2 {
+            f.append(&Keyword::On).ws().append(t).ws();
+        }
+        f.append_format(&self.3)
+    }
+}
+
+impl Format for AlterObjectAction {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::RenameTo(n) => f
+                .append(&Keyword::Rename)
+                .ws()
+                .append(&Keyword::To)
+                .ws()
+                .append(n),
+            Self::OwnerTo(n) => f
+                .append(&Keyword::Owner)
+                .ws()
+                .append(&Keyword::To)
+                .ws()
+                .append(n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,73 @@
+use crate::alter::parse_alter_object_statement;
+use crate::alter::AlterObjectAction;
+use crate::alter::AlterObjectStatement;
+use crate::assert_format;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::privilege::ObjectType;
+use crate::table::create::TableRef;
+
+#[test]
+fn test_parse_alter_object_statement() {
+    assert_eq!(
+        parse_alter_object_statement("ALTER TABLE movies RENAME TO films"),
+        Ok((
+            "",
+            AlterObjectStatement(
+                ObjectType::Table,
+                TableRef(None, Name::Name("movies".to_string())),
+                None,
+                AlterObjectAction::RenameTo(Name::Name("films".to_string()))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_alter_object_statement() {
+    assert_format!(
+        parse_alter_object_statement("alter schema staging owner to admin"),
+        "ALTER SCHEMA staging OWNER TO admin"
+    );
+    assert_format!(
+        parse_alter_object_statement("alter function public.touch rename to touch_row"),
+        "ALTER FUNCTION public.touch RENAME TO touch_row"
+    );
+    assert_format!(
+        parse_alter_object_statement("alter table movies rename to films"),
+        "ALTER TABLE movies RENAME TO films"
+    );
+}
+
+#[test]
+fn test_format_alter_rename_targets() {
+    for target in [
+        "VIEW",
+        "PROCEDURE",
+        "ROLE",
+        "SEQUENCE",
+        "TYPE",
+        "SCHEMA",
+        "DOMAIN",
+        "DATABASE",
+    ] {
+        let input = format!("ALTER {target} old RENAME TO new");
+        assert_format!(parse_alter_object_statement(&input), input);
+    }
+    assert_format!(
+        parse_alter_object_statement("alter trigger touch on public.movies rename to touch_movie"),
+        "ALTER TRIGGER touch ON public.movies RENAME TO touch_movie"
+    );
+    assert!(parse_alter_object_statement("ALTER TRIGGER touch ON movies OWNER TO admin").is_err());
+}
+
+#[test]
+fn test_format_alter_rename_statements() {
+    assert_eq!(
+        crate::format("alter view v rename to w; alter role reader rename to viewer; alter role reader with login;"),
+        Ok(
+            "ALTER VIEW v RENAME TO w;\n\nALTER ROLE reader RENAME TO viewer;\n\nALTER ROLE reader WITH LOGIN;"
+                .to_string()
+        )
+    );
+}
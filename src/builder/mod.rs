@@ -0,0 +1,664 @@
+//! Programmatic construction of statements.
+//!
+//! ```
+//! use sql_lint::builder::*;
+//! use sql_lint::formatter::Format;
+//!
+//! let query = select([col("title"), col("year")])
+//!     .from(table("movies"))
+//!     .where_(col("year").gt_(1999).and(col("title").like(param())))
+//!     .order_by(desc("year"))
+//!     .limit(10);
+//! assert_eq!(
+//!     query.output(),
+//!     "SELECT title, year\n  FROM movies\n WHERE year > 1999\n   AND title LIKE ?\n ORDER BY year DESC\nLIMIT 10"
+//! );
+//! ```
+//!
+//! Lists SQL requires to be non-empty are arrays, an empty one does not compile:
+//!
+//! ```compile_fail
+//! use sql_lint::builder::*;
+//! use sql_lint::term::Term;
+//!
+//! let query = select::<Term, 0>([]);
+//! ```
+//!
+//! Rows are only inserted from a query:
+//!
+//! ```compile_fail
+//! use sql_lint::builder::*;
+//!
+//! let query = insert_into("movies").query(update("movies").set("year", 1999));
+//! ```
+use crate::expression::Compare;
+use crate::expression::Condition;
+use crate::expression::Expression;
+use crate::expression::InPredicateValue;
+use crate::expression::Operand;
+use crate::expression::RightOperand;
+use crate::expression::WhereClause;
+use crate::function::AggregateFunction;
+use crate::function::Function;
+use crate::identifier::Name;
+use crate::insert::InsertIntoClause;
+use crate::insert::InsertSource;
+use crate::insert::InsertStatement;
+use crate::insert::InsertValue;
+use crate::insert::ValuesClause;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::numeric::Numeric;
+use crate::query::Query;
+use crate::returning::ReturningClause;
+use crate::select::clause::SelectClause;
+use crate::select::clause::SelectedExpression;
+use crate::select::clause::SetQuantifier;
+use crate::select::from::TableExpression;
+use crate::select::from::TableName;
+use crate::select::group::GroupByClause;
+use crate::select::group::GroupingElement;
+use crate::select::group::HavingClause;
+use crate::select::join::JoinClause;
+use crate::select::join::JoinSpecification;
+use crate::select::join::JoinType;
+use crate::select::join::OuterJoin;
+use crate::select::join::OuterJoinType;
+use crate::select::limit::Fetch;
+use crate::select::limit::LimitClause;
+use crate::select::limit::Offset;
+use crate::select::order::NullsSort;
+use crate::select::order::Order;
+use crate::select::order::OrderByClause;
+use crate::select::order::OrderSort;
+use crate::select::order::SortKey;
+use crate::select::FromClause;
+use crate::select::SelectStatement;
+use crate::table::create::TableRef;
+use crate::term::bind_parameter::BindParameter;
+use crate::term::column::ColumnRef;
+use crate::term::value::Value;
+use crate::term::Term;
+use crate::update::SetClause;
+use crate::update::SetExpression;
+use crate::update::SetTarget;
+use crate::update::SetValue;
+use crate::update::UpdateClause;
+use crate::update::UpdateStatement;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+struct NonEmpty<const N: usize>;
+
+impl<const N: usize> NonEmpty<N> {
+    const CHECK: () = assert!(N > 0, "at least one item is required");
+}
+
+fn non_empty<T, U, const N: usize>(items: [T; N], f: impl FnMut(T) -> U) -> List<U> {
+    #[allow(clippy::let_unit_value)]
+    let () = NonEmpty::<N>::CHECK;
+    List(IntoIterator::into_iter(items).map(f).collect())
+}
+
+// Identifiers the parser would reject are quoted.
+pub fn name(s: &str) -> Name {
+    let identifier = !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_');
+    let reserved = Keyword::from_str(&s.to_lowercase()).is_ok_and(|k| k.is_reserved());
+    match identifier && !reserved {
+        true => Name::Name(s.to_string()),
+        false => Name::QuotedName(s.to_string()),
+    }
+}
+
+// The first dot separates the qualifier, `a.b.c` is the name `"b.c"` qualified by `a`.
+fn qualified(s: &str) -> (Option<Name>, Name) {
+    match s.split_once('.') {
+        Some((family, n)) => (Some(name(family)), name(n)),
+        None => (None, name(s)),
+    }
+}
+
+// `col("title")`, `col("m.title")`
+pub fn col(s: &str) -> Term {
+    Term::ColumnRef(ColumnRef::from(s))
+}
+
+pub fn val(v: impl Into<Value>) -> Term {
+    Term::Value(v.into())
+}
+
+pub fn null() -> Term {
+    Term::Value(Value::Null)
+}
+
+pub fn param() -> Term {
+    Term::BindParameter(BindParameter::Placeholder)
+}
+
+pub fn param_at(index: u16) -> Term {
+    Term::BindParameter(BindParameter::Index(index))
+}
+
+pub fn func<T: Into<Term>>(n: &str, args: impl IntoIterator<Item = T>) -> Term {
+    Term::Function(AggregateFunction::Function(Function(
+        n.to_string(),
+        List(args.into_iter().map(Into::into).collect()),
+    )))
+}
+
+pub fn count_all() -> Term {
+    Term::Function(AggregateFunction::CountAll)
+}
+
+// `*`
+pub fn all() -> SelectedExpression {
+    SelectedExpression::All
+}
+
+pub fn table(s: &str) -> TableName {
+    TableName::Name(name(s))
+}
+
+pub fn asc(column: &str) -> Order {
+    Order(
+        SortKey::ColumnRef(column.into()),
+        Some(OrderSort::Asc),
+        None,
+    )
+}
+
+pub fn desc(column: &str) -> Order {
+    Order(
+        SortKey::ColumnRef(column.into()),
+        Some(OrderSort::Desc),
+        None,
+    )
+}
+
+pub fn select<T: Into<SelectedExpression>, const N: usize>(items: [T; N]) -> SelectStatement {
+    SelectStatement {
+        select: SelectClause(None, None, non_empty(items, Into::into)),
+        from: None,
+        r#where: None,
+        group_by: None,
+        order_by: None,
+        limit: None,
+    }
+}
+
+pub fn update(t: &str) -> Update {
+    Update(UpdateClause(t.into(), None))
+}
+
+pub fn insert_into(t: &str) -> InsertInto {
+    InsertInto(InsertIntoClause(t.into(), None))
+}
+
+impl From<&str> for ColumnRef {
+    fn from(s: &str) -> Self {
+        match qualified(s) {
+            (Some(family), n) => ColumnRef::WithFamily(family, n),
+            (None, n) => ColumnRef::Name(n),
+        }
+    }
+}
+
+impl From<&str> for TableRef {
+    fn from(s: &str) -> Self {
+        let (schema, n) = qualified(s);
+        TableRef(schema, n)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(i: i32) -> Self {
+        Value::Num(Numeric::Int(i))
+    }
+}
+
+// NaN and infinities have no SQL literal.
+impl TryFrom<f64> for Value {
+    type Error = f64;
+
+    fn try_from(d: f64) -> Result<Self, f64> {
+        match d.is_finite() {
+            true => Ok(Value::Num(Numeric::Decimal(d))),
+            false => Err(d),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl<T: Into<Value>> From<T> for Term {
+    fn from(v: T) -> Self {
+        Term::Value(v.into())
+    }
+}
+
+impl From<SelectStatement> for Term {
+    fn from(s: SelectStatement) -> Self {
+        Term::Subquery(Box::new(s))
+    }
+}
+
+impl From<Term> for SelectedExpression {
+    fn from(t: Term) -> Self {
+        SelectedExpression::Term(t)
+    }
+}
+
+impl From<Term> for Expression {
+    fn from(t: Term) -> Self {
+        Expression::Condition(Condition::Operand(Operand::Term(t)))
+    }
+}
+
+impl From<Condition> for Expression {
+    fn from(c: Condition) -> Self {
+        Expression::Condition(c)
+    }
+}
+
+impl From<Condition> for WhereClause {
+    fn from(c: Condition) -> Self {
+        WhereClause(List(vec![Expression::Condition(c)]))
+    }
+}
+
+impl From<SelectStatement> for Query {
+    fn from(s: SelectStatement) -> Self {
+        Query::Select(Box::new(s))
+    }
+}
+
+impl From<UpdateStatement> for Query {
+    fn from(s: UpdateStatement) -> Self {
+        Query::Update(s)
+    }
+}
+
+impl From<InsertStatement> for Query {
+    fn from(s: InsertStatement) -> Self {
+        Query::Insert(s)
+    }
+}
+
+fn operand(t: impl Into<Term>) -> Operand {
+    Operand::Term(t.into())
+}
+
+impl Term {
+    fn compare(self, compare: Compare, other: impl Into<Term>) -> Condition {
+        Condition::BinaryExpression(
+            Operand::Term(self),
+            RightOperand::Compare(compare, operand(other)),
+        )
+    }
+
+    pub fn eq_(self, other: impl Into<Term>) -> Condition {
+        self.compare(Compare::Equal, other)
+    }
+
+    pub fn ne_(self, other: impl Into<Term>) -> Condition {
+        self.compare(Compare::NotEqual, other)
+    }
+
+    pub fn gt_(self, other: impl Into<Term>) -> Condition {
+        self.compare(Compare::GreaterThan, other)
+    }
+
+    pub fn ge_(self, other: impl Into<Term>) -> Condition {
+        self.compare(Compare::GreaterOrEqual, other)
+    }
+
+    pub fn lt_(self, other: impl Into<Term>) -> Condition {
+        self.compare(Compare::LowerThan, other)
+    }
+
+    pub fn le_(self, other: impl Into<Term>) -> Condition {
+        self.compare(Compare::LowerOrEqual, other)
+    }
+
+    pub fn like(self, pattern: impl Into<Term>) -> Condition {
+        Condition::BinaryExpression(Operand::Term(self), RightOperand::Like(operand(pattern)))
+    }
+
+    pub fn between(self, low: impl Into<Term>, high: impl Into<Term>) -> Condition {
+        Condition::BinaryExpression(
+            Operand::Term(self),
+            RightOperand::Between(operand(low), operand(high)),
+        )
+    }
+
+    pub fn is_in<T: Into<Term>, const N: usize>(self, values: [T; N]) -> Condition {
+        let values = non_empty(values, operand);
+        Condition::BinaryExpression(
+            Operand::Term(self),
+            RightOperand::In(InPredicateValue::InValueList(values)),
+        )
+    }
+
+    pub fn in_subquery(self, query: SelectStatement) -> Condition {
+        Condition::BinaryExpression(
+            Operand::Term(self),
+            RightOperand::In(InPredicateValue::Subquery(operand(query))),
+        )
+    }
+
+    pub fn is_null(self) -> Condition {
+        Condition::BinaryExpression(Operand::Term(self), RightOperand::Null(true))
+    }
+
+    pub fn is_not_null(self) -> Condition {
+        Condition::BinaryExpression(Operand::Term(self), RightOperand::Null(false))
+    }
+
+    pub fn as_(self, alias: &str) -> Term {
+        Term::AliasedTerm(Box::new(self), name(alias))
+    }
+}
+
+impl Condition {
+    pub fn and(self, other: Condition) -> WhereClause {
+        WhereClause::from(self).and(other)
+    }
+
+    pub fn or(self, other: Condition) -> WhereClause {
+        WhereClause::from(self).or(other)
+    }
+}
+
+impl WhereClause {
+    pub fn and(mut self, other: Condition) -> Self {
+        self.0 .0.push(Expression::AndExpression(other));
+        self
+    }
+
+    pub fn or(mut self, other: Condition) -> Self {
+        self.0 .0.push(Expression::OrExpression(other));
+        self
+    }
+}
+
+impl TableName {
+    pub fn as_(self, alias: &str) -> TableName {
+        match self {
+            Self::Name(n) | Self::AliasedName(n, _) => Self::AliasedName(n, name(alias)),
+        }
+    }
+
+    pub fn join(self, t: TableName, on: impl Into<WhereClause>) -> FromClause {
+        FromClause::from(self).join(t, on)
+    }
+
+    pub fn left_join(self, t: TableName, on: impl Into<WhereClause>) -> FromClause {
+        FromClause::from(self).left_join(t, on)
+    }
+}
+
+impl From<TableName> for FromClause {
+    fn from(t: TableName) -> Self {
+        FromClause(List(vec![TableExpression(t)]), None)
+    }
+}
+
+impl FromClause {
+    fn join_with(mut self, join_type: JoinType, t: TableName, on: WhereClause) -> Self {
+        let join = JoinClause(
+            join_type,
+            Box::new(TableExpression(t)),
+            Some(JoinSpecification::On(on.0)),
+        );
+        self.1.get_or_insert_with(|| List(vec![])).0.push(join);
+        self
+    }
+
+    pub fn join(self, t: TableName, on: impl Into<WhereClause>) -> Self {
+        self.join_with(JoinType::Default, t, on.into())
+    }
+
+    pub fn inner_join(self, t: TableName, on: impl Into<WhereClause>) -> Self {
+        self.join_with(JoinType::Inner, t, on.into())
+    }
+
+    pub fn left_join(self, t: TableName, on: impl Into<WhereClause>) -> Self {
+        let join_type = JoinType::QualifedJoin(OuterJoin(OuterJoinType::Left, false));
+        self.join_with(join_type, t, on.into())
+    }
+}
+
+impl Order {
+    pub fn nulls_first(self) -> Self {
+        Order(self.0, self.1, Some(NullsSort::First))
+    }
+
+    pub fn nulls_last(self) -> Self {
+        Order(self.0, self.1, Some(NullsSort::Last))
+    }
+}
+
+impl From<&str> for Order {
+    fn from(column: &str) -> Self {
+        Order(SortKey::ColumnRef(column.into()), None, None)
+    }
+}
+
+impl GroupByClause {
+    pub fn having(self, c: impl Into<WhereClause>) -> Self {
+        GroupByClause(self.0, self.1, Some(HavingClause(c.into().0)))
+    }
+}
+
+// `group_by(["country"]).having(count_all().gt_(10))`
+pub fn group_by<const N: usize>(columns: [&str; N]) -> GroupByClause {
+    GroupByClause(
+        None,
+        non_empty(columns, |c| GroupingElement::ColumnRef(c.into())),
+        None,
+    )
+}
+
+impl SelectStatement {
+    pub fn distinct(mut self) -> Self {
+        self.select.0 = Some(SetQuantifier::Distinct);
+        self
+    }
+
+    // Tables are appended to the FROM clause.
+    pub fn from(mut self, from: impl Into<FromClause>) -> Self {
+        let from = from.into();
+        self.from = Some(match self.from {
+            Some(FromClause(mut tables, joins)) => {
+                tables.0.extend(from.0 .0);
+                let joins = match (joins, from.1) {
+                    (Some(mut l), Some(r)) => {
+                        l.0.extend(r.0);
+                        Some(l)
+                    }
+                    (l, r) => l.or(r),
+                };
+                FromClause(tables, joins)
+            }
+            None => from,
+        });
+        self
+    }
+
+    pub fn where_(mut self, c: impl Into<WhereClause>) -> Self {
+        self.r#where = Some(c.into());
+        self
+    }
+
+    pub fn group_by(mut self, g: GroupByClause) -> Self {
+        self.group_by = Some(g);
+        self
+    }
+
+    pub fn order_by(mut self, o: impl Into<Order>) -> Self {
+        let order = o.into();
+        match &mut self.order_by {
+            Some(OrderByClause(l)) => l.0.push(order),
+            None => self.order_by = Some(OrderByClause(List(vec![order]))),
+        };
+        self
+    }
+
+    pub fn limit(self, n: u16) -> Self {
+        self.limit_term(val(i32::from(n)))
+    }
+
+    pub fn limit_param(self) -> Self {
+        self.limit_term(param())
+    }
+
+    pub fn offset(mut self, n: u16) -> Self {
+        let offset = Offset {
+            count: val(i32::from(n)),
+            rows: false,
+        };
+        self.limit = Some(match self.limit {
+            Some(LimitClause::Limit(count, _)) => LimitClause::Limit(count, Some(offset)),
+            Some(LimitClause::OffsetLimit(_, count)) => LimitClause::OffsetLimit(offset, count),
+            Some(LimitClause::OffsetCount(_, count)) => {
+                LimitClause::Limit(Some(count), Some(offset))
+            }
+            Some(LimitClause::Fetch(_, fetch)) => LimitClause::Fetch(Some(offset), fetch),
+            None => LimitClause::Limit(None, Some(offset)),
+        });
+        self
+    }
+
+    fn limit_term(mut self, count: Term) -> Self {
+        self.limit = Some(match self.limit {
+            Some(LimitClause::Limit(_, offset)) => LimitClause::Limit(Some(count), offset),
+            Some(LimitClause::OffsetLimit(offset, _)) => LimitClause::OffsetLimit(offset, count),
+            Some(LimitClause::OffsetCount(offset, _)) => LimitClause::OffsetCount(offset, count),
+            Some(LimitClause::Fetch(offset, fetch)) => LimitClause::Fetch(
+                offset,
+                Fetch {
+                    count: Some(count),
+                    ..fetch
+                },
+            ),
+            None => LimitClause::Limit(Some(count), None),
+        });
+        self
+    }
+}
+
+fn returning<T: Into<SelectedExpression>, const N: usize>(items: [T; N]) -> ReturningClause {
+    ReturningClause(non_empty(items, Into::into))
+}
+
+// An UPDATE needs at least one SET expression.
+pub struct Update(UpdateClause);
+
+impl Update {
+    pub fn as_(self, alias: &str) -> Self {
+        Update(UpdateClause(self.0 .0, Some(name(alias))))
+    }
+
+    pub fn set(self, column: &str, v: impl Into<Term>) -> UpdateStatement {
+        UpdateStatement(self.0, SetClause(List(vec![])), None, None, None).set(column, v)
+    }
+
+    pub fn set_default(self, column: &str) -> UpdateStatement {
+        UpdateStatement(self.0, SetClause(List(vec![])), None, None, None).set_default(column)
+    }
+}
+
+impl UpdateStatement {
+    pub fn set(mut self, column: &str, v: impl Into<Term>) -> Self {
+        let value = SetValue::Expression(Expression::from(v.into()));
+        self.1
+             .0
+             .0
+            .push(SetExpression(SetTarget::Column(column.into()), value));
+        self
+    }
+
+    pub fn set_default(mut self, column: &str) -> Self {
+        self.1 .0 .0.push(SetExpression(
+            SetTarget::Column(column.into()),
+            SetValue::Default,
+        ));
+        self
+    }
+
+    pub fn from(mut self, from: impl Into<FromClause>) -> Self {
+        self.2 = Some(from.into());
+        self
+    }
+
+    pub fn where_(mut self, c: impl Into<WhereClause>) -> Self {
+        self.3 = Some(c.into());
+        self
+    }
+
+    pub fn returning<T: Into<SelectedExpression>, const N: usize>(mut self, items: [T; N]) -> Self {
+        self.4 = Some(returning(items));
+        self
+    }
+}
+
+// An INSERT needs a source: rows, a query or DEFAULT VALUES.
+pub struct InsertInto(InsertIntoClause);
+
+impl InsertInto {
+    pub fn columns<const N: usize>(self, columns: [&str; N]) -> Self {
+        let columns = non_empty(columns, name);
+        InsertInto(InsertIntoClause(self.0 .0, Some(columns)))
+    }
+
+    pub fn values<T: Into<Term>, const N: usize>(self, row: [T; N]) -> InsertStatement {
+        let source = InsertSource::Values(ValuesClause(List(vec![])));
+        InsertStatement(self.0, source, None, None).values(row)
+    }
+
+    pub fn query(self, q: SelectStatement) -> InsertStatement {
+        InsertStatement(self.0, InsertSource::Query(Box::new(q.into())), None, None)
+    }
+
+    pub fn default_values(self) -> InsertStatement {
+        InsertStatement(self.0, InsertSource::DefaultValues, None, None)
+    }
+}
+
+impl InsertStatement {
+    // Appends a row, replacing a query or DEFAULT VALUES source.
+    pub fn values<T: Into<Term>, const N: usize>(mut self, row: [T; N]) -> Self {
+        let row = InsertValue::ParenthesisExpression(non_empty(row, |t| {
+            InsertValue::Expression(Box::new(Expression::from(t.into())))
+        }));
+        match &mut self.1 {
+            InsertSource::Values(ValuesClause(rows)) => rows.0.push(row),
+            source => *source = InsertSource::Values(ValuesClause(List(vec![row]))),
+        };
+        self
+    }
+
+    pub fn returning<T: Into<SelectedExpression>, const N: usize>(mut self, items: [T; N]) -> Self {
+        self.3 = Some(returning(items));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests;
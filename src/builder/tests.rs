@@ -0,0 +1,120 @@
+use crate::builder::*;
+use crate::formatter::Format;
+use crate::insert::parse_insert_into_statement;
+use crate::select::parse_select_statement;
+use crate::term::value::Value;
+use crate::update::parse_update_statement;
+use std::convert::TryFrom;
+
+#[test]
+fn test_select() {
+    let built = select([col("m.title"), count_all().as_("total")])
+        .distinct()
+        .from(
+            table("movies")
+                .as_("m")
+                .join(table("actors").as_("a"), col("a.id").eq_(col("m.actor_id"))),
+        )
+        .where_(
+            col("m.year")
+                .between(1990, 1999)
+                .and(col("a.name").is_in(["Keanu", "Carrie"]))
+                .or(col("m.title").is_null()),
+        )
+        .group_by(group_by(["m.title"]).having(count_all().gt_(param())))
+        .order_by(desc("m.title").nulls_last())
+        .order_by("total")
+        .limit(10);
+    let input = "SELECT DISTINCT m.title, COUNT(*) AS total FROM movies AS m JOIN actors AS a ON a.id = m.actor_id WHERE m.year BETWEEN 1990 AND 1999 AND a.name IN ('Keanu', 'Carrie') OR m.title IS NULL GROUP BY m.title HAVING COUNT(*) > ? ORDER BY m.title DESC NULLS LAST, total LIMIT 10";
+    assert_eq!(parse_select_statement(input), Ok(("", built.clone())));
+    assert_eq!(
+        built.output(),
+        parse_select_statement(input).unwrap().1.output()
+    );
+}
+
+#[test]
+fn test_select_subquery() {
+    let built = select([all()])
+        .from(table("movies"))
+        .where_(col("id").in_subquery(select([col("movie_id")]).from(table("awards"))));
+    let input = "SELECT * FROM movies WHERE id IN (SELECT movie_id FROM awards)";
+    assert_eq!(parse_select_statement(input), Ok(("", built)));
+}
+
+#[test]
+fn test_select_offset() {
+    let built = select([col("title")])
+        .from(table("movies"))
+        .offset(20)
+        .limit(10);
+    let input = "SELECT title FROM movies LIMIT 10 OFFSET 20";
+    assert_eq!(parse_select_statement(input), Ok(("", built)));
+}
+
+#[test]
+fn test_quoted_names() {
+    let built = select([col("m.title"), col("m.select")]).from(table("group").as_("m"));
+    let input = "SELECT m.title, m.\"select\" FROM \"group\" AS m";
+    assert_eq!(parse_select_statement(input), Ok(("", built.clone())));
+    assert_eq!(
+        built.output(),
+        "SELECT m.title, m.\"select\"\n  FROM \"group\" AS m"
+    );
+}
+
+#[test]
+fn test_update() {
+    let built = update("movies")
+        .set("title", "Matrix")
+        .set_default("rating")
+        .set("year", param())
+        .where_(col("id").eq_(5))
+        .returning([col("id")]);
+    let input =
+        "UPDATE movies SET title = 'Matrix', rating = DEFAULT, year = ? WHERE id = 5 RETURNING id";
+    assert_eq!(parse_update_statement(input), Ok(("", built)));
+}
+
+#[test]
+fn test_insert() {
+    let built = insert_into("public.movies")
+        .columns(["title", "year"])
+        .values([val("Alien"), val(1979)])
+        .values([param(), null()]);
+    let input = "INSERT INTO public.movies (title, year) VALUES ('Alien', 1979), (?, NULL)";
+    assert_eq!(parse_insert_into_statement(input), Ok(("", built.clone())));
+    assert_eq!(
+        built.output(),
+        parse_insert_into_statement(input).unwrap().1.output()
+    );
+}
+
+#[test]
+fn test_insert_query() {
+    let built = insert_into("archive").query(select([all()]).from(table("movies")));
+    let input = "INSERT INTO archive SELECT * FROM movies";
+    assert_eq!(parse_insert_into_statement(input), Ok(("", built)));
+    let built = insert_into("movies").default_values();
+    let input = "INSERT INTO movies DEFAULT VALUES";
+    assert_eq!(parse_insert_into_statement(input), Ok(("", built)));
+}
+
+#[test]
+fn test_terms_compare_with_partial_eq() {
+    assert!(col("title").eq(&col("title")));
+    assert_eq!(col("year").ge_(2000).output(), "year >= 2000");
+}
+
+#[test]
+fn test_decimal_values() {
+    assert_eq!(val(Value::try_from(2.5).unwrap()).output(), "2.5");
+    assert!(Value::try_from(f64::NAN).is_err());
+    assert!(Value::try_from(f64::NEG_INFINITY).is_err());
+}
+
+#[test]
+fn test_qualified_names() {
+    assert_eq!(col("m.title").output(), "m.title");
+    assert_eq!(col("m.title.en").output(), "m.\"title.en\"");
+}
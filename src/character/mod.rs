@@ -1,6 +1,9 @@
 use crate::ws::ws;
 use nom::bytes::complete::tag;
 use nom::combinator::map;
+use nom::error::Error;
+use nom::error::ErrorKind;
+use nom::Err;
 use nom::IResult;
 
 pub fn parse_left_parenthesis(input: &str) -> IResult<&str, ()> {
@@ -14,3 +17,47 @@ pub fn parse_right_parenthesis(input: &str) -> IResult<&str, ()> {
 pub fn parse_comma(input: &str) -> IResult<&str, ()> {
     map(ws(tag(",")), |_| ())(input)
 }
+
+// Everything up to the parenthesis closing the current group, nested groups included.
+pub fn parse_balanced(input: &str) -> IResult<&str, &str> {
+    let mut depth = 0;
+    let mut quoted = false;
+    for (i, c) in input.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted && depth == 0 => {
+                return match i {
+                    0 => Err(Err::Error(Error::new(input, ErrorKind::TakeWhile1))),
+                    _ => Ok((&input[i..], &input[..i])),
+                }
+            }
+            ')' if !quoted => depth -= 1,
+            _ => (),
+        }
+    }
+    Err(Err::Error(Error::new(input, ErrorKind::TakeUntil)))
+}
+
+// An opaque expression, up to the next top-level comma, semicolon or closing parenthesis.
+pub fn parse_raw_expression(input: &str) -> IResult<&str, &str> {
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut end = input.len();
+    for (i, c) in input.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' | ',' | ';' if !quoted && depth == 0 => {
+                end = i;
+                break;
+            }
+            ')' if !quoted => depth -= 1,
+            _ => (),
+        }
+    }
+    match input[..end].trim() {
+        "" => Err(Err::Error(Error::new(input, ErrorKind::TakeWhile1))),
+        e => Ok((&input[end..], e)),
+    }
+}
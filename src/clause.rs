@@ -1,7 +1,5 @@
+use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
-use crate::ws::ws;
-use nom::bytes::complete::tag_no_case;
-use nom::combinator::map;
 use nom::IResult;
 
 pub trait Clause {
@@ -16,7 +14,6 @@ pub trait Clause {
     }
 
     fn parse_keyword(input: &str) -> IResult<&str, ()> {
-        let clause: &'static str = Self::KEYWORD.into();
-        map(ws(tag_no_case(clause)), |_| ())(input)
+        parse_keyword(*Self::KEYWORD)(input)
     }
 }
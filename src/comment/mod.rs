@@ -1,3 +1,7 @@
+pub mod on;
+#[cfg(test)]
+mod on_tests;
+
 use nom::bytes::complete::tag;
 use nom::bytes::complete::take_until;
 use nom::combinator::value;
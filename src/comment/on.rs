@@ -0,0 +1,95 @@
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::privilege::parser::parse_object_type;
+use crate::privilege::ObjectType;
+use crate::table::create::parse_table_ref;
+use crate::table::create::TableRef;
+use crate::term::value::parse_value;
+use crate::term::value::Value;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::character::complete::char;
+use nom::combinator::map;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::separated_pair;
+use nom::sequence::tuple;
+use nom::IResult;
+
+// COMMENT ON COLUMN movies.title IS 'Original title'
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommentOnStatement(pub CommentTarget, pub Value);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CommentTarget {
+    Object(ObjectType, TableRef),
+    Column(TableRef, Name),
+}
+
+fn parse_comment_target(input: &str) -> IResult<&str, CommentTarget> {
+    alt((
+        map(
+            preceded(
+                parse_keyword(Keyword::Column),
+                ws(alt((
+                    separated_pair(parse_table_ref, char('.'), parse_name),
+                    map(
+                        separated_pair(parse_name, char('.'), parse_name),
+                        |(t, c)| (TableRef(None, t), c),
+                    ),
+                ))),
+            ),
+            |(t, c)| CommentTarget::Column(t, c),
+        ),
+        map(pair(parse_object_type, ws(parse_table_ref)), |(o, t)| {
+            CommentTarget::Object(o, t)
+        }),
+    ))(input)
+}
+
+pub fn parse_comment_on_statement(input: &str) -> IResult<&str, CommentOnStatement> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Comment),
+            parse_keyword(Keyword::On),
+            parse_comment_target,
+            parse_keyword(Keyword::Is),
+            ws(parse_value),
+        )),
+        |(_, _, t, _, v)| CommentOnStatement(t, v),
+    )(input)
+}
+
+impl Format for CommentOnStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Comment)
+            .ws()
+            .append(&Keyword::On)
+            .ws()
+            .append_format(&self.0)
+            .ws()
+            .append(&Keyword::Is)
+            .ws()
+            .append_format(&self.1)
+    }
+}
+
+impl Format for CommentTarget {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Object(o, t) => f.append(&o.keyword()).ws().append(t),
+            Self::Column(t, c) => f
+                .append(&Keyword::Column)
+                .ws()
+                .append(t)
+                .append_str(".")
+                .append(c),
+        }
+    }
+}
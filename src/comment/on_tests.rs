@@ -0,0 +1,54 @@
+use crate::assert_format;
+use crate::comment::on::parse_comment_on_statement;
+use crate::comment::on::CommentOnStatement;
+use crate::comment::on::CommentTarget;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::privilege::ObjectType;
+use crate::table::create::TableRef;
+use crate::term::value::Value;
+
+#[test]
+fn test_parse_comment_on_statement() {
+    assert_eq!(
+        parse_comment_on_statement("COMMENT ON COLUMN movies.title IS 'Original title'"),
+        Ok((
+            "",
+            CommentOnStatement(
+                CommentTarget::Column(
+                    TableRef(None, Name::Name("movies".to_string())),
+                    Name::Name("title".to_string())
+                ),
+                Value::String("Original title".to_string())
+            )
+        ))
+    );
+    assert_eq!(
+        parse_comment_on_statement("COMMENT ON TABLE public.movies IS NULL"),
+        Ok((
+            "",
+            CommentOnStatement(
+                CommentTarget::Object(
+                    ObjectType::Table,
+                    TableRef(
+                        Some(Name::Name("public".to_string())),
+                        Name::Name("movies".to_string())
+                    )
+                ),
+                Value::Null
+            )
+        ))
+    );
+}
+
+#[test]
+fn test_format_comment_on_statement() {
+    assert_format!(
+        parse_comment_on_statement("comment on column public.movies.title is 'Original title'"),
+        "COMMENT ON COLUMN public.movies.title IS 'Original title'"
+    );
+    assert_format!(
+        parse_comment_on_statement("comment on schema staging is 'Imports'"),
+        "COMMENT ON SCHEMA staging IS 'Imports'"
+    );
+}
@@ -25,7 +25,8 @@ use nom::IResult;
 
 use std::str::FromStr;
 
-#[derive(Debug, EnumString, IntoStaticStr, Clone, Copy, PartialEq)]
+#[derive(Debug, EnumString, IntoStaticStr, Clone, Copy, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(ascii_case_insensitive, serialize_all = "title_case")]
 pub enum PredefinedType {
     Character,
@@ -54,22 +55,19 @@ pub enum PredefinedType {
     Json, // Not ANSI
     Bigint,
     Enum,
+    Text, // Not ANSI
 }
 
-#[derive(Debug, EnumString, IntoStaticStr, Clone, Copy, PartialEq)]
+#[derive(Debug, EnumString, IntoStaticStr, Clone, Copy, PartialEq, Default, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[strum(ascii_case_insensitive, serialize_all = "title_case")]
 pub enum DateTimeType {
+    #[default]
     Date,
     Time(DateTimeOption),
     Timestamp(DateTimeOption),
 }
 
-impl std::default::Default for DateTimeType {
-    fn default() -> Self {
-        Self::Date
-    }
-}
-
 pub fn parse_with_or_without_timezone(input: &str) -> IResult<&str, Option<bool>> {
     opt(alt((
         value(
@@ -121,7 +119,8 @@ pub fn parse_datetime_type(input: &str) -> IResult<&str, DateTimeType> {
     ))(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataType(pub PredefinedType, pub Option<List<Value>>);
 
 pub fn parse_predefined_type(input: &str) -> IResult<&str, PredefinedType> {
@@ -179,7 +178,8 @@ impl Format for DateTimeType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateTimeOption(Option<u8>, Option<bool>);
 
 impl Format for DateTimeOption {
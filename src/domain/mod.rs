@@ -0,0 +1,147 @@
+use crate::data_type::parse_data_type;
+use crate::data_type::DataType;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::table::constraint::parse_check_constraint;
+use crate::table::constraint::parse_constraint_name_definition;
+use crate::table::constraint::ConstraintNameDefinition;
+use crate::table::create::parse_default_clause;
+use crate::table::create::parse_table_ref;
+use crate::table::create::DefaultClause;
+use crate::table::create::TableRef;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::map_opt;
+use nom::combinator::opt;
+use nom::combinator::value;
+use nom::multi::many0;
+use nom::sequence::pair;
+use nom::sequence::tuple;
+use nom::IResult;
+
+// CREATE DOMAIN postcode AS TEXT NOT NULL CHECK (VALUE ~ '^\d{5}$')
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateDomainStatement(
+    pub TableRef,
+    pub DataType,
+    pub Option<DefaultClause>,
+    pub List<DomainConstraint>,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DomainConstraint(
+    pub Option<ConstraintNameDefinition>,
+    pub DomainConstraintKind,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DomainConstraintKind {
+    NotNull,
+    Null,
+    Check(String),
+}
+
+fn parse_domain_constraint(input: &str) -> IResult<&str, DomainConstraint> {
+    map(
+        pair(
+            opt(parse_constraint_name_definition),
+            alt((
+                value(
+                    DomainConstraintKind::NotNull,
+                    pair(parse_keyword(Keyword::Not), parse_keyword(Keyword::Null)),
+                ),
+                value(DomainConstraintKind::Null, parse_keyword(Keyword::Null)),
+                map(parse_check_constraint, DomainConstraintKind::Check),
+            )),
+        ),
+        |(n, c)| DomainConstraint(n, c),
+    )(input)
+}
+
+pub fn parse_create_domain(input: &str) -> IResult<&str, CreateDomainStatement> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Create),
+            parse_keyword(Keyword::Domain),
+            ws(parse_table_ref),
+            opt(parse_keyword(Keyword::As)),
+            parse_data_type,
+            parse_domain_clauses,
+        )),
+        |(_, _, n, _, t, (d, c))| CreateDomainStatement(n, t, d, List(c)),
+    )(input)
+}
+
+// The default and the constraints come in any order, with at most one default.
+fn parse_domain_clauses(
+    input: &str,
+) -> IResult<&str, (Option<DefaultClause>, Vec<DomainConstraint>)> {
+    map_opt(
+        many0(alt((
+            map(ws(parse_default_clause), |d| (Some(d), None)),
+            map(ws(parse_domain_constraint), |c| (None, Some(c))),
+        ))),
+        |clauses| {
+            let mut default = None;
+            let mut constraints = vec![];
+            for (d, c) in clauses {
+                if d.is_some() {
+                    if default.is_some() {
+                        return None;
+                    }
+                    default = d;
+                }
+                constraints.extend(c);
+            }
+            Some((default, constraints))
+        },
+    )(input)
+}
+
+impl Format for CreateDomainStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Create)
+            .ws()
+            .append(&Keyword::Domain)
+            .ws()
+            .append(&self.0)
+            .ws()
+            .append(&Keyword::As)
+            .ws()
+            .append_format(&self.1);
+        if let Some(d) = &self.2 {
+            f.ws().append_format(d);
+        }
+        for c in self.3 .0.iter() {
+            f.ws().append_format(c);
+        }
+        f
+    }
+}
+
+impl Format for DomainConstraint {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        if let Some(n) = &self.0 {
+            f.append_format(n).ws();
+        }
+        match &self.1 {
+            DomainConstraintKind::NotNull => f.append(&Keyword::Not).ws().append(&Keyword::Null),
+            DomainConstraintKind::Null => f.append(&Keyword::Null),
+            DomainConstraintKind::Check(c) => f
+                .append(&Keyword::Check)
+                .append_str("(")
+                .append(c)
+                .append_str(")"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
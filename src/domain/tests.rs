@@ -0,0 +1,52 @@
+use crate::assert_format;
+use crate::data_type::DataType;
+use crate::data_type::PredefinedType;
+use crate::domain::parse_create_domain;
+use crate::domain::CreateDomainStatement;
+use crate::domain::DomainConstraint;
+use crate::domain::DomainConstraintKind;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::table::create::TableRef;
+
+#[test]
+fn test_parse_create_domain() {
+    assert_eq!(
+        parse_create_domain("CREATE DOMAIN title AS text NOT NULL CHECK (length(VALUE) > 0)"),
+        Ok((
+            "",
+            CreateDomainStatement(
+                TableRef(None, Name::Name("title".to_string())),
+                DataType(PredefinedType::Text, None),
+                None,
+                List(vec!(
+                    DomainConstraint(None, DomainConstraintKind::NotNull),
+                    DomainConstraint(
+                        None,
+                        DomainConstraintKind::Check("length(VALUE) > 0".to_string())
+                    )
+                ))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_create_domain() {
+    assert_format!(
+        parse_create_domain(
+            "create domain postcode varchar(5) default '00000' constraint valid_postcode check (length(value) = 5)"
+        ),
+        "CREATE DOMAIN postcode AS VARCHAR(5) DEFAULT '00000' CONSTRAINT valid_postcode CHECK(length(value) = 5)"
+    );
+}
+
+#[test]
+fn test_format_create_domain_clause_order() {
+    assert_format!(
+        parse_create_domain("CREATE DOMAIN d AS int NOT NULL DEFAULT 0"),
+        "CREATE DOMAIN d AS INT DEFAULT 0 NOT NULL"
+    );
+    assert!(parse_create_domain("CREATE DOMAIN d AS int DEFAULT 0 NOT NULL DEFAULT 1").is_err());
+}
@@ -0,0 +1,309 @@
+use crate::format_fragment;
+use crate::FormatOptions;
+use crate::Warning;
+use regex::Regex;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Host {
+    Rust,
+    Python,
+    Markdown,
+}
+
+impl Host {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Self::Rust),
+            "py" => Some(Self::Python),
+            "md" | "markdown" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+}
+
+// How the SQL is written in the host file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    // "SELECT \"id\""
+    RustString,
+    // r#"SELECT "id""#
+    RustRawString(usize),
+    // """SELECT id"""
+    PythonString(&'static str),
+    // ```sql
+    Fence,
+}
+
+// SQL found in a host file, `start..end` are the offsets of the literal contents
+// and `indent` is the indentation of the line where it starts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snippet {
+    pub start: usize,
+    pub end: usize,
+    pub sql: String,
+    pub indent: String,
+    pub literal: Literal,
+}
+
+pub fn extract(source: &str, host: Host) -> Vec<Snippet> {
+    match host {
+        Host::Rust => rust_snippets(source),
+        Host::Python => python_snippets(source),
+        Host::Markdown => markdown_snippets(source),
+    }
+}
+
+// Formats the SQL of the snippets, snippets with unparseable SQL are left unchanged.
+pub fn format_embedded(source: &str, host: Host, options: FormatOptions) -> (String, Vec<Warning>) {
+    let mut output = String::new();
+    let mut warnings = vec![];
+    let mut offset = 0;
+    for snippet in extract(source, host) {
+        if snippet.sql.trim().is_empty() {
+            continue;
+        }
+        let (formatted, w) = format_fragment(&snippet.sql, options);
+        if !w.is_empty() {
+            warnings.extend(w.into_iter().map(|w| locate(source, &snippet, w)));
+            continue;
+        }
+        if let Some(contents) = layout(&snippet, &formatted) {
+            output.push_str(&source[offset..snippet.start]);
+            output.push_str(&contents);
+            offset = snippet.end;
+        }
+    }
+    output.push_str(&source[offset..]);
+    (output, warnings)
+}
+
+// Moves a warning from the snippet to the host file.
+fn locate(source: &str, snippet: &Snippet, warning: Warning) -> Warning {
+    let before = &source[..snippet.start];
+    let line = before.matches('\n').count() + warning.line;
+    let column = match (&snippet.literal, warning.line) {
+        (Literal::Fence, _) => warning.column + snippet.indent.chars().count(),
+        (_, 1) => {
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            warning.column + before[line_start..].chars().count()
+        }
+        _ => warning.column,
+    };
+    Warning {
+        line,
+        column,
+        statement: warning.statement,
+    }
+}
+
+// New contents of the literal, `None` when the SQL cannot be written back as is.
+fn layout(snippet: &Snippet, formatted: &str) -> Option<String> {
+    let formatted = match &snippet.literal {
+        Literal::Fence => return Some(format!("{}\n", indent_lines(formatted, &snippet.indent))),
+        Literal::RustString => formatted.replace('\\', "\\\\").replace('"', "\\\""),
+        Literal::RustRawString(hashes) => {
+            match formatted.contains(&format!("\"{}", "#".repeat(*hashes))) {
+                true => return None,
+                false => formatted.to_string(),
+            }
+        }
+        Literal::PythonString(quotes) => {
+            match formatted.contains(quotes) || formatted.contains('\\') {
+                true => return None,
+                false => formatted.to_string(),
+            }
+        }
+    };
+    match snippet.sql.contains('\n') || formatted.contains('\n') {
+        false => Some(formatted),
+        true => Some(format!(
+            "\n{}\n{}",
+            indent_lines(&formatted, &format!("{}    ", snippet.indent)),
+            snippet.indent
+        )),
+    }
+}
+
+fn indent_lines(s: &str, indent: &str) -> String {
+    s.lines()
+        .map(|l| match l.is_empty() {
+            true => String::new(),
+            false => format!("{}{}", indent, l),
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn line_indent(source: &str, offset: usize) -> String {
+    let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+    source[line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
+
+// sqlx::query!("..."), query_as!(User, r#"..."#), sqlx::query_scalar::<_, i64>("...")
+fn rust_snippets(source: &str) -> Vec<Snippet> {
+    let call = Regex::new(
+        r"\b(?:query|query_as|query_scalar)(?:_unchecked)?!?\s*(?:::<[^()]*>)?\s*\(\s*(?:[A-Za-z_][\w:]*\s*,\s*)?",
+    )
+    .unwrap();
+    call.find_iter(source)
+        .filter_map(|m| rust_literal(source, m.end()))
+        .collect()
+}
+
+fn rust_literal(source: &str, at: usize) -> Option<Snippet> {
+    let rest = &source[at..];
+    let (start, end, sql, literal) = match rest.strip_prefix('r') {
+        Some(raw) => {
+            let hashes = raw.len() - raw.trim_start_matches('#').len();
+            let start = at + 1 + hashes + 1;
+            if source.as_bytes().get(start - 1) != Some(&b'"') {
+                return None;
+            }
+            let end = start + source[start..].find(&format!("\"{}", "#".repeat(hashes)))?;
+            let sql = source[start..end].to_string();
+            (start, end, sql, Literal::RustRawString(hashes))
+        }
+        None if rest.starts_with('"') => {
+            let start = at + 1;
+            let mut sql = String::new();
+            let mut chars = source[start..].char_indices();
+            let end = loop {
+                match chars.next()? {
+                    (i, '"') => break start + i,
+                    (_, '\\') => match chars.next()?.1 {
+                        c @ ('"' | '\\' | '\'') => sql.push(c),
+                        'n' => sql.push('\n'),
+                        't' => sql.push('\t'),
+                        _ => return None,
+                    },
+                    (_, c) => sql.push(c),
+                }
+            };
+            (start, end, sql, Literal::RustString)
+        }
+        None => return None,
+    };
+    Some(Snippet {
+        start,
+        end,
+        sql,
+        indent: line_indent(source, at),
+        literal,
+    })
+}
+
+// Triple-quoted strings starting with a statement keyword, f-strings and bytes are skipped.
+fn python_snippets(source: &str) -> Vec<Snippet> {
+    let mut snippets = vec![];
+    let bytes = source.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => i = source[i..].find('\n').map_or(source.len(), |n| i + n),
+            b'"' | b'\'' => {
+                let prefix_start = source[..i]
+                    .char_indices()
+                    .rfind(|(_, c)| !c.is_alphanumeric() && *c != '_')
+                    .map_or(0, |(p, c)| p + c.len_utf8());
+                let prefix = source[prefix_start..i].to_lowercase();
+                let raw = prefix.contains('r');
+                let quote = bytes[i] as char;
+                let triple = source[i..].starts_with(&quote.to_string().repeat(3));
+                let (start, quotes) = match triple {
+                    true => (i + 3, if quote == '"' { "\"\"\"" } else { "'''" }),
+                    false => (i + 1, if quote == '"' { "\"" } else { "'" }),
+                };
+                let end = python_string_end(source, start, quotes);
+                let sql = &source[start..end];
+                let is_sql = triple
+                    && (prefix.is_empty() || prefix == "r" || prefix == "u")
+                    && (raw || !sql.contains('\\'))
+                    && looks_like_sql(sql);
+                if is_sql {
+                    snippets.push(Snippet {
+                        start,
+                        end,
+                        sql: sql.to_string(),
+                        indent: line_indent(source, i),
+                        literal: Literal::PythonString(quotes),
+                    });
+                }
+                i = (end + quotes.len()).min(source.len());
+            }
+            _ => i += 1,
+        }
+    }
+    snippets
+}
+
+fn python_string_end(source: &str, start: usize, quotes: &str) -> usize {
+    let mut i = start;
+    while i < source.len() {
+        if source[i..].starts_with('\\') {
+            i += 1 + source[i + 1..].chars().next().map_or(0, |c| c.len_utf8());
+        } else if source[i..].starts_with(quotes)
+            || (quotes.len() == 1 && source[i..].starts_with('\n'))
+        {
+            return i;
+        } else {
+            i += source[i..].chars().next().map_or(1, |c| c.len_utf8());
+        }
+    }
+    source.len()
+}
+
+// Docstrings such as "Create a user." are not SQL, keywords are all uppercase or all lowercase.
+fn looks_like_sql(s: &str) -> bool {
+    const STATEMENTS: [&str; 13] = [
+        "select", "insert", "update", "delete", "with", "create", "alter", "drop", "merge",
+        "truncate", "grant", "revoke", "comment",
+    ];
+    let word = s.split_whitespace().next().unwrap_or_default();
+    let lowercase = word.to_lowercase();
+    (word == lowercase || word == word.to_uppercase()) && STATEMENTS.contains(&lowercase.as_str())
+}
+
+// ```sql fences, the contents are dedented by the indentation of the fence.
+fn markdown_snippets(source: &str) -> Vec<Snippet> {
+    let open = Regex::new(r"(?mi)^([ \t]*)(`{3,}|~{3,})[ \t]*sql\b[^\n]*\n").unwrap();
+    let mut snippets = vec![];
+    let mut offset = 0;
+    while let Some(c) = open.captures(&source[offset..]) {
+        let indent = c.get(1).unwrap().as_str().to_string();
+        let fence = c.get(2).unwrap().as_str();
+        let start = offset + c.get(0).unwrap().end();
+        let close = Regex::new(&format!(
+            r"(?m)^[ \t]*{}{}*[ \t]*$",
+            regex::escape(fence),
+            regex::escape(&fence[..1])
+        ))
+        .unwrap();
+        let end = match close.find(&source[start..]) {
+            Some(m) => start + m.start(),
+            None => return snippets,
+        };
+        let sql = source[start..end]
+            .lines()
+            .map(|l| l.strip_prefix(indent.as_str()).unwrap_or(l.trim_start()))
+            .collect::<Vec<&str>>()
+            .join("\n");
+        snippets.push(Snippet {
+            start,
+            end,
+            sql,
+            indent,
+            literal: Literal::Fence,
+        });
+        offset = source[end..]
+            .find('\n')
+            .map_or(source.len(), |n| end + n + 1);
+    }
+    snippets
+}
+
+#[cfg(test)]
+mod tests;
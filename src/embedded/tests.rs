@@ -0,0 +1,158 @@
+use crate::embedded::extract;
+use crate::embedded::format_embedded;
+use crate::embedded::Host;
+use crate::embedded::Literal;
+use crate::FormatOptions;
+use crate::Warning;
+use std::path::Path;
+
+fn format(source: &str, host: Host) -> String {
+    let (formatted, warnings) = format_embedded(source, host, FormatOptions::default());
+    assert_eq!(warnings, vec!());
+    let (reformatted, _) = format_embedded(&formatted, host, FormatOptions::default());
+    assert_eq!(reformatted, formatted, "formatting is not idempotent");
+    formatted
+}
+
+#[test]
+fn test_host_from_path() {
+    assert_eq!(Host::from_path(Path::new("src/db.rs")), Some(Host::Rust));
+    assert_eq!(Host::from_path(Path::new("app.py")), Some(Host::Python));
+    assert_eq!(
+        Host::from_path(Path::new("README.md")),
+        Some(Host::Markdown)
+    );
+    assert_eq!(Host::from_path(Path::new("schema.sql")), None);
+}
+
+#[test]
+fn test_extract_rust() {
+    let source = r##"
+let a = sqlx::query!("select \"id\" from users where id = $1", id);
+let b = query_as!(User, r#"select * from users"#);
+let c = sqlx::query_scalar::<_, i64>("select count(*) from users");
+let d = query!(sql);
+"##;
+    let snippets: Vec<(String, Literal)> = extract(source, Host::Rust)
+        .into_iter()
+        .map(|s| (s.sql, s.literal))
+        .collect();
+    assert_eq!(
+        snippets,
+        vec!(
+            (
+                "select \"id\" from users where id = $1".to_string(),
+                Literal::RustString
+            ),
+            ("select * from users".to_string(), Literal::RustRawString(1)),
+            (
+                "select count(*) from users".to_string(),
+                Literal::RustString
+            ),
+        )
+    );
+}
+
+#[test]
+fn test_format_rust() {
+    let source = r#"fn titles() {
+    let titles = sqlx::query!("select title from movies where year > ?")
+        .fetch_all(&pool);
+    let one = sqlx::query!("SELECT 1");
+}
+"#;
+    assert_eq!(
+        format(source, Host::Rust),
+        r#"fn titles() {
+    let titles = sqlx::query!("
+        SELECT title
+          FROM movies
+         WHERE year > ?
+    ")
+        .fetch_all(&pool);
+    let one = sqlx::query!("SELECT 1");
+}
+"#
+    );
+}
+
+#[test]
+fn test_format_quoted_identifier() {
+    let source = r##"let a = query!(r#"SELECT "id""#);
+let b = query!("select \"id\", \"Title\" from \"Movies\"");
+"##;
+    assert_eq!(
+        format(source, Host::Rust),
+        r##"let a = query!(r#"SELECT "id""#);
+let b = query!("
+    SELECT \"id\", \"Title\"
+      FROM \"Movies\"
+");
+"##
+    );
+}
+
+#[test]
+fn test_format_python() {
+    let source = r#"def titles(cursor):
+    """Select the titles."""
+    cursor.execute("""select title from movies where year > 1999""")
+    return cursor.execute(f"""select {column} from movies""")
+"#;
+    assert_eq!(
+        format(source, Host::Python),
+        r#"def titles(cursor):
+    """Select the titles."""
+    cursor.execute("""
+        SELECT title
+          FROM movies
+         WHERE year > 1999
+    """)
+    return cursor.execute(f"""select {column} from movies""")
+"#
+    );
+}
+
+#[test]
+fn test_format_python_non_ascii() {
+    let source = "x = \"caf\\é\"\ny = “\"\"\"select 1\"\"\"\n";
+    assert_eq!(
+        format(source, Host::Python),
+        "x = \"caf\\é\"\ny = “\"\"\"SELECT 1\"\"\"\n"
+    );
+}
+
+#[test]
+fn test_format_markdown() {
+    let source = "# Movies\n\n- Titles:\n  ```sql\n  select title from movies;\n  ```\n\n```python\nselect\n```\n";
+    assert_eq!(
+        format(source, Host::Markdown),
+        "# Movies\n\n- Titles:\n  ```sql\n  SELECT title\n    FROM movies;\n  ```\n\n```python\nselect\n```\n"
+    );
+}
+
+#[test]
+fn test_format_untouched() {
+    let source = "```sql\nSELECT title\n  FROM movies;\n```\n\nSome text.\n";
+    assert_eq!(format(source, Host::Markdown), source);
+    let source = "let q = sqlx::query!(\"SELECT 1\");\n";
+    assert_eq!(format(source, Host::Rust), source);
+}
+
+#[test]
+fn test_format_embedded_warnings() {
+    let source = "Intro\n\n  ```sql\n  SELECT 1;\n  VACUUM movies;\n  ```\n";
+    let (formatted, warnings) = format_embedded(source, Host::Markdown, FormatOptions::default());
+    assert_eq!(formatted, source);
+    assert_eq!(
+        warnings,
+        vec!(Warning {
+            line: 5,
+            column: 3,
+            statement: "VACUUM movies;".to_string()
+        })
+    );
+    let source = "x = sqlx::query!(\"VACUUM movies\");";
+    let (_, warnings) = format_embedded(source, Host::Rust, FormatOptions::default());
+    assert_eq!((warnings[0].line, warnings[0].column), (1, 19));
+}
@@ -4,11 +4,13 @@ use crate::character::parse_right_parenthesis;
 use crate::clause::Clause;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
+use crate::function::parse_aggregate_function;
 use crate::keyword::is_keyword;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
 use crate::list::List;
 use crate::term::parse_term;
+use crate::term::value::parse_value;
 use crate::term::Term;
 use crate::ws::ws;
 use nom::branch::alt;
@@ -17,26 +19,33 @@ use nom::combinator::map;
 use nom::combinator::not;
 use nom::combinator::peek;
 use nom::combinator::value;
-use nom::multi::many1;
+use nom::multi::many0;
 use nom::multi::separated_list0;
 use nom::sequence::delimited;
+use nom::sequence::pair;
 use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
+use nom::Parser;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Condition {
     Operand(Operand),
     BinaryExpression(Operand, RightOperand),
+    // (a = b OR c = d)
+    Parenthesis(List<Expression>),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Operand {
     Term(Term),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RightOperand {
     Compare(Compare, Operand),
     In(InPredicateValue),
@@ -45,7 +54,8 @@ pub enum RightOperand {
     Null(bool),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InPredicateValue {
     Subquery(Operand),
     InValueList(List<Operand>),
@@ -144,9 +154,15 @@ impl Format for Operand {
     }
 }
 
+// Aggregate functions are tried first, COUNT is a reserved keyword.
 pub fn operand(input: &str) -> IResult<&str, Operand> {
-    let (input, _) = peek(not(is_keyword))(input)?;
-    map(parse_term, Operand::Term)(input)
+    alt((
+        map(parse_aggregate_function, |f| {
+            Operand::Term(Term::Function(f))
+        }),
+        map(parse_value, |v| Operand::Term(Term::Value(v))),
+        map(preceded(peek(not(is_keyword)), parse_term), Operand::Term),
+    ))(input)
 }
 
 pub fn compare(input: &str) -> IResult<&str, Compare> {
@@ -160,7 +176,8 @@ pub fn compare(input: &str) -> IResult<&str, Compare> {
     ))(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Compare {
     GreaterThan,
     GreaterOrEqual,
@@ -183,7 +200,8 @@ impl fmt::Display for Compare {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Expression {
     Condition(Condition),
     AndExpression(Condition),
@@ -191,16 +209,31 @@ pub enum Expression {
 }
 
 pub fn condition(input: &str) -> IResult<&str, Condition> {
-    //Operand, Compare, Operand
     alt((
-        map(tuple((ws(operand), right_operand)), |(left, right)| {
-            Condition::BinaryExpression(left, right)
-        }),
-        map(ws(operand), Condition::Operand),
+        map(
+            delimited(
+                ws(parse_left_parenthesis),
+                parse_expressions,
+                ws(parse_right_parenthesis),
+            ),
+            Condition::Parenthesis,
+        ),
+        binary_condition,
     ))(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
+fn binary_condition(input: &str) -> IResult<&str, Condition> {
+    //Operand, Compare, Operand
+    let (input, left) = ws(operand).parse(input)?;
+    match right_operand(input) {
+        Ok((input, right)) => Ok((input, Condition::BinaryExpression(left, right))),
+        Err(nom::Err::Error(_)) => Ok((input, Condition::Operand(left))),
+        Err(e) => Err(e),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WhereClause(pub List<Expression>);
 
 impl Clause for WhereClause {
@@ -218,10 +251,28 @@ impl Format for Condition {
         match self {
             Self::Operand(o) => f.append_format(o),
             Self::BinaryExpression(l, r) => f.append_format(l).ws().append_format(r),
+            Self::Parenthesis(e) => e.format_inline(f.append(&"(")).append(&")"),
         }
     }
 }
 
+impl List<Expression> {
+    // On a single line, for MERGE and trigger WHEN conditions.
+    pub fn format_inline<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        for (pos, e) in self.0.iter().enumerate() {
+            if pos > 0 {
+                f.ws();
+            }
+            match e {
+                Expression::Condition(c) => f.append_format(c),
+                Expression::AndExpression(c) => f.append(&Keyword::And).ws().append_format(c),
+                Expression::OrExpression(c) => f.append(&Keyword::Or).ws().append_format(c),
+            };
+        }
+        f
+    }
+}
+
 impl Format for Expression {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         match self {
@@ -233,6 +284,13 @@ impl Format for Expression {
 }
 
 pub fn parse_expression(input: &str) -> IResult<&str, Expression> {
+    alt((
+        parse_logical_expression,
+        map(ws(condition), Expression::Condition),
+    ))(input)
+}
+
+fn parse_logical_expression(input: &str) -> IResult<&str, Expression> {
     alt((
         map(
             preceded(parse_keyword(Keyword::And), condition),
@@ -242,12 +300,15 @@ pub fn parse_expression(input: &str) -> IResult<&str, Expression> {
             preceded(parse_keyword(Keyword::Or), condition),
             Expression::OrExpression,
         ),
-        map(ws(condition), Expression::Condition),
     ))(input)
 }
 
+// Conditions after the first one need AND or OR, a word following a condition ends it.
 pub fn parse_expressions(input: &str) -> IResult<&str, List<Expression>> {
-    map(many1(parse_expression), List)(input)
+    map(
+        pair(parse_expression, many0(parse_logical_expression)),
+        |(first, others)| List([vec![first], others].concat()),
+    )(input)
 }
 
 pub fn parse_where_clause(input: &str) -> IResult<&str, WhereClause> {
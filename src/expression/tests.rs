@@ -220,7 +220,7 @@ fn test_format_in_predicate_value() {
 fn test_format_in_predicate_value_subquery() {
     assert_format!(
         parse_in_predicate_value("(SELECT id from users)"),
-        "\n (        SELECT id\n          FROM users)"
+        "\n (SELECT id\n    FROM users)"
     )
 }
 
@@ -252,3 +252,11 @@ fn test_format_like() {
 fn test_format_not_null() {
     assert_format!(parse_expressions("album IS not null"), "album IS NOT NULL")
 }
+
+#[test]
+fn test_format_parenthesized_condition() {
+    assert_format!(
+        parse_expressions("(genre = 'drama' or genre = 'crime') and year > 1990"),
+        "(genre = 'drama' OR genre = 'crime')\nAND year > 1990"
+    )
+}
@@ -0,0 +1,128 @@
+use crate::character::parse_comma;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::schema::format_if_exists;
+use crate::schema::format_if_not_exists;
+use crate::schema::parse_if_exists;
+use crate::schema::parse_if_not_exists;
+use crate::table::drop_table::parse_drop_behavior;
+use crate::table::drop_table::DropBehavior;
+use crate::term::value::parse_value;
+use crate::term::value::Value;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::opt;
+use nom::combinator::value;
+use nom::multi::many0;
+use nom::multi::separated_list1;
+use nom::sequence::preceded;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExtensionStatement {
+    // CREATE EXTENSION IF NOT EXISTS pgcrypto WITH SCHEMA public
+    Create(bool, Name, List<ExtensionOption>),
+    // DROP EXTENSION IF EXISTS pgcrypto CASCADE
+    Drop(bool, List<Name>, Option<DropBehavior>),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExtensionOption {
+    Schema(Name),
+    Version(Value),
+    Cascade,
+}
+
+fn parse_extension_option(input: &str) -> IResult<&str, ExtensionOption> {
+    alt((
+        map(
+            preceded(parse_keyword(Keyword::Schema), ws(parse_name)),
+            ExtensionOption::Schema,
+        ),
+        map(
+            preceded(parse_keyword(Keyword::Version), ws(parse_value)),
+            ExtensionOption::Version,
+        ),
+        value(ExtensionOption::Cascade, parse_keyword(Keyword::Cascade)),
+    ))(input)
+}
+
+pub fn parse_extension_statement(input: &str) -> IResult<&str, ExtensionStatement> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::Create),
+                parse_keyword(Keyword::Extension),
+                parse_if_not_exists,
+                ws(parse_name),
+                opt(parse_keyword(Keyword::With)),
+                many0(parse_extension_option),
+            )),
+            |(_, _, e, n, _, o)| ExtensionStatement::Create(e, n, List(o)),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Drop),
+                parse_keyword(Keyword::Extension),
+                parse_if_exists,
+                separated_list1(parse_comma, ws(parse_name)),
+                opt(parse_drop_behavior),
+            )),
+            |(_, _, e, n, b)| ExtensionStatement::Drop(e, List(n), b),
+        ),
+    ))(input)
+}
+
+impl Format for ExtensionStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Create(e, n, o) => {
+                f.append(&Keyword::Create)
+                    .ws()
+                    .append(&Keyword::Extension)
+                    .ws();
+                format_if_not_exists(f, *e).append(n);
+                if !o.0.is_empty() {
+                    f.ws().append(&Keyword::With);
+                }
+                for i in o.0.iter() {
+                    f.ws().append_format(i);
+                }
+                f
+            }
+            Self::Drop(e, n, b) => {
+                f.append(&Keyword::Drop)
+                    .ws()
+                    .append(&Keyword::Extension)
+                    .ws();
+                format_if_exists(f, *e).append(n);
+                match b {
+                    Some(b) => f.ws().append(b),
+                    None => f,
+                }
+            }
+        }
+    }
+}
+
+impl Format for ExtensionOption {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Schema(s) => f.append(&Keyword::Schema).ws().append(s),
+            Self::Version(v) => f.append(&Keyword::Version).ws().append_format(v),
+            Self::Cascade => f.append(&Keyword::Cascade),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
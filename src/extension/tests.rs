@@ -0,0 +1,44 @@
+use crate::assert_format;
+use crate::extension::parse_extension_statement;
+use crate::extension::ExtensionOption;
+use crate::extension::ExtensionStatement;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::term::value::Value;
+
+#[test]
+fn test_parse_extension_statement() {
+    let input = "CREATE EXTENSION IF NOT EXISTS pgcrypto WITH SCHEMA public VERSION '1.3' CASCADE";
+    assert_eq!(
+        parse_extension_statement(input),
+        Ok((
+            "",
+            ExtensionStatement::Create(
+                true,
+                Name::Name("pgcrypto".to_string()),
+                List(vec!(
+                    ExtensionOption::Schema(Name::Name("public".to_string())),
+                    ExtensionOption::Version(Value::String("1.3".to_string())),
+                    ExtensionOption::Cascade
+                ))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_extension_statement() {
+    assert_format!(
+        parse_extension_statement("create extension pgcrypto schema public"),
+        "CREATE EXTENSION pgcrypto WITH SCHEMA public"
+    );
+    assert_format!(
+        parse_extension_statement("create extension postgis"),
+        "CREATE EXTENSION postgis"
+    );
+    assert_format!(
+        parse_extension_statement("drop extension if exists pgcrypto, postgis cascade"),
+        "DROP EXTENSION IF EXISTS pgcrypto, postgis CASCADE"
+    );
+}
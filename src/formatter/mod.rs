@@ -1,31 +1,62 @@
 use pad::{Alignment, PadStr};
 use std::fmt;
 
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FormatOptions {
+    // Pads names, types and constraints of column lists into aligned columns.
+    pub align_columns: bool,
+    // Rewrites TOP, LIMIT and FETCH FIRST with the syntax of the dialect.
+    pub dialect: Dialect,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Dialect {
+    // Clauses are kept as written.
+    #[default]
+    Generic,
+    Ansi,
+    Postgres,
+    MySql,
+    SqlServer,
+}
+
 #[derive(Debug, Clone)]
 pub struct Formatter {
     pub pad: usize,
     pub depth: usize,
     pub offset: usize,
+    pub options: FormatOptions,
     buffer: String,
 }
 
 impl Formatter {
     fn new() -> Formatter {
+        Formatter::with_options(FormatOptions::default())
+    }
+
+    pub fn with_options(options: FormatOptions) -> Formatter {
         Formatter {
             pad: 0,
             offset: 0,
             depth: 0,
+            options,
             buffer: String::from(""),
         }
     }
 
+    // Formats `s` on its own, with the same options.
+    pub fn render(&self, s: &dyn Format) -> String {
+        let mut f = Formatter::with_options(self.options);
+        s.format(&mut f).output()
+    }
+
     pub fn set_offset(&mut self, x: usize) -> &mut Self {
         self.offset = x;
         self
     }
 
     pub fn pop_context(&mut self) -> &mut Self {
-        self.depth -= 1;
+        self.depth = self.depth.saturating_sub(1);
         self
     }
 
@@ -48,8 +79,9 @@ impl Formatter {
         self
     }
 
+    // Spaces written before the line break are dropped.
     pub fn new_line(&mut self) -> &mut Self {
-        self.buffer = format!("{}\n", self.buffer);
+        self.buffer = format!("{}\n", self.buffer.trim_end_matches(' '));
         self
     }
 
@@ -106,6 +138,28 @@ impl Formatter {
         s.format(self)
     }
 
+    pub fn column(&self) -> usize {
+        match self.buffer.rfind('\n') {
+            Some(i) => self.buffer[i + 1..].chars().count(),
+            None => self.buffer.chars().count(),
+        }
+    }
+
+    // Blank lines stay empty.
+    pub fn append_indented(&mut self, s: &dyn Format) -> &mut Self {
+        let indent = " ".repeat(self.column());
+        let lines: Vec<String> = self
+            .render(s)
+            .split('\n')
+            .enumerate()
+            .map(|(i, l)| match i == 0 || l.is_empty() {
+                true => l.to_string(),
+                false => format!("{indent}{l}"),
+            })
+            .collect();
+        self.append(&lines.join("\n"))
+    }
+
     pub fn indent(&mut self, s: &dyn Format) -> &mut Self {
         self.append(&"    ");
         s.format(self)
@@ -152,6 +206,11 @@ pub trait Format {
         let mut f = Formatter::new();
         self.format(&mut f).output()
     }
+
+    fn output_with(&self, options: FormatOptions) -> String {
+        let mut f = Formatter::with_options(options);
+        self.format(&mut f).output()
+    }
 }
 
 impl<T: std::fmt::Display> Format for T {
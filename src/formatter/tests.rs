@@ -31,3 +31,22 @@ fn test_format_padd() {
         "SELECT r.last_name,      (SELECT MAX(YEAR(championship_date))"
     )
 }
+
+#[test]
+fn test_format_indented() {
+    let mut f = Formatter::new();
+    assert_eq!(
+        f.append_str("SET (")
+            .append_indented(&"a,\nb")
+            .append_str(")")
+            .output(),
+        "SET (a,\n     b)"
+    )
+}
+
+#[test]
+fn test_pop_context_without_context() {
+    let mut f = Formatter::new();
+    f.pop_context();
+    assert_eq!(f.depth, 0)
+}
@@ -0,0 +1,464 @@
+use crate::character::parse_comma;
+use crate::character::parse_left_parenthesis;
+use crate::character::parse_right_parenthesis;
+use crate::data_type::parse_data_type;
+use crate::data_type::DataType;
+use crate::formatter::Format;
+use crate::formatter::FormatOptions;
+use crate::formatter::Formatter;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::query::parse_query;
+use crate::query::parse_statements;
+use crate::table::create::parse_table_ref;
+use crate::table::create::TableRef;
+use crate::term::value::parse_value;
+use crate::term::value::Value;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::bytes::complete::take_until;
+use nom::bytes::complete::take_while;
+use nom::character::complete::char;
+use nom::character::complete::satisfy;
+use nom::combinator::map;
+use nom::combinator::map_opt;
+use nom::combinator::opt;
+use nom::combinator::recognize;
+use nom::combinator::value;
+use nom::multi::many0;
+use nom::multi::separated_list0;
+use nom::sequence::delimited;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::separated_pair;
+use nom::sequence::tuple;
+use nom::IResult;
+
+// CREATE OR REPLACE FUNCTION add(a integer, b integer) RETURNS integer LANGUAGE sql AS $$ SELECT a + b; $$
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateFunctionStatement(
+    pub bool,
+    pub RoutineKind,
+    pub TableRef,
+    pub List<FunctionParameter>,
+    pub Option<ReturnType>,
+    pub List<RoutineOption>,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoutineKind {
+    Function,
+    Procedure,
+}
+
+// INOUT total integer DEFAULT 0
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FunctionParameter(
+    pub Option<ParameterMode>,
+    pub Option<Name>,
+    pub DataType,
+    pub Option<Value>,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParameterMode {
+    In,
+    Out,
+    Inout,
+    Variadic,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReturnType {
+    Type(DataType),
+    SetOf(DataType),
+    // SETOF movies, the rows of a table or of a user-defined type
+    SetOfNamed(TableRef),
+    Table(List<FunctionParameter>),
+    Trigger,
+    Void,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoutineOption {
+    Language(Name),
+    Volatility(Volatility),
+    Strict,
+    Security(Security),
+    Body(RoutineBody),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Volatility {
+    Immutable,
+    Stable,
+    Volatile,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Security {
+    Definer,
+    Invoker,
+}
+
+// $body$ BEGIN RETURN NEW; END; $body$
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoutineBody {
+    DollarQuoted(String, String),
+    Quoted(String),
+}
+
+impl RoutineKind {
+    pub fn keyword(&self) -> Keyword {
+        match self {
+            Self::Function => Keyword::Function,
+            Self::Procedure => Keyword::Procedure,
+        }
+    }
+}
+
+impl CreateFunctionStatement {
+    pub fn is_sql(&self) -> bool {
+        self.5 .0.iter().any(|o| match o {
+            RoutineOption::Language(Name::Name(l) | Name::QuotedName(l)) => {
+                l.eq_ignore_ascii_case("sql")
+            }
+            _ => false,
+        })
+    }
+}
+
+pub fn parse_routine_kind(input: &str) -> IResult<&str, RoutineKind> {
+    alt((
+        value(RoutineKind::Function, parse_keyword(Keyword::Function)),
+        value(RoutineKind::Procedure, parse_keyword(Keyword::Procedure)),
+    ))(input)
+}
+
+fn parse_parameter_mode(input: &str) -> IResult<&str, ParameterMode> {
+    alt((
+        value(ParameterMode::Inout, parse_keyword(Keyword::Inout)),
+        value(ParameterMode::In, parse_keyword(Keyword::In)),
+        value(ParameterMode::Out, parse_keyword(Keyword::Out)),
+        value(ParameterMode::Variadic, parse_keyword(Keyword::Variadic)),
+    ))(input)
+}
+
+pub fn parse_function_parameter(input: &str) -> IResult<&str, FunctionParameter> {
+    map(
+        tuple((
+            opt(parse_parameter_mode),
+            alt((
+                map(pair(ws(parse_name), parse_data_type), |(n, t)| (Some(n), t)),
+                map(parse_data_type, |t| (None, t)),
+            )),
+            opt(preceded(
+                alt((parse_keyword(Keyword::Default), value((), ws(tag("="))))),
+                ws(parse_value),
+            )),
+        )),
+        |(m, (n, t), d)| FunctionParameter(m, n, t, d),
+    )(input)
+}
+
+fn parse_function_parameters(input: &str) -> IResult<&str, List<FunctionParameter>> {
+    map(
+        delimited(
+            parse_left_parenthesis,
+            separated_list0(parse_comma, ws(parse_function_parameter)),
+            parse_right_parenthesis,
+        ),
+        List,
+    )(input)
+}
+
+pub fn parse_return_type(input: &str) -> IResult<&str, ReturnType> {
+    alt((
+        value(ReturnType::Trigger, parse_keyword(Keyword::Trigger)),
+        value(ReturnType::Void, parse_keyword(Keyword::Void)),
+        preceded(
+            parse_keyword(Keyword::Setof),
+            alt((
+                map(parse_data_type, ReturnType::SetOf),
+                map(ws(parse_table_ref), ReturnType::SetOfNamed),
+            )),
+        ),
+        map(
+            preceded(parse_keyword(Keyword::Table), parse_function_parameters),
+            ReturnType::Table,
+        ),
+        map(parse_data_type, ReturnType::Type),
+    ))(input)
+}
+
+fn parse_dollar_quoted(input: &str) -> IResult<&str, RoutineBody> {
+    let (input, t) = delimited(
+        char('$'),
+        take_while(|c: char| c.is_alphanumeric() || c == '_'),
+        char('$'),
+    )(input)?;
+    let delimiter = format!("${t}$");
+    let (input, body) = take_until(delimiter.as_str())(input)?;
+    let (input, _) = tag(delimiter.as_str())(input)?;
+    Ok((
+        input,
+        RoutineBody::DollarQuoted(t.to_string(), body.to_string()),
+    ))
+}
+
+pub fn parse_routine_body(input: &str) -> IResult<&str, RoutineBody> {
+    alt((
+        ws(parse_dollar_quoted),
+        map_opt(ws(parse_value), |v| match v {
+            Value::String(s) => Some(RoutineBody::Quoted(s)),
+            _ => None,
+        }),
+    ))(input)
+}
+
+pub fn parse_routine_option(input: &str) -> IResult<&str, RoutineOption> {
+    alt((
+        map(
+            preceded(parse_keyword(Keyword::Language), ws(parse_name)),
+            RoutineOption::Language,
+        ),
+        map(
+            alt((
+                value(Volatility::Immutable, parse_keyword(Keyword::Immutable)),
+                value(Volatility::Stable, parse_keyword(Keyword::Stable)),
+                value(Volatility::Volatile, parse_keyword(Keyword::Volatile)),
+            )),
+            RoutineOption::Volatility,
+        ),
+        value(RoutineOption::Strict, parse_keyword(Keyword::Strict)),
+        map(
+            preceded(
+                parse_keyword(Keyword::Security),
+                alt((
+                    value(Security::Definer, parse_keyword(Keyword::Definer)),
+                    value(Security::Invoker, parse_keyword(Keyword::Invoker)),
+                )),
+            ),
+            RoutineOption::Security,
+        ),
+        map(
+            preceded(parse_keyword(Keyword::As), parse_routine_body),
+            RoutineOption::Body,
+        ),
+    ))(input)
+}
+
+// Routine names can be keywords, as `add` or `count`.
+fn parse_routine_name_part(input: &str) -> IResult<&str, Name> {
+    alt((
+        parse_name,
+        map(
+            recognize(pair(
+                satisfy(|c: char| c.is_alphabetic() || c == '_'),
+                take_while(|c: char| c.is_alphanumeric() || c == '_'),
+            )),
+            |s: &str| Name::Name(s.to_string()),
+        ),
+    ))(input)
+}
+
+pub fn parse_routine_name(input: &str) -> IResult<&str, TableRef> {
+    alt((
+        map(
+            separated_pair(parse_routine_name_part, char('.'), parse_routine_name_part),
+            |(s, n)| TableRef(Some(s), n),
+        ),
+        map(parse_routine_name_part, |n| TableRef(None, n)),
+    ))(input)
+}
+
+pub fn parse_create_function(input: &str) -> IResult<&str, CreateFunctionStatement> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Create),
+            opt(pair(
+                parse_keyword(Keyword::Or),
+                parse_keyword(Keyword::Replace),
+            )),
+            parse_routine_kind,
+            ws(parse_routine_name),
+            parse_function_parameters,
+            opt(preceded(parse_keyword(Keyword::Returns), parse_return_type)),
+            many0(parse_routine_option),
+        )),
+        |(_, r, k, n, p, t, o)| CreateFunctionStatement(r.is_some(), k, n, p, t, List(o)),
+    )(input)
+}
+
+impl RoutineBody {
+    pub fn content(&self) -> &str {
+        match self {
+            Self::DollarQuoted(_, c) => c,
+            Self::Quoted(c) => c,
+        }
+    }
+
+    // The original tag is kept unless the content contains it, `$$` for quoted bodies.
+    fn dollar_tag(&self, content: &str) -> String {
+        let mut tag = match self {
+            Self::DollarQuoted(t, _) => t.clone(),
+            Self::Quoted(_) => String::new(),
+        };
+        let mut i = 0;
+        while content.contains(&format!("${tag}$")) {
+            i += 1;
+            tag = format!("body{i}");
+        }
+        tag
+    }
+
+    // Formats the body of a LANGUAGE sql routine, None when it cannot be parsed.
+    pub fn format_sql(&self, options: FormatOptions) -> Option<String> {
+        let content = self.content().trim();
+        if let Ok((rest, s)) = parse_statements(content) {
+            if rest.trim().is_empty() {
+                return Some(s.output_with(options));
+            }
+        }
+        match parse_query(content) {
+            Ok((rest, q)) if rest.trim().is_empty() => Some(q.output_with(options)),
+            _ => None,
+        }
+    }
+}
+
+impl Format for CreateFunctionStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Create).ws();
+        if self.0 {
+            f.append(&Keyword::Or).ws().append(&Keyword::Replace).ws();
+        }
+        f.append(&self.1.keyword())
+            .ws()
+            .append(&self.2)
+            .append_str("(")
+            .append_format(&self.3)
+            .append_str(")");
+        if let Some(t) = &self.4 {
+            f.new_line().append(&Keyword::Returns).ws().append_format(t);
+        }
+        for o in self.5 .0.iter() {
+            f.new_line();
+            match o {
+                RoutineOption::Body(b) => match (self.is_sql(), b.format_sql(f.options)) {
+                    (true, Some(s)) => {
+                        let delimiter = format!("${}$", b.dollar_tag(&s));
+                        f.append(&Keyword::As)
+                            .ws()
+                            .append_str(&delimiter)
+                            .append_str("\n")
+                            .append_str(&s)
+                            .append_str("\n")
+                            .append_str(&delimiter)
+                    }
+                    _ => f.append(&Keyword::As).ws().append_format(b),
+                },
+                _ => f.append_format(o),
+            };
+        }
+        f
+    }
+}
+
+impl Format for FunctionParameter {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        if let Some(m) = &self.0 {
+            f.append_format(m).ws();
+        }
+        if let Some(n) = &self.1 {
+            f.append(n).ws();
+        }
+        f.append_format(&self.2);
+        match &self.3 {
+            Some(d) => f.ws().append(&Keyword::Default).ws().append_format(d),
+            None => f,
+        }
+    }
+}
+
+impl Format for List<FunctionParameter> {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        for (pos, i) in self.0.iter().enumerate() {
+            match pos {
+                0 => f.append_format(i),
+                _ => f.append_str(", ").append_format(i),
+            };
+        }
+        f
+    }
+}
+
+impl Format for ParameterMode {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::In => f.append(&Keyword::In),
+            Self::Out => f.append(&Keyword::Out),
+            Self::Inout => f.append(&Keyword::Inout),
+            Self::Variadic => f.append(&Keyword::Variadic),
+        }
+    }
+}
+
+impl Format for ReturnType {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Type(t) => f.append_format(t),
+            Self::SetOf(t) => f.append(&Keyword::Setof).ws().append_format(t),
+            Self::SetOfNamed(t) => f.append(&Keyword::Setof).ws().append(t),
+            Self::Table(c) => f
+                .append(&Keyword::Table)
+                .ws()
+                .append_str("(")
+                .append_format(c)
+                .append_str(")"),
+            Self::Trigger => f.append(&Keyword::Trigger),
+            Self::Void => f.append(&Keyword::Void),
+        }
+    }
+}
+
+impl Format for RoutineOption {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Language(l) => f.append(&Keyword::Language).ws().append(l),
+            Self::Volatility(Volatility::Immutable) => f.append(&Keyword::Immutable),
+            Self::Volatility(Volatility::Stable) => f.append(&Keyword::Stable),
+            Self::Volatility(Volatility::Volatile) => f.append(&Keyword::Volatile),
+            Self::Strict => f.append(&Keyword::Strict),
+            Self::Security(Security::Definer) => {
+                f.append(&Keyword::Security).ws().append(&Keyword::Definer)
+            }
+            Self::Security(Security::Invoker) => {
+                f.append(&Keyword::Security).ws().append(&Keyword::Invoker)
+            }
+            Self::Body(b) => f.append(&Keyword::As).ws().append_format(b),
+        }
+    }
+}
+
+impl Format for RoutineBody {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::DollarQuoted(t, c) => f.append_str(&format!("${t}${c}${t}$")),
+            Self::Quoted(c) => f.append(&Value::String(c.clone())),
+        }
+    }
+}
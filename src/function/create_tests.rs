@@ -0,0 +1,203 @@
+use crate::assert_format;
+use crate::data_type::DataType;
+use crate::data_type::PredefinedType;
+use crate::formatter::Dialect;
+use crate::formatter::Format;
+use crate::formatter::FormatOptions;
+use crate::function::create::parse_create_function;
+use crate::function::create::parse_function_parameter;
+use crate::function::create::parse_routine_body;
+use crate::function::create::CreateFunctionStatement;
+use crate::function::create::FunctionParameter;
+use crate::function::create::ParameterMode;
+use crate::function::create::ReturnType;
+use crate::function::create::RoutineBody;
+use crate::function::create::RoutineKind;
+use crate::function::create::RoutineOption;
+use crate::function::create::Volatility;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::numeric::Numeric;
+use crate::table::create::TableRef;
+use crate::term::value::Value;
+
+#[test]
+fn test_parse_function_parameter() {
+    assert_eq!(
+        parse_function_parameter("INOUT total integer DEFAULT 0"),
+        Ok((
+            "",
+            FunctionParameter(
+                Some(ParameterMode::Inout),
+                Some(Name::Name("total".to_string())),
+                DataType(PredefinedType::Integer, None),
+                Some(Value::Num(Numeric::Int(0)))
+            )
+        ))
+    );
+    assert_eq!(
+        parse_function_parameter("text"),
+        Ok((
+            "",
+            FunctionParameter(None, None, DataType(PredefinedType::Text, None), None)
+        ))
+    );
+}
+
+#[test]
+fn test_parse_routine_body() {
+    assert_eq!(
+        parse_routine_body("$body$ SELECT '$$'; $body$"),
+        Ok((
+            "",
+            RoutineBody::DollarQuoted("body".to_string(), " SELECT '$$'; ".to_string())
+        ))
+    );
+    assert_eq!(
+        parse_routine_body("'SELECT 1'"),
+        Ok(("", RoutineBody::Quoted("SELECT 1".to_string())))
+    );
+    assert!(parse_routine_body("42").is_err());
+    assert!(parse_routine_body("true").is_err());
+}
+
+#[test]
+fn test_parse_create_function() {
+    let input = "CREATE OR REPLACE FUNCTION one() RETURNS integer LANGUAGE sql IMMUTABLE AS $$ SELECT 1; $$";
+    assert_eq!(
+        parse_create_function(input),
+        Ok((
+            "",
+            CreateFunctionStatement(
+                true,
+                RoutineKind::Function,
+                TableRef(None, Name::Name("one".to_string())),
+                List(vec!()),
+                Some(ReturnType::Type(DataType(PredefinedType::Integer, None))),
+                List(vec!(
+                    RoutineOption::Language(Name::Name("sql".to_string())),
+                    RoutineOption::Volatility(Volatility::Immutable),
+                    RoutineOption::Body(RoutineBody::DollarQuoted(
+                        "".to_string(),
+                        " SELECT 1; ".to_string()
+                    ))
+                ))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_create_function_sql_body() {
+    assert_format!(
+        parse_create_function(
+            "create function movie_title(movie_id integer) returns text language sql stable as $$select title from movies where id = movie_id;$$"
+        ),
+        "CREATE FUNCTION movie_title(movie_id INTEGER)
+RETURNS TEXT
+LANGUAGE sql
+STABLE
+AS $$
+SELECT title
+  FROM movies
+ WHERE id = movie_id;
+$$"
+    );
+}
+
+#[test]
+fn test_format_create_function_dollar_tag() {
+    assert_format!(
+        parse_create_function(
+            "create function dollars() returns text language sql as $body$ select '$$' $body$"
+        ),
+        "CREATE FUNCTION dollars()
+RETURNS TEXT
+LANGUAGE sql
+AS $body$
+SELECT '$$'
+$body$"
+    );
+    assert_format!(
+        parse_create_function(
+            "create function dollars() returns text language sql as 'select 1 as \"$$\"'"
+        ),
+        "CREATE FUNCTION dollars()
+RETURNS TEXT
+LANGUAGE sql
+AS $body1$
+SELECT 1 AS \"$$\"
+$body1$"
+    );
+}
+
+#[test]
+fn test_format_create_function_opaque_body() {
+    assert_format!(
+        parse_create_function(
+            "create function touch() returns trigger as $$\nBEGIN\n  RETURN NEW;\nEND;\n$$ language plpgsql security definer"
+        ),
+        "CREATE FUNCTION touch()
+RETURNS TRIGGER
+AS $$
+BEGIN
+  RETURN NEW;
+END;
+$$
+LANGUAGE plpgsql
+SECURITY DEFINER"
+    );
+}
+
+#[test]
+fn test_format_create_procedure() {
+    assert_format!(
+        parse_create_function(
+            "create procedure reset_marks(in student integer, variadic text) language plpgsql as 'BEGIN END'"
+        ),
+        "CREATE PROCEDURE reset_marks(IN student INTEGER, VARIADIC TEXT)
+LANGUAGE plpgsql
+AS 'BEGIN END'"
+    );
+}
+
+#[test]
+fn test_parse_create_function_keyword_name() {
+    let input = "CREATE FUNCTION add(a int, b int) RETURNS int LANGUAGE sql AS 'SELECT a + b'";
+    let (_, f) = parse_create_function(input).unwrap();
+    assert_eq!(f.2, TableRef(None, Name::Name("add".to_string())));
+    let (_, f) =
+        parse_create_function("CREATE FUNCTION public.count() RETURNS int AS 'x'").unwrap();
+    assert_eq!(
+        f.2,
+        TableRef(
+            Some(Name::Name("public".to_string())),
+            Name::Name("count".to_string())
+        )
+    );
+}
+
+#[test]
+fn test_format_create_function_setof_type() {
+    assert_format!(
+        parse_create_function(
+            "create function recent() returns setof public.movies language sql as 'select * from movies'"
+        ),
+        "CREATE FUNCTION recent()\nRETURNS SETOF public.movies\nLANGUAGE sql\nAS $$\nSELECT *\n  FROM movies\n$$"
+    );
+}
+
+#[test]
+fn test_format_create_function_body_options() {
+    let (_, f) = parse_create_function(
+        "CREATE FUNCTION first() RETURNS int LANGUAGE sql AS 'SELECT id FROM movies LIMIT 1'",
+    )
+    .unwrap();
+    assert_eq!(
+        f.output_with(FormatOptions {
+            dialect: Dialect::SqlServer,
+            ..FormatOptions::default()
+        }),
+        "CREATE FUNCTION first()\nRETURNS INT\nLANGUAGE sql\nAS $$\nSELECT TOP 1 id\n  FROM movies\n$$"
+    );
+}
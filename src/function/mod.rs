@@ -1,3 +1,6 @@
+pub mod create;
+#[cfg(test)]
+mod create_tests;
 #[cfg(test)]
 mod tests;
 
@@ -8,6 +11,8 @@ use crate::formatter::Formatter;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
 use crate::list::List;
+use crate::select::order::parse_order_by_clause;
+use crate::select::order::OrderByClause;
 use crate::term::parse_term;
 use crate::term::Term;
 use crate::ws::ws;
@@ -17,21 +22,37 @@ use nom::bytes::complete::tag;
 use nom::character::complete::alphanumeric1;
 use nom::character::complete::one_of;
 use nom::combinator::map;
+use nom::combinator::opt;
 use nom::combinator::recognize;
 use nom::multi::many1;
 use nom::multi::separated_list0;
+use nom::multi::separated_list1;
 use nom::sequence::delimited;
 use nom::sequence::pair;
+use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Function(pub String, pub List<Term>);
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AggregateFunction {
     CountAll,
     Function(Function),
+    // string_agg(name, ', ' ORDER BY name)
+    Ordered(Function, OrderByClause),
+    // row_number() OVER (PARTITION BY year ORDER BY rating DESC)
+    Window(Box<AggregateFunction>, WindowSpecification),
+}
+
+#[derive(PartialEq, Clone, Debug, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowSpecification {
+    pub partition_by: Option<List<Term>>,
+    pub order_by: Option<OrderByClause>,
 }
 
 impl Format for Function {
@@ -48,10 +69,38 @@ impl Format for AggregateFunction {
         match self {
             Self::CountAll => f.append(&Keyword::Count).append_str("(*)"),
             Self::Function(fu) => f.append_format(fu),
+            Self::Ordered(fu, order_by) => {
+                f.append_format(&fu.0)
+                    .append_str("(")
+                    .append_format(&fu.1)
+                    .ws();
+                order_by.format_inline(f).append_str(")")
+            }
+            Self::Window(fu, window) => f.append_format(fu.as_ref()).ws().append_format(window),
         }
     }
 }
 
+impl Format for WindowSpecification {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Over).ws().append_str("(");
+        if let Some(terms) = &self.partition_by {
+            f.append(&Keyword::Partition)
+                .ws()
+                .append(&Keyword::By)
+                .ws()
+                .append_format(terms);
+        }
+        if let Some(order_by) = &self.order_by {
+            if self.partition_by.is_some() {
+                f.ws();
+            }
+            order_by.format_inline(f);
+        }
+        f.append_str(")")
+    }
+}
+
 fn function_name(input: &str) -> IResult<&str, String> {
     map(
         recognize(many1(alt((alphanumeric1, recognize(one_of("_")))))),
@@ -60,7 +109,7 @@ fn function_name(input: &str) -> IResult<&str, String> {
 }
 
 pub fn parse_aggregate_function(input: &str) -> IResult<&str, AggregateFunction> {
-    alt((
+    let aggregate = alt((
         map(
             pair(
                 parse_keyword(Keyword::Count),
@@ -68,8 +117,57 @@ pub fn parse_aggregate_function(input: &str) -> IResult<&str, AggregateFunction>
             ),
             |(_, _)| AggregateFunction::CountAll,
         ),
-        map(parse_function, AggregateFunction::Function),
-    ))(input)
+        map(
+            tuple((
+                function_name,
+                delimited(
+                    parse_left_parenthesis,
+                    pair(
+                        map(separated_list0(parse_comma, ws(parse_term)), List),
+                        opt(parse_order_by_clause),
+                    ),
+                    parse_right_parenthesis,
+                ),
+            )),
+            |(n, (terms, order_by))| match order_by {
+                Some(o) => AggregateFunction::Ordered(Function(n, terms), o),
+                None => AggregateFunction::Function(Function(n, terms)),
+            },
+        ),
+    ));
+    map(
+        pair(aggregate, opt(parse_window_specification)),
+        |(a, window)| match window {
+            Some(w) => AggregateFunction::Window(Box::new(a), w),
+            None => a,
+        },
+    )(input)
+}
+
+pub fn parse_window_specification(input: &str) -> IResult<&str, WindowSpecification> {
+    map(
+        preceded(
+            parse_keyword(Keyword::Over),
+            delimited(
+                parse_left_parenthesis,
+                pair(
+                    opt(preceded(
+                        pair(
+                            parse_keyword(Keyword::Partition),
+                            parse_keyword(Keyword::By),
+                        ),
+                        map(separated_list1(parse_comma, ws(parse_term)), List),
+                    )),
+                    opt(parse_order_by_clause),
+                ),
+                parse_right_parenthesis,
+            ),
+        ),
+        |(partition_by, order_by)| WindowSpecification {
+            partition_by,
+            order_by,
+        },
+    )(input)
 }
 
 pub fn parse_function(input: &str) -> IResult<&str, Function> {
@@ -3,6 +3,7 @@ use crate::formatter::Format;
 use crate::function::{parse_aggregate_function, parse_function, AggregateFunction, Function};
 use crate::identifier::Name;
 use crate::list::List;
+use crate::select::order::{Order, OrderByClause, SortKey};
 use crate::term::column::ColumnRef;
 use crate::term::Term;
 
@@ -37,3 +38,48 @@ fn test_format_function() {
     assert_format!(parse_function("DO(1, '',true)"), "DO(1, '', true)");
     assert_format!(parse_aggregate_function("COUNT(*)"), "COUNT(*)")
 }
+
+#[test]
+fn test_parse_ordered_aggregate() {
+    assert_eq!(
+        parse_aggregate_function("array_agg(title ORDER BY year)"),
+        Ok((
+            "",
+            AggregateFunction::Ordered(
+                Function(
+                    "array_agg".to_string(),
+                    List(vec!(Term::ColumnRef(ColumnRef::Name(Name::Name(
+                        "title".to_string()
+                    )))))
+                ),
+                OrderByClause(List(vec!(Order(
+                    SortKey::ColumnRef(ColumnRef::Name(Name::Name("year".to_string()))),
+                    None,
+                    None
+                ))))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_window_function() {
+    assert_format!(
+        parse_aggregate_function("string_agg(name, ', '  order by name desc)"),
+        "string_agg(name, ', ' ORDER BY name DESC)"
+    );
+    assert_format!(
+        parse_aggregate_function(
+            "rank()  over ( partition by year,genre order by rating desc nulls last )"
+        ),
+        "rank() OVER (PARTITION BY year, genre ORDER BY rating DESC NULLS LAST)"
+    );
+    assert_format!(
+        parse_aggregate_function("count(*) over ()"),
+        "COUNT(*) OVER ()"
+    );
+    assert_format!(
+        parse_aggregate_function("sum(amount) OVER (ORDER BY day)"),
+        "sum(amount) OVER (ORDER BY day)"
+    )
+}
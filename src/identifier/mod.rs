@@ -3,6 +3,7 @@ use crate::formatter::Formatter;
 use crate::keyword::is_keyword;
 use crate::list::List;
 use nom::branch::alt;
+use nom::bytes::complete::is_not;
 use nom::character::complete::alphanumeric1;
 use nom::character::complete::char;
 use nom::character::complete::one_of;
@@ -17,20 +18,22 @@ use nom::sequence::separated_pair;
 use nom::IResult;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Name {
     Name(String),
     QuotedName(String),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SchemaQualifiedName(pub Option<Name>, pub Name);
 
 impl fmt::Display for Name {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Name(name) => write!(f, "{}", name),
-            Self::QuotedName(name) => write!(f, "'{}'", name),
+            Self::QuotedName(name) => write!(f, "\"{}\"", name),
         }
     }
 }
@@ -38,7 +41,7 @@ impl fmt::Display for Name {
 pub fn parse_name(input: &str) -> IResult<&str, Name> {
     let (input, _) = peek(not(is_keyword))(input)?;
     alt((
-        map(delimited(char('"'), alphanumeric1, char('"')), |s: &str| {
+        map(delimited(char('"'), is_not("\""), char('"')), |s: &str| {
             Name::QuotedName(s.to_string())
         }),
         map(
@@ -66,12 +69,13 @@ impl fmt::Display for SchemaQualifiedName {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.0 {
             Some(name) => write!(f, "{}.{}", name, self.1),
-            None => write!(f, "'{}'", self.1),
+            None => write!(f, "{}", self.1),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Delimitedidentifier {
     Name(Name),
     Quoted(Name),
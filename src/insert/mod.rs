@@ -1,5 +1,6 @@
 mod parser;
 pub use self::parser::*;
+use pad::{Alignment, PadStr};
 use std::ops::Deref;
 
 #[cfg(test)]
@@ -7,33 +8,77 @@ mod tests;
 
 use crate::clause::Clause;
 use crate::expression::Expression;
+use crate::expression::WhereClause;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
 use crate::identifier::Name;
 use crate::keyword::Keyword;
 use crate::list::List;
-
+use crate::query::Query;
+use crate::returning::ReturningClause;
 use crate::table::create::TableRef;
+use crate::update::SetClause;
+use crate::update::SetExpression;
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct InsertStatement(pub InsertIntoClause, pub ValuesClause);
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InsertStatement(
+    pub InsertIntoClause,
+    pub InsertSource,
+    pub Option<OnConflictClause>,
+    pub Option<ReturningClause>,
+);
 
 // INSERT INTO users (first_name, last_name)
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsertIntoClause(pub TableRef, pub Option<List<Name>>);
 
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InsertSource {
+    Values(ValuesClause),
+    Query(Box<Query>),
+    DefaultValues,
+}
+
 // VALUES ('John', 'Doe'), ('Carpenter', 'Brut')
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValuesClause(pub List<InsertValue>);
 
 // ('John', 'Doe')
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InsertValue {
     Default,
     Expression(Box<Expression>),
     ParenthesisExpression(List<InsertValue>),
 }
 
+// ON CONFLICT (id) DO NOTHING
+// ON DUPLICATE KEY UPDATE title = 'Brazil'
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OnConflictClause {
+    OnConflict(Option<ConflictTarget>, ConflictAction),
+    OnDuplicateKeyUpdate(List<SetExpression>),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConflictTarget {
+    Columns(List<Name>),
+    Constraint(Name),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConflictAction {
+    DoNothing,
+    DoUpdate(SetClause, Option<WhereClause>),
+}
+
 impl Clause for InsertIntoClause {
     const KEYWORD: &'static Keyword = &Keyword::Insert;
 }
@@ -45,7 +90,15 @@ impl Clause for ValuesClause {
 impl Format for InsertStatement {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         f.set_pad(self.0.keyword().len());
-        f.append_format(&self.0).append_clause(&self.1)
+        f.append_format(&self.0).append_clause(&self.1);
+        match &self.2 {
+            Some(c) => f.append_clause(c),
+            None => f,
+        };
+        match &self.3 {
+            Some(r) => f.append_clause(r),
+            None => f,
+        }
     }
 }
 
@@ -63,13 +116,77 @@ impl Format for InsertIntoClause {
     }
 }
 
+impl Format for InsertSource {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Values(v) => f.append_format(v),
+            Self::Query(q) => f.append_format(q.deref()),
+            Self::DefaultValues => f
+                .append_with_padding(&Keyword::Default)
+                .space()
+                .append(&Keyword::Values),
+        }
+    }
+}
+
+// Rows are aligned one per line, a row with a cell over several lines is left as is.
+impl ValuesClause {
+    fn cells(row: &InsertValue, f: &Formatter) -> Option<Vec<String>> {
+        match row {
+            InsertValue::ParenthesisExpression(l) => {
+                let cells: Vec<String> = l.0.iter().map(|v| f.render(v)).collect();
+                match cells.iter().any(|c| c.contains('\n')) {
+                    true => None,
+                    false => Some(cells),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn column_widths(rows: &[Option<Vec<String>>]) -> Vec<usize> {
+        let mut widths: Vec<usize> = vec![];
+        for cells in rows.iter().flatten() {
+            for (i, c) in cells.iter().enumerate() {
+                let width = c.chars().count();
+                match widths.get_mut(i) {
+                    Some(w) => *w = (*w).max(width),
+                    None => widths.push(width),
+                };
+            }
+        }
+        widths
+    }
+
+    fn aligned_row(cells: &[String], widths: &[usize]) -> String {
+        let last = cells.len() - 1;
+        let cells = cells
+            .iter()
+            .enumerate()
+            .map(|(i, c)| match i == last {
+                true => c.to_string(),
+                false => {
+                    format!("{c},").pad_to_width_with_alignment(widths[i] + 1, Alignment::Left)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        format!("({cells})")
+    }
+}
+
 impl Format for ValuesClause {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        let rows: Vec<Option<Vec<String>>> = self.0 .0.iter().map(|r| Self::cells(r, f)).collect();
+        let widths = Self::column_widths(&rows);
         f.append_with_padding(self.keyword()).space();
-        for (pos, e) in self.0 .0.iter().enumerate() {
-            match pos {
-                0 => f.append_format(e),
-                _ => f.new_line().right_side(e),
+        for (pos, (e, cells)) in self.0 .0.iter().zip(&rows).enumerate() {
+            if pos > 0 {
+                f.append_str(",").new_line().right_side(&String::new());
+            }
+            match cells {
+                Some(c) => f.append_str(&Self::aligned_row(c, &widths)),
+                None => f.append_format(e),
             };
         }
         f
@@ -97,3 +214,66 @@ impl Format for List<InsertValue> {
         f
     }
 }
+
+impl Format for OnConflictClause {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.left_side(&Keyword::On).ws();
+        match self {
+            Self::OnConflict(t, a) => {
+                f.append(&Keyword::Conflict);
+                match t {
+                    Some(t) => f.ws().append_format(t),
+                    None => f,
+                };
+                f.ws().append_format(a)
+            }
+            Self::OnDuplicateKeyUpdate(l) => {
+                f.append(&Keyword::Duplicate)
+                    .ws()
+                    .append(&Keyword::Key)
+                    .ws()
+                    .append(&Keyword::Update)
+                    .ws();
+                for (pos, e) in l.0.iter().enumerate() {
+                    match pos {
+                        0 => f.append_format(e),
+                        _ => f.append_str(", ").append_format(e),
+                    };
+                }
+                f
+            }
+        }
+    }
+}
+
+impl Format for ConflictTarget {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Columns(c) => f.append(&format!("({c})")),
+            Self::Constraint(n) => f
+                .append(&Keyword::On)
+                .ws()
+                .append(&Keyword::Constraint)
+                .ws()
+                .append(n),
+        }
+    }
+}
+
+impl Format for ConflictAction {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::DoNothing => f.append(&Keyword::Do).ws().append(&Keyword::Nothing),
+            Self::DoUpdate(s, w) => {
+                f.append(&Keyword::Do)
+                    .ws()
+                    .append(&Keyword::Update)
+                    .append_clause(s);
+                match w {
+                    Some(w) => f.append_clause(w),
+                    None => f,
+                }
+            }
+        }
+    }
+}
@@ -3,15 +3,27 @@ use crate::character::parse_left_parenthesis;
 use crate::character::parse_right_parenthesis;
 use crate::clause::Clause;
 use crate::expression::parse_expression;
+use crate::expression::parse_where_clause;
 use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::insert::ConflictAction;
+use crate::insert::ConflictTarget;
 use crate::insert::InsertIntoClause;
+use crate::insert::InsertSource;
 use crate::insert::InsertStatement;
 use crate::insert::InsertValue;
+use crate::insert::OnConflictClause;
 use crate::insert::ValuesClause;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
 use crate::list::List;
+use crate::query::Query;
+use crate::returning::parse_returning_clause;
+use crate::select::parse_select_statement;
+use crate::select::table_operator::combined_tables;
 use crate::table::create::parse_table_ref;
+use crate::update::parser::parse_set_clause;
+use crate::update::parser::parse_set_expression;
 use crate::ws::ws;
 use nom::branch::alt;
 use nom::combinator::map;
@@ -23,20 +35,24 @@ use nom::sequence::pair;
 use nom::sequence::tuple;
 use nom::IResult;
 
+pub fn parse_column_list(input: &str) -> IResult<&str, List<Name>> {
+    map(
+        delimited(
+            parse_left_parenthesis,
+            separated_list1(parse_comma, ws(parse_name)),
+            parse_right_parenthesis,
+        ),
+        List,
+    )(input)
+}
+
 pub fn parse_insert_into_clause(input: &str) -> IResult<&str, InsertIntoClause> {
     map(
         tuple((
             InsertIntoClause::parse_keyword,
             parse_keyword(Keyword::Into),
             ws(parse_table_ref),
-            opt(map(
-                delimited(
-                    parse_left_parenthesis,
-                    separated_list1(parse_comma, ws(parse_name)),
-                    parse_right_parenthesis,
-                ),
-                List,
-            )),
+            opt(parse_column_list),
         )),
         |(_, _, t, cols)| InsertIntoClause(t, cols),
     )(input)
@@ -52,10 +68,90 @@ pub fn parse_values_clause(input: &str) -> IResult<&str, ValuesClause> {
     )(input)
 }
 
+pub fn parse_insert_source(input: &str) -> IResult<&str, InsertSource> {
+    alt((
+        map(parse_values_clause, InsertSource::Values),
+        value(
+            InsertSource::DefaultValues,
+            pair(
+                parse_keyword(Keyword::Default),
+                parse_keyword(Keyword::Values),
+            ),
+        ),
+        map(combined_tables, |c| {
+            InsertSource::Query(Box::new(Query::CombinedSelect(c)))
+        }),
+        map(ws(parse_select_statement), |s| {
+            InsertSource::Query(Box::new(Query::Select(Box::new(s))))
+        }),
+    ))(input)
+}
+
+pub fn parse_on_conflict_clause(input: &str) -> IResult<&str, OnConflictClause> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::On),
+                parse_keyword(Keyword::Conflict),
+                opt(parse_conflict_target),
+                parse_conflict_action,
+            )),
+            |(_, _, t, a)| OnConflictClause::OnConflict(t, a),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::On),
+                parse_keyword(Keyword::Duplicate),
+                parse_keyword(Keyword::Key),
+                parse_keyword(Keyword::Update),
+                separated_list1(parse_comma, parse_set_expression),
+            )),
+            |(_, _, _, _, l)| OnConflictClause::OnDuplicateKeyUpdate(List(l)),
+        ),
+    ))(input)
+}
+
+fn parse_conflict_target(input: &str) -> IResult<&str, ConflictTarget> {
+    alt((
+        map(parse_column_list, ConflictTarget::Columns),
+        map(
+            tuple((
+                parse_keyword(Keyword::On),
+                parse_keyword(Keyword::Constraint),
+                ws(parse_name),
+            )),
+            |(_, _, n)| ConflictTarget::Constraint(n),
+        ),
+    ))(input)
+}
+
+fn parse_conflict_action(input: &str) -> IResult<&str, ConflictAction> {
+    alt((
+        value(
+            ConflictAction::DoNothing,
+            pair(parse_keyword(Keyword::Do), parse_keyword(Keyword::Nothing)),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Do),
+                parse_keyword(Keyword::Update),
+                parse_set_clause,
+                opt(parse_where_clause),
+            )),
+            |(_, _, s, w)| ConflictAction::DoUpdate(s, w),
+        ),
+    ))(input)
+}
+
 pub fn parse_insert_into_statement(input: &str) -> IResult<&str, InsertStatement> {
     map(
-        pair(parse_insert_into_clause, parse_values_clause),
-        |(i, v)| InsertStatement(i, v),
+        tuple((
+            parse_insert_into_clause,
+            parse_insert_source,
+            opt(parse_on_conflict_clause),
+            opt(parse_returning_clause),
+        )),
+        |(i, s, c, r)| InsertStatement(i, s, c, r),
     )(input)
 }
 
@@ -1,12 +1,19 @@
+use crate::assert_format;
 use crate::expression::Condition;
 use crate::expression::Expression;
 use crate::expression::Operand;
+use crate::formatter::Dialect;
 use crate::formatter::Format;
+use crate::formatter::FormatOptions;
 use crate::identifier::Name;
 use crate::insert::parse_insert_into_statement;
+use crate::insert::ConflictAction;
+use crate::insert::ConflictTarget;
 use crate::insert::InsertIntoClause;
+use crate::insert::InsertSource;
 use crate::insert::InsertStatement;
 use crate::insert::InsertValue;
+use crate::insert::OnConflictClause;
 use crate::insert::ValuesClause;
 use crate::list::List;
 use crate::numeric::Numeric;
@@ -29,14 +36,22 @@ fn test_insert() {
                         Name::Name(String::from("title"))
                     )))
                 ),
-                ValuesClause(List(vec!(InsertValue::ParenthesisExpression(List(vec!(
-                    InsertValue::Expression(Box::new(Expression::Condition(Condition::Operand(
-                        Operand::Term(Term::Value(Value::Num(Numeric::Int(1))))
-                    )))),
-                    InsertValue::Expression(Box::new(Expression::Condition(Condition::Operand(
-                        Operand::Term(Term::Value(Value::String("Coup de torchon".to_string())))
-                    ))))
-                ))))))
+                InsertSource::Values(ValuesClause(List(vec!(
+                    InsertValue::ParenthesisExpression(List(vec!(
+                        InsertValue::Expression(Box::new(Expression::Condition(
+                            Condition::Operand(Operand::Term(Term::Value(Value::Num(
+                                Numeric::Int(1)
+                            ))))
+                        ))),
+                        InsertValue::Expression(Box::new(Expression::Condition(
+                            Condition::Operand(Operand::Term(Term::Value(Value::String(
+                                "Coup de torchon".to_string()
+                            ))))
+                        )))
+                    )))
+                )))),
+                None,
+                None
             )
         ))
     )
@@ -51,14 +66,22 @@ fn test_insert_into_without_columns() {
             "",
             InsertStatement(
                 InsertIntoClause(TableRef(None, Name::Name(String::from("movies"))), None),
-                ValuesClause(List(vec!(InsertValue::ParenthesisExpression(List(vec!(
-                    InsertValue::Expression(Box::new(Expression::Condition(Condition::Operand(
-                        Operand::Term(Term::Value(Value::Num(Numeric::Int(1))))
-                    )))),
-                    InsertValue::Expression(Box::new(Expression::Condition(Condition::Operand(
-                        Operand::Term(Term::Value(Value::String("Fils de plouc".to_string())))
-                    ))))
-                ))))))
+                InsertSource::Values(ValuesClause(List(vec!(
+                    InsertValue::ParenthesisExpression(List(vec!(
+                        InsertValue::Expression(Box::new(Expression::Condition(
+                            Condition::Operand(Operand::Term(Term::Value(Value::Num(
+                                Numeric::Int(1)
+                            ))))
+                        ))),
+                        InsertValue::Expression(Box::new(Expression::Condition(
+                            Condition::Operand(Operand::Term(Term::Value(Value::String(
+                                "Fils de plouc".to_string()
+                            ))))
+                        )))
+                    )))
+                )))),
+                None,
+                None
             )
         ))
     )
@@ -80,7 +103,7 @@ fn test_insert_into_with_schema() {
                     ),
                     None
                 ),
-                ValuesClause(List(vec!(
+                InsertSource::Values(ValuesClause(List(vec!(
                     InsertValue::ParenthesisExpression(List(vec!(
                         InsertValue::Expression(Box::new(Expression::Condition(
                             Condition::Operand(Operand::Term(Term::Value(Value::Num(
@@ -105,7 +128,9 @@ fn test_insert_into_with_schema() {
                             ))))
                         )))
                     )))
-                )))
+                )))),
+                None,
+                None
             )
         ))
     )
@@ -119,7 +144,7 @@ fn test_format_insert_statement() {
     .unwrap();
     assert_eq!(
         t.output(),
-        "INSERT INTO emule.movies\nVALUES (1, 'The french dispatch')\n       (2, 'Bo Nunham inside')"
+        "INSERT INTO emule.movies\nVALUES (1, 'The french dispatch'),\n       (2, 'Bo Nunham inside')"
     )
 }
 
@@ -131,6 +156,113 @@ fn test_format_insert_statement_with_columns() {
     .unwrap();
     assert_eq!(
         t.output(),
-        "INSERT INTO twitch.leaks (name, password)\nVALUES ('dd', 'azerty')\n       ('gg', '1984')"
+        "INSERT INTO twitch.leaks (name, password)\nVALUES ('dd', 'azerty'),\n       ('gg', '1984')"
     )
 }
+
+#[test]
+fn test_insert_default_values() {
+    let input = "INSERT INTO movies DEFAULT VALUES";
+    assert_eq!(
+        parse_insert_into_statement(input),
+        Ok((
+            "",
+            InsertStatement(
+                InsertIntoClause(TableRef(None, Name::Name(String::from("movies"))), None),
+                InsertSource::DefaultValues,
+                None,
+                None
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_insert_on_conflict_do_nothing() {
+    let input = "INSERT INTO movies VALUES (1) ON CONFLICT (id) DO NOTHING";
+    let (remaining, t) = parse_insert_into_statement(input).unwrap();
+    assert_eq!(remaining, "");
+    assert_eq!(
+        t.2,
+        Some(OnConflictClause::OnConflict(
+            Some(ConflictTarget::Columns(List(vec!(Name::Name(
+                "id".to_string()
+            ))))),
+            ConflictAction::DoNothing
+        ))
+    )
+}
+
+#[test]
+fn test_format_insert_select() {
+    assert_format!(
+        parse_insert_into_statement(
+            "insert into archived_movies (id, title) select id, title from movies where year < 1950"
+        ),
+        "INSERT INTO archived_movies (id, title)\nSELECT id, title\n  FROM movies\n WHERE year < 1950"
+    )
+}
+
+#[test]
+fn test_format_insert_default_values() {
+    assert_format!(
+        parse_insert_into_statement("INSERT INTO movies   default values RETURNING id"),
+        "INSERT INTO movies\nDEFAULT VALUES\nRETURNING id"
+    )
+}
+
+#[test]
+fn test_format_insert_on_conflict() {
+    assert_format!(
+        parse_insert_into_statement(
+            "INSERT INTO movies (id, title) VALUES (1, 'Brazil') ON CONFLICT (id) DO UPDATE SET title = excluded.title WHERE movies.locked = false RETURNING *"
+        ),
+        "INSERT INTO movies (id, title)\nVALUES (1, 'Brazil')\n    ON CONFLICT (id) DO UPDATE\n   SET title = excluded.title\n WHERE movies.locked = false\nRETURNING *"
+    );
+    assert_format!(
+        parse_insert_into_statement(
+            "INSERT INTO movies (id) VALUES (1) ON CONFLICT ON CONSTRAINT movies_pkey DO NOTHING"
+        ),
+        "INSERT INTO movies (id)\nVALUES (1)\n    ON CONFLICT ON CONSTRAINT movies_pkey DO NOTHING"
+    )
+}
+
+#[test]
+fn test_format_insert_on_duplicate_key_update() {
+    assert_format!(
+        parse_insert_into_statement(
+            "INSERT INTO movies (id, title) VALUES (1, 'Brazil') ON DUPLICATE KEY UPDATE title = 'Brazil', year = 1985"
+        ),
+        "INSERT INTO movies (id, title)\nVALUES (1, 'Brazil')\n    ON DUPLICATE KEY UPDATE title = 'Brazil', year = 1985"
+    )
+}
+
+#[test]
+fn test_format_insert_aligned_values() {
+    assert_format!(
+        parse_insert_into_statement(
+            "INSERT INTO movies (id, title, year) VALUES (1, 'Brazil', 1985), (12, 'Alien', 1979), (123, DEFAULT, 2001)"
+        ),
+        "INSERT INTO movies (id, title, year)\nVALUES (1,   'Brazil', 1985),\n       (12,  'Alien',  1979),\n       (123, DEFAULT,  2001)"
+    )
+}
+
+#[test]
+fn test_format_values_multi_line_cells() {
+    let (_, t) = parse_insert_into_statement(
+        "INSERT INTO t (a, b) VALUES (1, (SELECT max(id) FROM u LIMIT 1)), (22, 'x')",
+    )
+    .unwrap();
+    assert_eq!(
+        t.output(),
+        "INSERT INTO t (a, b)\nVALUES (1,\n       (SELECT max(id)\n          FROM u\n        LIMIT 1)),\n       (22, 'x')"
+    );
+    let options = FormatOptions {
+        dialect: Dialect::SqlServer,
+        ..FormatOptions::default()
+    };
+    assert_eq!(
+        t.output_with(options),
+        "INSERT INTO t (a, b)\nVALUES (1,\n       (SELECT TOP 1 max(id)\n          FROM u)),\n       (22, 'x')"
+    );
+}
@@ -1,9 +1,13 @@
 use crate::ws::ws;
 use nom::bytes::complete::tag_no_case;
 use nom::bytes::complete::take_while1;
+use nom::character::complete::satisfy;
 use nom::combinator::map;
 use nom::combinator::map_res;
+use nom::combinator::not;
 use nom::combinator::peek;
+use nom::combinator::verify;
+use nom::sequence::terminated;
 use nom::IResult;
 use std::fmt;
 use std::str::FromStr;
@@ -64,6 +68,7 @@ pub enum Keyword {
     End,
     Union,
     Except,
+    Corresponding,
     Desc,
     Last,
     Between,
@@ -78,6 +83,13 @@ pub enum Keyword {
     And,
     Or,
     Limit,
+    Offset,
+    Fetch,
+    Next,
+    Rows,
+    Ties,
+    Top,
+    Percent,
     On,
     Avg,
     Order,
@@ -96,6 +108,121 @@ pub enum Keyword {
     Constraint,
     Check,
     Set,
+    Conflict,
+    Do,
+    Nothing,
+    Duplicate,
+    Returning,
+    Merge,
+    Matched,
+    Begin,
+    Commit,
+    Rollback,
+    Savepoint,
+    Release,
+    To,
+    Transaction,
+    Work,
+    Isolation,
+    Level,
+    Read,
+    Write,
+    Only,
+    Committed,
+    Uncommitted,
+    Repeatable,
+    Serializable,
+    Local,
+    Session,
+    Reset,
+    Grant,
+    Revoke,
+    Privileges,
+    Option,
+    Public,
+    Usage,
+    Execute,
+    Truncate,
+    Trigger,
+    Temporary,
+    Connect,
+    Role,
+    User,
+    Login,
+    Superuser,
+    Createdb,
+    Createrole,
+    Inherit,
+    Replication,
+    Bypassrls,
+    Password,
+    Connection,
+    Valid,
+    Until,
+    Schema,
+    Database,
+    Function,
+    Tables,
+    Sequences,
+    Functions,
+    Types,
+    Schemas,
+    Admin,
+    For,
+    If,
+    Exists,
+    Replace,
+    Procedure,
+    Returns,
+    Language,
+    Immutable,
+    Stable,
+    Volatile,
+    Strict,
+    Security,
+    Definer,
+    Invoker,
+    Out,
+    Inout,
+    Variadic,
+    Setof,
+    Void,
+    Before,
+    After,
+    Instead,
+    Of,
+    Each,
+    Row,
+    Statement,
+    Extension,
+    Comment,
+    Rename,
+    Owner,
+    Domain,
+    Restart,
+    Continue,
+    Identity,
+    Version,
+    Authorization,
+    Data,
+    Validate,
+    Generated,
+    Always,
+    Stored,
+    Temp,
+    Including,
+    Excluding,
+    Partition,
+    Over,
+    Rollup,
+    Cube,
+    Grouping,
+    Sets,
+    Range,
+    List,
+    Hash,
+    Collate,
+    View,
 }
 
 #[allow(clippy::len_without_is_empty)]
@@ -103,12 +230,140 @@ impl Keyword {
     pub fn len(&self) -> usize {
         self.to_string().len()
     }
+
+    // Non-reserved keywords can still be used as identifiers.
+    pub fn is_reserved(&self) -> bool {
+        !matches!(
+            self,
+            Self::Transaction
+                | Self::Work
+                | Self::Isolation
+                | Self::Level
+                | Self::Read
+                | Self::Write
+                | Self::Only
+                | Self::Committed
+                | Self::Uncommitted
+                | Self::Repeatable
+                | Self::Serializable
+                | Self::Local
+                | Self::Session
+                | Self::Reset
+                | Self::Privileges
+                | Self::Option
+                | Self::Public
+                | Self::Usage
+                | Self::Execute
+                | Self::Truncate
+                | Self::Trigger
+                | Self::Temporary
+                | Self::Connect
+                | Self::Role
+                | Self::User
+                | Self::Login
+                | Self::Superuser
+                | Self::Createdb
+                | Self::Createrole
+                | Self::Inherit
+                | Self::Replication
+                | Self::Bypassrls
+                | Self::Password
+                | Self::Connection
+                | Self::Valid
+                | Self::Until
+                | Self::Schema
+                | Self::Database
+                | Self::Function
+                | Self::Tables
+                | Self::Sequences
+                | Self::Functions
+                | Self::Types
+                | Self::Schemas
+                | Self::Admin
+                | Self::If
+                | Self::Replace
+                | Self::Procedure
+                | Self::Returns
+                | Self::Language
+                | Self::Immutable
+                | Self::Stable
+                | Self::Volatile
+                | Self::Strict
+                | Self::Security
+                | Self::Definer
+                | Self::Invoker
+                | Self::Out
+                | Self::Inout
+                | Self::Variadic
+                | Self::Setof
+                | Self::Void
+                | Self::Before
+                | Self::After
+                | Self::Instead
+                | Self::Of
+                | Self::Each
+                | Self::Row
+                | Self::Statement
+                | Self::Extension
+                | Self::Comment
+                | Self::Rename
+                | Self::Owner
+                | Self::Domain
+                | Self::Restart
+                | Self::Continue
+                | Self::Identity
+                | Self::Version
+                | Self::Authorization
+                | Self::Data
+                | Self::Validate
+                | Self::Generated
+                | Self::Always
+                | Self::Stored
+                | Self::Temp
+                | Self::Including
+                | Self::Excluding
+                | Self::Partition
+                | Self::Over
+                | Self::Rollup
+                | Self::Cube
+                | Self::Grouping
+                | Self::Sets
+                | Self::Corresponding
+                | Self::Range
+                | Self::List
+                | Self::Hash
+                | Self::Offset
+                | Self::Fetch
+                | Self::Next
+                | Self::Rows
+                | Self::Ties
+                | Self::Top
+                | Self::Percent
+                | Self::Conflict
+                | Self::Nothing
+                | Self::Duplicate
+                | Self::Returning
+                | Self::Merge
+                | Self::Matched
+                | Self::Begin
+                | Self::Commit
+                | Self::Rollback
+                | Self::Savepoint
+                | Self::Release
+                | Self::Grant
+                | Self::Revoke
+                | Self::View
+        )
+    }
 }
 
 pub fn is_keyword(input: &str) -> IResult<&str, Keyword> {
-    map_res(
-        peek(take_while1(|c: char| c.is_alphanumeric())),
-        |s: &str| Keyword::from_str(&s.to_lowercase()),
+    verify(
+        map_res(
+            peek(take_while1(|c: char| c.is_alphanumeric() || c == '_')),
+            |s: &str| Keyword::from_str(&s.to_lowercase()),
+        ),
+        |k: &Keyword| k.is_reserved(),
     )(input)
 }
 
@@ -121,7 +376,15 @@ impl fmt::Display for Keyword {
 
 pub fn parse_keyword(keyword: Keyword) -> impl Fn(&str) -> IResult<&str, ()> {
     let key: &str = keyword.into();
-    move |i: &str| map(ws(tag_no_case(key)), |_| ())(i)
+    move |i: &str| {
+        map(
+            ws(terminated(
+                tag_no_case(key),
+                not(satisfy(|c: char| c.is_alphanumeric() || c == '_')),
+            )),
+            |_| (),
+        )(i)
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,67 @@
 use crate::keyword::is_keyword;
+use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
+use crate::query::parse_statements;
 
 #[test]
 fn test_is_keyword() {
     let input = "UNION";
     assert_eq!(is_keyword(input), Ok(("UNION", Keyword::Union)))
 }
+
+#[test]
+fn test_is_not_keyword() {
+    assert!(is_keyword("level").is_err());
+    assert!(is_keyword("set_at").is_err())
+}
+
+#[test]
+fn test_parse_keyword_word_boundary() {
+    assert_eq!(parse_keyword(Keyword::All)("ALL users"), Ok(("users", ())));
+    assert!(parse_keyword(Keyword::All)("all_users").is_err())
+}
+
+fn parses(input: &str) -> bool {
+    matches!(parse_statements(input), Ok(("", _)))
+}
+
+#[test]
+fn test_insert_keywords_as_identifiers() {
+    assert!(parses("SELECT conflict FROM t WHERE conflict = 1;"));
+    assert!(parses("SELECT nothing, duplicate, returning FROM t;"));
+    assert!(parses(
+        "INSERT INTO t (a) VALUES (1) ON CONFLICT DO NOTHING RETURNING a;"
+    ));
+}
+
+#[test]
+fn test_merge_keywords_as_identifiers() {
+    assert!(parses("SELECT merge FROM t WHERE matched = 1;"));
+    assert!(parses(
+        "MERGE INTO t USING s ON t.id = s.id WHEN MATCHED THEN DELETE;"
+    ));
+}
+
+#[test]
+fn test_transaction_keywords_as_identifiers() {
+    assert!(parses("SELECT a FROM t WHERE release > 1;"));
+    assert!(parses("SELECT a FROM t WHERE commit = 1;"));
+    assert!(parses("SELECT rollback, savepoint FROM t;"));
+    assert!(parses("CREATE TABLE t (begin int);"));
+    assert!(parses("BEGIN; SAVEPOINT s; RELEASE SAVEPOINT s; COMMIT;"));
+}
+
+#[test]
+fn test_privilege_keywords_as_identifiers() {
+    assert!(parses("CREATE TABLE t (grant int, revoke int);"));
+    assert!(parses("SELECT grant FROM t WHERE revoke = 1;"));
+    assert!(parses("GRANT SELECT ON movies TO reader;"));
+    assert!(parses("REVOKE SELECT ON movies FROM reader;"));
+}
+
+#[test]
+fn test_pagination_keywords_as_identifiers() {
+    assert!(parses("SELECT a FROM t WHERE offset = 1;"));
+    assert!(parses("SELECT a FROM t WHERE fetch = 1;"));
+    assert!(parses("SELECT offset, fetch FROM t AS offset;"));
+}
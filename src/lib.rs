@@ -1,15 +1,28 @@
 #![allow(clippy::unused_unit)]
 
 //! SQL parser.
+pub use crate::formatter::Dialect;
 use crate::formatter::Format;
+pub use crate::formatter::FormatOptions;
 /// This module contains the function `format`.
+use crate::list::List;
 use crate::query::parse_statements;
+use crate::query::Statement;
+use crate::range::byte_offset;
+pub use crate::range::format_range;
+use crate::range::statement_spans;
+use crate::range::utf16_offset;
 
+pub mod alter;
+pub mod builder;
 pub mod character;
 pub mod clause;
 pub mod comment;
 pub mod data_type;
+pub mod domain;
+pub mod embedded;
 pub mod expression;
+pub mod extension;
 pub mod formatter;
 pub mod function;
 pub mod identifier;
@@ -17,21 +30,35 @@ pub mod insert;
 pub mod keyword;
 //pub mod lint;
 pub mod list;
+pub mod merge;
+pub mod nesting;
 pub mod numeric;
+pub mod privilege;
 pub mod query;
+pub mod range;
+pub mod returning;
+pub mod schema;
 pub mod select;
 pub mod sequence;
 pub mod set;
+pub mod split;
 pub mod statement;
 pub mod table;
+pub mod template;
 pub mod term;
+pub mod transaction;
+pub mod trigger;
+pub mod truncate;
 pub mod r#type;
 pub mod update;
+pub mod visitor;
 pub mod ws;
 
 extern crate strum;
 #[macro_use]
 extern crate strum_macros;
+#[macro_use]
+extern crate sql_lint_derive;
 
 use wasm_bindgen::prelude::*;
 
@@ -50,14 +77,35 @@ impl std::fmt::Display for Error {
     }
 }
 
+impl std::error::Error for Error {}
+
+// An unparseable statement left unchanged by `format_tolerant`, lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    pub line: usize,
+    pub column: usize,
+    pub statement: String,
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        let first_line = self.statement.lines().next().unwrap_or_default();
+        write!(
+            f,
+            "Unable to parse SQL at line {}, column {}, left unchanged: '{}'",
+            self.line, self.column, first_line
+        )
+    }
+}
+
 type FResult<T> = std::result::Result<T, Error>;
 
-pub fn format(s: &str) -> FResult<String> {
+pub fn parse(s: &str) -> FResult<List<Statement>> {
     match parse_statements(s) {
         Err(e) => Err(Error::ParsingError(e.to_string())),
         Ok((remaining, ast)) => {
             if remaining.is_empty() {
-                Ok(ast.output())
+                Ok(ast)
             } else {
                 Err(Error::ParsingIncompleteError(remaining.to_string()))
             }
@@ -65,7 +113,85 @@ pub fn format(s: &str) -> FResult<String> {
     }
 }
 
-#[wasm_bindgen(catch)]
+pub fn format(s: &str) -> FResult<String> {
+    format_with_options(s, FormatOptions::default())
+}
+
+pub fn format_with_options(s: &str, options: FormatOptions) -> FResult<String> {
+    parse(s).map(|ast| ast.output_with(options))
+}
+
+// Formats the statements that parse and keeps the others, and the comments between
+// statements, verbatim.
+pub fn format_tolerant(s: &str, options: FormatOptions) -> (String, Vec<Warning>) {
+    let mut output = String::new();
+    let mut warnings = vec![];
+    let mut offset = 0;
+    for span in statement_spans(s) {
+        let gap = &s[offset..span.start];
+        match (offset, gap.trim().is_empty()) {
+            (0, true) => (),
+            (0, false) => output.push_str(gap.trim_start()),
+            (_, true) => output.push_str("\n\n"),
+            (_, false) => output.push_str(&comments_between(gap)),
+        };
+        let source = &s[span.start..span.end];
+        match span.query {
+            Some(q) => output.push_str(&format!("{};", q.output_with(options))),
+            None => {
+                let before = &s[..span.start];
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                warnings.push(Warning {
+                    line: before.matches('\n').count() + 1,
+                    column: before[line_start..].chars().count() + 1,
+                    statement: source.to_string(),
+                });
+                output.push_str(source);
+            }
+        };
+        offset = span.end;
+    }
+    let tail = &s[offset..];
+    if !tail.trim().is_empty() {
+        output.push_str(if offset == 0 {
+            tail.trim()
+        } else {
+            tail.trim_end()
+        });
+    }
+    (output, warnings)
+}
+
+// Comments after a statement stay on its line, the others go on their own line
+// before the next statement. Statements remain separated by a blank line.
+fn comments_between(gap: &str) -> String {
+    let comments = gap.trim();
+    let before = &gap[..gap.len() - gap.trim_start().len()];
+    let after = &gap[gap.trim_end().len()..];
+    match (before.contains('\n'), after.matches('\n').count()) {
+        (false, _) => format!(" {}\n\n", comments),
+        (true, 0 | 1) => format!("\n\n{}\n", comments),
+        (true, _) => format!("\n\n{}\n\n", comments),
+    }
+}
+
+// Queries in host code and templates usually omit the final `;`.
+pub(crate) fn format_fragment(sql: &str, options: FormatOptions) -> (String, Vec<Warning>) {
+    let sql = sql.trim_end();
+    match sql.ends_with(';') {
+        true => format_tolerant(sql, options),
+        false => {
+            let (formatted, warnings) = format_tolerant(&format!("{};", sql), options);
+            let formatted = formatted
+                .strip_suffix(';')
+                .unwrap_or(&formatted)
+                .to_string();
+            (formatted, warnings)
+        }
+    }
+}
+
+#[wasm_bindgen]
 pub fn format_sql(s: &str) -> Result<String, JsValue> {
     match format(s) {
         Ok(s) => Ok(s),
@@ -73,5 +199,31 @@ pub fn format_sql(s: &str) -> Result<String, JsValue> {
     }
 }
 
+// Replaces `start..end` of the source by `text`, offsets are in UTF-16 code units.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct RangeEdit {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+// `start` and `end` are offsets in UTF-16 code units, as `selectionStart` and `selectionEnd`.
+#[wasm_bindgen]
+pub fn format_sql_range(s: &str, start: usize, end: usize) -> Result<Vec<RangeEdit>, JsValue> {
+    let range = byte_offset(s, start)..byte_offset(s, end);
+    match format_range(s, range, FormatOptions::default()) {
+        Ok(edits) => Ok(edits
+            .into_iter()
+            .map(|e| RangeEdit {
+                start: utf16_offset(s, e.start),
+                end: utf16_offset(s, e.end),
+                text: e.text,
+            })
+            .collect()),
+        Err(e) => Err(JsValue::from(e.to_string())),
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,13 @@
+use clap::ArgEnum;
 use clap::Parser;
-use sql_lint::format;
+use sql_lint::embedded::format_embedded;
+use sql_lint::embedded::Host;
+use sql_lint::format_tolerant;
+use sql_lint::format_with_options;
 use sql_lint::query::parse_statements;
+use sql_lint::template::format_template;
+use sql_lint::Dialect;
+use sql_lint::FormatOptions;
 use std::error::Error;
 use std::fs;
 use std::io;
@@ -16,10 +23,53 @@ struct Args {
     output: Option<PathBuf>,
     #[clap(short, long)]
     debug: bool,
+    #[clap(short, long)]
+    align_columns: bool,
+    #[clap(short, long)]
+    recover: bool,
+    #[clap(long, requires = "input")]
+    embedded: bool,
+    #[clap(long)]
+    jinja: bool,
+    #[clap(short, long, requires = "embedded")]
+    write: bool,
+    #[clap(long, arg_enum, default_value = "sql")]
+    emit: Emit,
+    #[clap(long, arg_enum, default_value = "generic")]
+    dialect: SqlDialect,
     #[clap()]
     query: Vec<String>,
 }
 
+#[derive(ArgEnum, Clone, Debug, PartialEq)]
+enum Emit {
+    Sql,
+    AstJson,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+enum SqlDialect {
+    Generic,
+    Ansi,
+    Postgres,
+    #[clap(name = "mysql")]
+    MySql,
+    #[clap(name = "sqlserver")]
+    SqlServer,
+}
+
+impl From<SqlDialect> for Dialect {
+    fn from(d: SqlDialect) -> Self {
+        match d {
+            SqlDialect::Generic => Dialect::Generic,
+            SqlDialect::Ansi => Dialect::Ansi,
+            SqlDialect::Postgres => Dialect::Postgres,
+            SqlDialect::MySql => Dialect::MySql,
+            SqlDialect::SqlServer => Dialect::SqlServer,
+        }
+    }
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let args = Args::parse();
     let contents = match args.input {
@@ -40,7 +90,28 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
-    match format(&contents) {
+    if args.emit == Emit::AstJson {
+        return emit_ast_json(&args, &contents);
+    }
+
+    let options = FormatOptions {
+        align_columns: args.align_columns,
+        dialect: args.dialect.into(),
+    };
+    if args.embedded {
+        return format_embedded_file(&args, &contents, options);
+    }
+    if args.recover || args.jinja {
+        let (formatted, warnings) = match args.jinja {
+            true => format_template(&contents, options),
+            false => format_tolerant(&contents, options),
+        };
+        for w in warnings {
+            eprintln!("{}", w);
+        }
+        return write_output(&args, &formatted);
+    }
+    match format_with_options(&contents, options) {
         Ok(formatted) => write_output(&args, &formatted),
         Err(e) => {
             eprintln!("{}", e);
@@ -49,6 +120,36 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 }
 
+// SQL in Rust, Python or Markdown files, `--write` only rewrites files that change.
+fn format_embedded_file(
+    args: &Args,
+    contents: &str,
+    options: FormatOptions,
+) -> Result<(), Box<dyn Error>> {
+    let input = args.input.as_ref().unwrap();
+    let host = Host::from_path(input).ok_or("--embedded supports .rs, .py and .md files")?;
+    let (formatted, warnings) = format_embedded(contents, host, options);
+    for w in warnings {
+        eprintln!("{}: {}", input.display(), w);
+    }
+    match args.write {
+        true if formatted != contents => fs::write(input, formatted).map_err(|e| e.into()),
+        true => Ok(()),
+        false => write_output(args, &formatted),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn emit_ast_json(args: &Args, contents: &str) -> Result<(), Box<dyn Error>> {
+    let ast = sql_lint::parse(contents)?;
+    write_output(args, &serde_json::to_string_pretty(&ast)?)
+}
+
+#[cfg(not(feature = "serde"))]
+fn emit_ast_json(_: &Args, _: &str) -> Result<(), Box<dyn Error>> {
+    Err("sql-lint was built without the `serde` feature".into())
+}
+
 fn write_output(args: &Args, content: &str) -> Result<(), Box<dyn Error>> {
     match &args.output {
         Some(o) => std::fs::write(o, content).map_err(|e| Box::new(e) as Box<dyn Error>),
@@ -0,0 +1,180 @@
+pub mod parser;
+#[cfg(test)]
+mod tests;
+
+pub use self::parser::parse_merge_statement;
+use crate::clause::Clause;
+use crate::expression::Expression;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::identifier::Name;
+use crate::insert::ValuesClause;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::select::SelectStatement;
+use crate::table::create::TableRef;
+use crate::update::SetClause;
+use std::ops::Deref;
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MergeStatement(
+    pub MergeIntoClause,
+    pub MergeUsingClause,
+    pub List<Expression>,
+    pub List<MergeWhenClause>,
+);
+
+// MERGE INTO customers AS c
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MergeIntoClause(pub TableRef, pub Option<Name>);
+
+// USING new_customers AS n
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MergeUsingClause(pub MergeSource, pub Option<Name>);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MergeSource {
+    Table(TableRef),
+    Subquery(Box<SelectStatement>),
+}
+
+// WHEN MATCHED AND n.deleted = true THEN DELETE
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MergeWhenClause {
+    Matched(Option<List<Expression>>, MatchedAction),
+    NotMatched(Option<List<Expression>>, NotMatchedAction),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatchedAction {
+    Update(SetClause),
+    Delete,
+    DoNothing,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NotMatchedAction {
+    Insert(Option<List<Name>>, ValuesClause),
+    InsertDefaultValues,
+    DoNothing,
+}
+
+impl Clause for MergeIntoClause {
+    const KEYWORD: &'static Keyword = &Keyword::Merge;
+}
+
+impl Clause for MergeUsingClause {
+    const KEYWORD: &'static Keyword = &Keyword::Using;
+}
+
+impl Format for MergeStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.set_pad(Keyword::Merge.len());
+        f.append_format(&self.0)
+            .append_clause(&self.1)
+            .new_line()
+            .append_left_right(&Keyword::On, &self.2);
+        for w in self.3 .0.iter() {
+            f.append_clause(w);
+        }
+        f
+    }
+}
+
+impl Format for MergeIntoClause {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append_with_padding(self.keyword())
+            .ws()
+            .append(&Keyword::Into)
+            .ws()
+            .append(&self.0);
+        match &self.1 {
+            Some(a) => f.ws().append(&Keyword::As).ws().append(a),
+            None => f,
+        }
+    }
+}
+
+impl Format for MergeUsingClause {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append_with_padding(self.keyword())
+            .ws()
+            .append_format(&self.0);
+        match &self.1 {
+            Some(a) => f.ws().append(&Keyword::As).ws().append(a),
+            None => f,
+        }
+    }
+}
+
+impl Format for MergeSource {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Table(t) => f.append(t),
+            Self::Subquery(s) => f.append_str("(").append_indented(s.deref()).append_str(")"),
+        }
+    }
+}
+
+impl Format for MergeWhenClause {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.left_side(&Keyword::When).ws();
+        let condition = match self {
+            Self::Matched(c, _) => {
+                f.append(&Keyword::Matched);
+                c
+            }
+            Self::NotMatched(c, _) => {
+                f.append(&Keyword::Not).ws().append(&Keyword::Matched);
+                c
+            }
+        };
+        if let Some(c) = condition {
+            c.format_inline(f.ws().append(&Keyword::And).ws());
+        }
+        f.ws().append(&Keyword::Then).ws();
+        match self {
+            Self::Matched(_, a) => f.append_format(a),
+            Self::NotMatched(_, a) => f.append_format(a),
+        }
+    }
+}
+
+impl Format for MatchedAction {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Update(s) => f.append(&Keyword::Update).append_clause(s),
+            Self::Delete => f.append(&Keyword::Delete),
+            Self::DoNothing => f.append(&Keyword::Do).ws().append(&Keyword::Nothing),
+        }
+    }
+}
+
+// MERGE inserts a single row, it stays on the WHEN line.
+impl Format for NotMatchedAction {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Insert(cols, v) => {
+                f.append(&Keyword::Insert);
+                if let Some(c) = cols {
+                    f.ws().append(&format!("({c})"));
+                }
+                f.ws().append(&Keyword::Values).ws().append_format(&v.0)
+            }
+            Self::InsertDefaultValues => f
+                .append(&Keyword::Insert)
+                .ws()
+                .append(&Keyword::Default)
+                .ws()
+                .append(&Keyword::Values),
+            Self::DoNothing => f.append(&Keyword::Do).ws().append(&Keyword::Nothing),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::character::parse_left_parenthesis;
+use crate::character::parse_right_parenthesis;
+use crate::clause::Clause;
+use crate::expression::parse_expressions;
+use crate::expression::Expression;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::insert::parse_column_list;
+use crate::insert::parse_values_clause;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::merge::MatchedAction;
+use crate::merge::MergeIntoClause;
+use crate::merge::MergeSource;
+use crate::merge::MergeStatement;
+use crate::merge::MergeUsingClause;
+use crate::merge::MergeWhenClause;
+use crate::merge::NotMatchedAction;
+use crate::select::parse_select_statement;
+use crate::table::create::parse_table_ref;
+use crate::update::parser::parse_set_clause;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::opt;
+use nom::combinator::value;
+use nom::multi::many1;
+use nom::sequence::delimited;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::tuple;
+use nom::IResult;
+
+pub fn parse_merge_statement(input: &str) -> IResult<&str, MergeStatement> {
+    map(
+        tuple((
+            parse_merge_into_clause,
+            parse_merge_using_clause,
+            preceded(parse_keyword(Keyword::On), parse_expressions),
+            many1(parse_merge_when_clause),
+        )),
+        |(i, u, o, w)| MergeStatement(i, u, o, List(w)),
+    )(input)
+}
+
+fn parse_alias(input: &str) -> IResult<&str, Name> {
+    preceded(opt(parse_keyword(Keyword::As)), ws(parse_name))(input)
+}
+
+pub fn parse_merge_into_clause(input: &str) -> IResult<&str, MergeIntoClause> {
+    map(
+        tuple((
+            MergeIntoClause::parse_keyword,
+            parse_keyword(Keyword::Into),
+            ws(parse_table_ref),
+            opt(parse_alias),
+        )),
+        |(_, _, t, a)| MergeIntoClause(t, a),
+    )(input)
+}
+
+pub fn parse_merge_using_clause(input: &str) -> IResult<&str, MergeUsingClause> {
+    map(
+        tuple((
+            MergeUsingClause::parse_keyword,
+            alt((
+                map(
+                    delimited(
+                        parse_left_parenthesis,
+                        parse_select_statement,
+                        parse_right_parenthesis,
+                    ),
+                    |s| MergeSource::Subquery(Box::new(s)),
+                ),
+                map(ws(parse_table_ref), MergeSource::Table),
+            )),
+            opt(parse_alias),
+        )),
+        |(_, s, a)| MergeUsingClause(s, a),
+    )(input)
+}
+
+fn parse_merge_condition(input: &str) -> IResult<&str, Option<List<Expression>>> {
+    opt(preceded(parse_keyword(Keyword::And), parse_expressions))(input)
+}
+
+pub fn parse_merge_when_clause(input: &str) -> IResult<&str, MergeWhenClause> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::When),
+                parse_keyword(Keyword::Matched),
+                parse_merge_condition,
+                parse_keyword(Keyword::Then),
+                parse_matched_action,
+            )),
+            |(_, _, c, _, a)| MergeWhenClause::Matched(c, a),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::When),
+                parse_keyword(Keyword::Not),
+                parse_keyword(Keyword::Matched),
+                parse_merge_condition,
+                parse_keyword(Keyword::Then),
+                parse_not_matched_action,
+            )),
+            |(_, _, _, c, _, a)| MergeWhenClause::NotMatched(c, a),
+        ),
+    ))(input)
+}
+
+fn parse_matched_action(input: &str) -> IResult<&str, MatchedAction> {
+    alt((
+        map(
+            preceded(parse_keyword(Keyword::Update), parse_set_clause),
+            MatchedAction::Update,
+        ),
+        value(MatchedAction::Delete, parse_keyword(Keyword::Delete)),
+        value(MatchedAction::DoNothing, parse_do_nothing),
+    ))(input)
+}
+
+fn parse_do_nothing(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        pair(parse_keyword(Keyword::Do), parse_keyword(Keyword::Nothing)),
+    )(input)
+}
+
+fn parse_not_matched_action(input: &str) -> IResult<&str, NotMatchedAction> {
+    alt((
+        preceded(
+            parse_keyword(Keyword::Insert),
+            alt((
+                value(
+                    NotMatchedAction::InsertDefaultValues,
+                    pair(
+                        parse_keyword(Keyword::Default),
+                        parse_keyword(Keyword::Values),
+                    ),
+                ),
+                map(
+                    pair(opt(parse_column_list), parse_values_clause),
+                    |(c, v)| NotMatchedAction::Insert(c, v),
+                ),
+            )),
+        ),
+        value(NotMatchedAction::DoNothing, parse_do_nothing),
+    ))(input)
+}
@@ -0,0 +1,95 @@
+use crate::assert_format;
+use crate::expression::Compare;
+use crate::expression::Condition;
+use crate::expression::Expression;
+use crate::expression::Operand;
+use crate::expression::RightOperand;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::merge::parse_merge_statement;
+use crate::merge::MatchedAction;
+use crate::merge::MergeIntoClause;
+use crate::merge::MergeSource;
+use crate::merge::MergeStatement;
+use crate::merge::MergeUsingClause;
+use crate::merge::MergeWhenClause;
+use crate::table::create::TableRef;
+use crate::term::column::ColumnRef;
+use crate::term::Term;
+
+#[test]
+fn test_parse_merge_statement() {
+    let input =
+        "MERGE INTO customers c USING new_customers AS n ON c.id = n.id WHEN MATCHED THEN DELETE";
+    assert_eq!(
+        parse_merge_statement(input),
+        Ok((
+            "",
+            MergeStatement(
+                MergeIntoClause(
+                    TableRef(None, Name::Name("customers".to_string())),
+                    Some(Name::Name("c".to_string()))
+                ),
+                MergeUsingClause(
+                    MergeSource::Table(TableRef(None, Name::Name("new_customers".to_string()))),
+                    Some(Name::Name("n".to_string()))
+                ),
+                List(vec!(Expression::Condition(Condition::BinaryExpression(
+                    Operand::Term(Term::ColumnRef(ColumnRef::WithFamily(
+                        Name::Name("c".to_string()),
+                        Name::Name("id".to_string())
+                    ))),
+                    RightOperand::Compare(
+                        Compare::Equal,
+                        Operand::Term(Term::ColumnRef(ColumnRef::WithFamily(
+                            Name::Name("n".to_string()),
+                            Name::Name("id".to_string())
+                        )))
+                    )
+                )))),
+                List(vec!(MergeWhenClause::Matched(None, MatchedAction::Delete)))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_merge_statement() {
+    assert_format!(
+        parse_merge_statement("merge into customers as c using new_customers n on c.id = n.id and c.region = n.region when matched and n.deleted = true then delete when matched then update set name = n.name, email = n.email when not matched then insert (id, name) values (n.id, n.name)"),
+        "MERGE INTO customers AS c\nUSING new_customers AS n\n   ON c.id = n.id\n  AND c.region = n.region\n WHEN MATCHED AND n.deleted = true THEN DELETE\n WHEN MATCHED THEN UPDATE\n  SET name = n.name,\n      email = n.email\n WHEN NOT MATCHED THEN INSERT (id, name) VALUES (n.id, n.name)"
+    )
+}
+
+#[test]
+fn test_format_merge_statement_using_subquery() {
+    assert_format!(
+        parse_merge_statement("MERGE INTO customers USING (SELECT id, name FROM staging WHERE id > 3) AS n ON customers.id = n.id WHEN NOT MATCHED THEN INSERT VALUES (n.id, n.name)"),
+        "MERGE INTO customers\nUSING (SELECT id, name\n         FROM staging\n        WHERE id > 3) AS n\n   ON customers.id = n.id\n WHEN NOT MATCHED THEN INSERT VALUES (n.id, n.name)"
+    )
+}
+
+#[test]
+fn test_format_merge_insert_default_values() {
+    assert_format!(
+        parse_merge_statement("merge into audit using events e on audit.id = e.id when not matched and e.kind = 1 or e.kind = 2 then insert default values"),
+        "MERGE INTO audit\nUSING events AS e\n   ON audit.id = e.id\n WHEN NOT MATCHED AND e.kind = 1 OR e.kind = 2 THEN INSERT DEFAULT VALUES"
+    )
+}
+
+#[test]
+fn test_format_merge_parenthesized_condition() {
+    assert_format!(
+        parse_merge_statement("merge into customers c using new_customers n on (c.id = n.id) when matched and (n.deleted = true or n.region = 'eu') then delete"),
+        "MERGE INTO customers AS c\nUSING new_customers AS n\n   ON (c.id = n.id)\n WHEN MATCHED AND (n.deleted = true OR n.region = 'eu') THEN DELETE"
+    )
+}
+
+#[test]
+fn test_format_merge_do_nothing() {
+    assert_format!(
+        parse_merge_statement("merge into customers c using new_customers n on c.id = n.id when matched then do nothing when not matched then do nothing"),
+        "MERGE INTO customers AS c\nUSING new_customers AS n\n   ON c.id = n.id\n WHEN MATCHED THEN DO NOTHING\n WHEN NOT MATCHED THEN DO NOTHING"
+    )
+}
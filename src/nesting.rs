@@ -0,0 +1,27 @@
+use nom::error::Error;
+use nom::error::ErrorKind;
+use nom::IResult;
+use std::cell::Cell;
+
+// Deeper input is rejected instead of overflowing the stack.
+pub const MAX_DEPTH: usize = 64;
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+pub fn nested<'a, O, F>(mut f: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    move |input: &'a str| {
+        let depth = DEPTH.with(|d| d.get());
+        if depth >= MAX_DEPTH {
+            return Err(nom::Err::Failure(Error::new(input, ErrorKind::TooLarge)));
+        }
+        DEPTH.with(|d| d.set(depth + 1));
+        let result = f(input);
+        DEPTH.with(|d| d.set(depth));
+        result
+    }
+}
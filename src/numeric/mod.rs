@@ -1,23 +1,30 @@
 use nom::branch::alt;
 use nom::character::complete::i32;
+use nom::character::complete::one_of;
 use nom::combinator::map;
+use nom::combinator::not;
+use nom::combinator::peek;
 use nom::number::complete::double;
 use nom::number::complete::float;
+use nom::sequence::terminated;
 
 use nom::IResult;
 use std::fmt;
 use std::str;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Numeric {
     Int(i32),
     Float(f32),
     Decimal(f64),
 }
 
+// Numbers start with a digit, a sign or a dot: `nan` and `inf` are identifiers.
 pub fn parse_numeric(input: &str) -> IResult<&str, Numeric> {
+    let (input, _) = peek(one_of("0123456789+-."))(input)?;
     alt((
-        map(i32, Numeric::Int),
+        map(terminated(i32, not(one_of(".eE"))), Numeric::Int),
         map(float, Numeric::Float),
         map(double, Numeric::Decimal),
     ))(input)
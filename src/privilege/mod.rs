@@ -0,0 +1,411 @@
+pub mod parser;
+pub mod role;
+#[cfg(test)]
+mod role_tests;
+#[cfg(test)]
+mod tests;
+
+pub use self::parser::parse_privilege_statement;
+use crate::clause::Clause;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::identifier::Name;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::table::create::TableRef;
+use crate::table::drop_table::DropBehavior;
+use std::ops::Deref;
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrivilegeStatement {
+    Grant(GrantStatement),
+    Revoke(RevokeStatement),
+    GrantRole(GrantRoleStatement),
+    RevokeRole(RevokeRoleStatement),
+    AlterDefaultPrivileges(AlterDefaultPrivileges),
+}
+
+// GRANT SELECT, INSERT ON TABLE movies TO reader WITH GRANT OPTION
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrantStatement(
+    pub Privileges,
+    pub PrivilegeObject,
+    pub List<Grantee>,
+    pub bool,
+);
+
+// REVOKE GRANT OPTION FOR SELECT ON movies FROM reader CASCADE
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevokeStatement(
+    pub bool,
+    pub Privileges,
+    pub PrivilegeObject,
+    pub List<Grantee>,
+    pub Option<DropBehavior>,
+);
+
+// GRANT admin TO alice WITH ADMIN OPTION
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrantRoleStatement(pub List<Name>, pub List<Grantee>, pub bool);
+
+// REVOKE ADMIN OPTION FOR admin FROM alice
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevokeRoleStatement(
+    pub bool,
+    pub List<Name>,
+    pub List<Grantee>,
+    pub Option<DropBehavior>,
+);
+
+// ALTER DEFAULT PRIVILEGES FOR ROLE admin IN SCHEMA public GRANT SELECT ON TABLES TO reader
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlterDefaultPrivileges(
+    pub Option<List<Name>>,
+    pub Option<List<Name>>,
+    pub Box<PrivilegeStatement>,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Privileges {
+    All,
+    List(List<Privilege>),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Privilege {
+    Select(Option<List<Name>>),
+    Insert(Option<List<Name>>),
+    Update(Option<List<Name>>),
+    References(Option<List<Name>>),
+    Delete,
+    Truncate,
+    Trigger,
+    Usage,
+    Execute,
+    Create,
+    Connect,
+    Temporary,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObjectType {
+    Table,
+    Sequence,
+    Function,
+    Type,
+    Schema,
+    Database,
+    Domain,
+    View,
+    Procedure,
+    Role,
+    Trigger,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrivilegeObject {
+    Objects(Option<ObjectType>, List<TableRef>),
+    AllInSchema(ObjectType, List<Name>),
+    Kind(ObjectType),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Grantee {
+    Public,
+    Role(Name),
+}
+
+impl Clause for GrantStatement {
+    const KEYWORD: &'static Keyword = &Keyword::Grant;
+}
+
+impl Clause for RevokeStatement {
+    const KEYWORD: &'static Keyword = &Keyword::Revoke;
+}
+
+impl ObjectType {
+    pub fn keyword(&self) -> Keyword {
+        match self {
+            Self::Table => Keyword::Table,
+            Self::Sequence => Keyword::Sequence,
+            Self::Function => Keyword::Function,
+            Self::Type => Keyword::Type,
+            Self::Schema => Keyword::Schema,
+            Self::Database => Keyword::Database,
+            Self::Domain => Keyword::Domain,
+            Self::View => Keyword::View,
+            Self::Procedure => Keyword::Procedure,
+            Self::Role => Keyword::Role,
+            Self::Trigger => Keyword::Trigger,
+        }
+    }
+
+    pub fn plural(&self) -> Keyword {
+        match self {
+            Self::Table => Keyword::Tables,
+            Self::Sequence => Keyword::Sequences,
+            Self::Function => Keyword::Functions,
+            Self::Type => Keyword::Types,
+            Self::Schema => Keyword::Schemas,
+            Self::Database => Keyword::Database,
+            Self::Domain => Keyword::Domain,
+            Self::View => Keyword::View,
+            Self::Procedure => Keyword::Procedure,
+            Self::Role => Keyword::Role,
+            Self::Trigger => Keyword::Trigger,
+        }
+    }
+}
+
+impl Format for PrivilegeStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Grant(s) => f.append_format(s),
+            Self::Revoke(s) => f.append_format(s),
+            Self::GrantRole(s) => f.append_format(s),
+            Self::RevokeRole(s) => f.append_format(s),
+            Self::AlterDefaultPrivileges(s) => f.append_format(s),
+        }
+    }
+}
+
+impl Format for GrantStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.set_pad(self.keyword().len());
+        f.append(self.keyword())
+            .ws()
+            .append_format(&self.0)
+            .new_line()
+            .left_side(&Keyword::On)
+            .ws()
+            .append_format(&self.1)
+            .new_line()
+            .left_side(&Keyword::To)
+            .ws()
+            .append_format(&self.2);
+        match self.3 {
+            true => f
+                .new_line()
+                .left_side(&Keyword::With)
+                .ws()
+                .append(&Keyword::Grant)
+                .ws()
+                .append(&Keyword::Option),
+            false => f,
+        }
+    }
+}
+
+impl Format for RevokeStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.set_pad(self.keyword().len());
+        f.append(self.keyword()).ws();
+        match self.0 {
+            true => f
+                .append(&Keyword::Grant)
+                .ws()
+                .append(&Keyword::Option)
+                .ws()
+                .append(&Keyword::For)
+                .ws(),
+            false => f,
+        };
+        f.append_format(&self.1)
+            .new_line()
+            .left_side(&Keyword::On)
+            .ws()
+            .append_format(&self.2)
+            .new_line()
+            .left_side(&Keyword::From)
+            .ws()
+            .append_format(&self.3);
+        match &self.4 {
+            Some(b) => f.ws().append(b),
+            None => f,
+        }
+    }
+}
+
+impl Format for GrantRoleStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.set_pad(Keyword::Grant.len());
+        f.append(&Keyword::Grant)
+            .ws()
+            .append(&self.0)
+            .new_line()
+            .left_side(&Keyword::To)
+            .ws()
+            .append_format(&self.1);
+        match self.2 {
+            true => f
+                .new_line()
+                .left_side(&Keyword::With)
+                .ws()
+                .append(&Keyword::Admin)
+                .ws()
+                .append(&Keyword::Option),
+            false => f,
+        }
+    }
+}
+
+impl Format for RevokeRoleStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.set_pad(Keyword::Revoke.len());
+        f.append(&Keyword::Revoke).ws();
+        match self.0 {
+            true => f
+                .append(&Keyword::Admin)
+                .ws()
+                .append(&Keyword::Option)
+                .ws()
+                .append(&Keyword::For)
+                .ws(),
+            false => f,
+        };
+        f.append(&self.1)
+            .new_line()
+            .left_side(&Keyword::From)
+            .ws()
+            .append_format(&self.2);
+        match &self.3 {
+            Some(b) => f.ws().append(b),
+            None => f,
+        }
+    }
+}
+
+impl Format for AlterDefaultPrivileges {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Alter)
+            .ws()
+            .append(&Keyword::Default)
+            .ws()
+            .append(&Keyword::Privileges);
+        match &self.0 {
+            Some(r) => f
+                .ws()
+                .append(&Keyword::For)
+                .ws()
+                .append(&Keyword::Role)
+                .ws()
+                .append(r),
+            None => f,
+        };
+        match &self.1 {
+            Some(s) => f
+                .ws()
+                .append(&Keyword::In)
+                .ws()
+                .append(&Keyword::Schema)
+                .ws()
+                .append(s),
+            None => f,
+        };
+        f.append_clause(self.2.deref())
+    }
+}
+
+impl Format for Privileges {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::All => f.append(&Keyword::All).ws().append(&Keyword::Privileges),
+            Self::List(l) => {
+                for (pos, p) in l.0.iter().enumerate() {
+                    match pos {
+                        0 => f.append_format(p),
+                        _ => f.append_str(", ").append_format(p),
+                    };
+                }
+                f
+            }
+        }
+    }
+}
+
+impl Format for Privilege {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        let (keyword, columns) = match self {
+            Self::Select(c) => (Keyword::Select, c),
+            Self::Insert(c) => (Keyword::Insert, c),
+            Self::Update(c) => (Keyword::Update, c),
+            Self::References(c) => (Keyword::References, c),
+            Self::Delete => (Keyword::Delete, &None),
+            Self::Truncate => (Keyword::Truncate, &None),
+            Self::Trigger => (Keyword::Trigger, &None),
+            Self::Usage => (Keyword::Usage, &None),
+            Self::Execute => (Keyword::Execute, &None),
+            Self::Create => (Keyword::Create, &None),
+            Self::Connect => (Keyword::Connect, &None),
+            Self::Temporary => (Keyword::Temporary, &None),
+        };
+        f.append(&keyword);
+        match columns {
+            Some(c) => f.ws().append(&format!("({c})")),
+            None => f,
+        }
+    }
+}
+
+impl Format for PrivilegeObject {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Objects(t, l) => {
+                match t {
+                    Some(t) => f.append(&t.keyword()).ws(),
+                    None => f,
+                };
+                for (pos, o) in l.0.iter().enumerate() {
+                    match pos {
+                        0 => f.append(o),
+                        _ => f.append_str(", ").append(o),
+                    };
+                }
+                f
+            }
+            Self::AllInSchema(t, s) => f
+                .append(&Keyword::All)
+                .ws()
+                .append(&t.plural())
+                .ws()
+                .append(&Keyword::In)
+                .ws()
+                .append(&Keyword::Schema)
+                .ws()
+                .append(s),
+            Self::Kind(t) => f.append(&t.plural()),
+        }
+    }
+}
+
+impl Format for Grantee {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Public => f.append(&Keyword::Public),
+            Self::Role(n) => f.append(n),
+        }
+    }
+}
+
+impl Format for List<Grantee> {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        for (pos, i) in self.0.iter().enumerate() {
+            match pos {
+                0 => f.append_format(i),
+                _ => f.append_str(", ").append_format(i),
+            };
+        }
+        f
+    }
+}
@@ -0,0 +1,247 @@
+use crate::character::parse_comma;
+use crate::clause::Clause;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::insert::parse_column_list;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::privilege::AlterDefaultPrivileges;
+use crate::privilege::GrantRoleStatement;
+use crate::privilege::GrantStatement;
+use crate::privilege::Grantee;
+use crate::privilege::ObjectType;
+use crate::privilege::Privilege;
+use crate::privilege::PrivilegeObject;
+use crate::privilege::PrivilegeStatement;
+use crate::privilege::Privileges;
+use crate::privilege::RevokeRoleStatement;
+use crate::privilege::RevokeStatement;
+use crate::table::create::parse_table_ref;
+use crate::table::drop_table::parse_drop_behavior;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::opt;
+use nom::combinator::value;
+use nom::multi::separated_list1;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::tuple;
+use nom::IResult;
+
+pub fn parse_privilege_statement(input: &str) -> IResult<&str, PrivilegeStatement> {
+    alt((
+        map(parse_grant_statement, PrivilegeStatement::Grant),
+        map(parse_revoke_statement, PrivilegeStatement::Revoke),
+        map(parse_grant_role_statement, PrivilegeStatement::GrantRole),
+        map(parse_revoke_role_statement, PrivilegeStatement::RevokeRole),
+        map(
+            parse_alter_default_privileges,
+            PrivilegeStatement::AlterDefaultPrivileges,
+        ),
+    ))(input)
+}
+
+fn parse_names(input: &str) -> IResult<&str, List<Name>> {
+    map(separated_list1(parse_comma, ws(parse_name)), List)(input)
+}
+
+fn parse_grantees(input: &str) -> IResult<&str, List<Grantee>> {
+    map(
+        separated_list1(
+            parse_comma,
+            alt((
+                value(Grantee::Public, parse_keyword(Keyword::Public)),
+                map(ws(parse_name), Grantee::Role),
+            )),
+        ),
+        List,
+    )(input)
+}
+
+pub fn parse_grant_statement(input: &str) -> IResult<&str, GrantStatement> {
+    map(
+        tuple((
+            GrantStatement::parse_keyword,
+            parse_privileges,
+            parse_keyword(Keyword::On),
+            parse_privilege_object,
+            parse_keyword(Keyword::To),
+            parse_grantees,
+            opt(tuple((
+                parse_keyword(Keyword::With),
+                parse_keyword(Keyword::Grant),
+                parse_keyword(Keyword::Option),
+            ))),
+        )),
+        |(_, p, _, o, _, g, w)| GrantStatement(p, o, g, w.is_some()),
+    )(input)
+}
+
+pub fn parse_revoke_statement(input: &str) -> IResult<&str, RevokeStatement> {
+    map(
+        tuple((
+            RevokeStatement::parse_keyword,
+            opt(tuple((
+                parse_keyword(Keyword::Grant),
+                parse_keyword(Keyword::Option),
+                parse_keyword(Keyword::For),
+            ))),
+            parse_privileges,
+            parse_keyword(Keyword::On),
+            parse_privilege_object,
+            parse_keyword(Keyword::From),
+            parse_grantees,
+            opt(parse_drop_behavior),
+        )),
+        |(_, g, p, _, o, _, r, b)| RevokeStatement(g.is_some(), p, o, r, b),
+    )(input)
+}
+
+pub fn parse_grant_role_statement(input: &str) -> IResult<&str, GrantRoleStatement> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Grant),
+            parse_names,
+            parse_keyword(Keyword::To),
+            parse_grantees,
+            opt(tuple((
+                parse_keyword(Keyword::With),
+                parse_keyword(Keyword::Admin),
+                parse_keyword(Keyword::Option),
+            ))),
+        )),
+        |(_, r, _, g, w)| GrantRoleStatement(r, g, w.is_some()),
+    )(input)
+}
+
+pub fn parse_revoke_role_statement(input: &str) -> IResult<&str, RevokeRoleStatement> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Revoke),
+            opt(tuple((
+                parse_keyword(Keyword::Admin),
+                parse_keyword(Keyword::Option),
+                parse_keyword(Keyword::For),
+            ))),
+            parse_names,
+            parse_keyword(Keyword::From),
+            parse_grantees,
+            opt(parse_drop_behavior),
+        )),
+        |(_, a, r, _, g, b)| RevokeRoleStatement(a.is_some(), r, g, b),
+    )(input)
+}
+
+pub fn parse_alter_default_privileges(input: &str) -> IResult<&str, AlterDefaultPrivileges> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Alter),
+            parse_keyword(Keyword::Default),
+            parse_keyword(Keyword::Privileges),
+            opt(preceded(
+                pair(parse_keyword(Keyword::For), parse_keyword(Keyword::Role)),
+                parse_names,
+            )),
+            opt(preceded(
+                pair(parse_keyword(Keyword::In), parse_keyword(Keyword::Schema)),
+                parse_names,
+            )),
+            alt((
+                map(parse_grant_statement, PrivilegeStatement::Grant),
+                map(parse_revoke_statement, PrivilegeStatement::Revoke),
+            )),
+        )),
+        |(_, _, _, r, s, g)| AlterDefaultPrivileges(r, s, Box::new(g)),
+    )(input)
+}
+
+pub fn parse_privileges(input: &str) -> IResult<&str, Privileges> {
+    alt((
+        value(
+            Privileges::All,
+            pair(
+                parse_keyword(Keyword::All),
+                opt(parse_keyword(Keyword::Privileges)),
+            ),
+        ),
+        map(separated_list1(parse_comma, parse_privilege), |l| {
+            Privileges::List(List(l))
+        }),
+    ))(input)
+}
+
+pub fn parse_privilege(input: &str) -> IResult<&str, Privilege> {
+    alt((
+        map(
+            preceded(parse_keyword(Keyword::Select), opt(parse_column_list)),
+            Privilege::Select,
+        ),
+        map(
+            preceded(parse_keyword(Keyword::Insert), opt(parse_column_list)),
+            Privilege::Insert,
+        ),
+        map(
+            preceded(parse_keyword(Keyword::Update), opt(parse_column_list)),
+            Privilege::Update,
+        ),
+        map(
+            preceded(parse_keyword(Keyword::References), opt(parse_column_list)),
+            Privilege::References,
+        ),
+        value(Privilege::Delete, parse_keyword(Keyword::Delete)),
+        value(Privilege::Truncate, parse_keyword(Keyword::Truncate)),
+        value(Privilege::Trigger, parse_keyword(Keyword::Trigger)),
+        value(Privilege::Usage, parse_keyword(Keyword::Usage)),
+        value(Privilege::Execute, parse_keyword(Keyword::Execute)),
+        value(Privilege::Create, parse_keyword(Keyword::Create)),
+        value(Privilege::Connect, parse_keyword(Keyword::Connect)),
+        value(Privilege::Temporary, parse_keyword(Keyword::Temporary)),
+    ))(input)
+}
+
+pub fn parse_object_type(input: &str) -> IResult<&str, ObjectType> {
+    alt((
+        value(ObjectType::Table, parse_keyword(Keyword::Table)),
+        value(ObjectType::Sequence, parse_keyword(Keyword::Sequence)),
+        value(ObjectType::Function, parse_keyword(Keyword::Function)),
+        value(ObjectType::Type, parse_keyword(Keyword::Type)),
+        value(ObjectType::Schema, parse_keyword(Keyword::Schema)),
+        value(ObjectType::Database, parse_keyword(Keyword::Database)),
+        value(ObjectType::Domain, parse_keyword(Keyword::Domain)),
+    ))(input)
+}
+
+fn parse_object_type_plural(input: &str) -> IResult<&str, ObjectType> {
+    alt((
+        value(ObjectType::Table, parse_keyword(Keyword::Tables)),
+        value(ObjectType::Sequence, parse_keyword(Keyword::Sequences)),
+        value(ObjectType::Function, parse_keyword(Keyword::Functions)),
+        value(ObjectType::Type, parse_keyword(Keyword::Types)),
+        value(ObjectType::Schema, parse_keyword(Keyword::Schemas)),
+    ))(input)
+}
+
+pub fn parse_privilege_object(input: &str) -> IResult<&str, PrivilegeObject> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::All),
+                parse_object_type_plural,
+                parse_keyword(Keyword::In),
+                parse_keyword(Keyword::Schema),
+                parse_names,
+            )),
+            |(_, t, _, _, s)| PrivilegeObject::AllInSchema(t, s),
+        ),
+        map(parse_object_type_plural, PrivilegeObject::Kind),
+        map(
+            pair(
+                opt(parse_object_type),
+                separated_list1(parse_comma, ws(parse_table_ref)),
+            ),
+            |(t, l)| PrivilegeObject::Objects(t, List(l)),
+        ),
+    ))(input)
+}
@@ -0,0 +1,239 @@
+use crate::character::parse_comma;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::term::value::parse_value;
+use crate::term::value::Value;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::i32;
+use nom::combinator::map;
+use nom::combinator::opt;
+use nom::combinator::value;
+use nom::multi::many0;
+use nom::multi::separated_list1;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoleStatement {
+    // CREATE ROLE reader WITH LOGIN PASSWORD 'secret'
+    Create(RoleKind, Name, List<RoleOption>),
+    // ALTER ROLE reader WITH NOLOGIN
+    Alter(RoleKind, Name, List<RoleOption>),
+    // DROP ROLE IF EXISTS reader, writer
+    Drop(RoleKind, bool, List<Name>),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoleKind {
+    Role,
+    User,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoleOption {
+    Flag(bool, RoleFlag),
+    ConnectionLimit(i32),
+    Password(Value),
+    ValidUntil(Value),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoleFlag {
+    Superuser,
+    Createdb,
+    Createrole,
+    Inherit,
+    Login,
+    Replication,
+    Bypassrls,
+}
+
+impl RoleFlag {
+    pub fn keyword(&self) -> Keyword {
+        match self {
+            Self::Superuser => Keyword::Superuser,
+            Self::Createdb => Keyword::Createdb,
+            Self::Createrole => Keyword::Createrole,
+            Self::Inherit => Keyword::Inherit,
+            Self::Login => Keyword::Login,
+            Self::Replication => Keyword::Replication,
+            Self::Bypassrls => Keyword::Bypassrls,
+        }
+    }
+}
+
+fn parse_role_kind(input: &str) -> IResult<&str, RoleKind> {
+    alt((
+        value(RoleKind::Role, parse_keyword(Keyword::Role)),
+        value(RoleKind::User, parse_keyword(Keyword::User)),
+    ))(input)
+}
+
+fn parse_role_flag(input: &str) -> IResult<&str, RoleFlag> {
+    alt((
+        value(RoleFlag::Superuser, parse_keyword(Keyword::Superuser)),
+        value(RoleFlag::Createdb, parse_keyword(Keyword::Createdb)),
+        value(RoleFlag::Createrole, parse_keyword(Keyword::Createrole)),
+        value(RoleFlag::Inherit, parse_keyword(Keyword::Inherit)),
+        value(RoleFlag::Login, parse_keyword(Keyword::Login)),
+        value(RoleFlag::Replication, parse_keyword(Keyword::Replication)),
+        value(RoleFlag::Bypassrls, parse_keyword(Keyword::Bypassrls)),
+    ))(input)
+}
+
+pub fn parse_role_option(input: &str) -> IResult<&str, RoleOption> {
+    alt((
+        map(parse_role_flag, |f| RoleOption::Flag(true, f)),
+        map(preceded(ws(tag_no_case("no")), parse_role_flag), |f| {
+            RoleOption::Flag(false, f)
+        }),
+        map(
+            preceded(
+                pair(
+                    parse_keyword(Keyword::Connection),
+                    parse_keyword(Keyword::Limit),
+                ),
+                ws(i32),
+            ),
+            RoleOption::ConnectionLimit,
+        ),
+        map(
+            preceded(parse_keyword(Keyword::Password), ws(parse_value)),
+            RoleOption::Password,
+        ),
+        map(
+            preceded(
+                pair(parse_keyword(Keyword::Valid), parse_keyword(Keyword::Until)),
+                ws(parse_value),
+            ),
+            RoleOption::ValidUntil,
+        ),
+    ))(input)
+}
+
+fn parse_role_options(input: &str) -> IResult<&str, List<RoleOption>> {
+    map(
+        preceded(opt(parse_keyword(Keyword::With)), many0(parse_role_option)),
+        List,
+    )(input)
+}
+
+pub fn parse_role_statement(input: &str) -> IResult<&str, RoleStatement> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::Create),
+                parse_role_kind,
+                ws(parse_name),
+                parse_role_options,
+            )),
+            |(_, k, n, o)| RoleStatement::Create(k, n, o),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Alter),
+                parse_role_kind,
+                ws(parse_name),
+                parse_role_options,
+            )),
+            |(_, k, n, o)| RoleStatement::Alter(k, n, o),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Drop),
+                parse_role_kind,
+                opt(pair(
+                    parse_keyword(Keyword::If),
+                    parse_keyword(Keyword::Exists),
+                )),
+                separated_list1(parse_comma, ws(parse_name)),
+            )),
+            |(_, k, e, n)| RoleStatement::Drop(k, e.is_some(), List(n)),
+        ),
+    ))(input)
+}
+
+impl Format for RoleStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Create(k, n, o) => f
+                .append(&Keyword::Create)
+                .ws()
+                .append_format(k)
+                .ws()
+                .append(n)
+                .append_format(o),
+            Self::Alter(k, n, o) => f
+                .append(&Keyword::Alter)
+                .ws()
+                .append_format(k)
+                .ws()
+                .append(n)
+                .append_format(o),
+            Self::Drop(k, e, n) => {
+                f.append(&Keyword::Drop).ws().append_format(k).ws();
+                match e {
+                    true => f.append(&Keyword::If).ws().append(&Keyword::Exists).ws(),
+                    false => f,
+                };
+                f.append(n)
+            }
+        }
+    }
+}
+
+impl Format for RoleKind {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Role => f.append(&Keyword::Role),
+            Self::User => f.append(&Keyword::User),
+        }
+    }
+}
+
+impl Format for RoleOption {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Flag(true, r) => f.append(&r.keyword()),
+            Self::Flag(false, r) => f.append_str("NO").append(&r.keyword()),
+            Self::ConnectionLimit(l) => f
+                .append(&Keyword::Connection)
+                .ws()
+                .append(&Keyword::Limit)
+                .ws()
+                .append(l),
+            Self::Password(p) => f.append(&Keyword::Password).ws().append_format(p),
+            Self::ValidUntil(v) => f
+                .append(&Keyword::Valid)
+                .ws()
+                .append(&Keyword::Until)
+                .ws()
+                .append_format(v),
+        }
+    }
+}
+
+impl Format for List<RoleOption> {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        if !self.0.is_empty() {
+            f.ws().append(&Keyword::With);
+        }
+        for o in self.0.iter() {
+            f.ws().append_format(o);
+        }
+        f
+    }
+}
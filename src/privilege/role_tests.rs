@@ -0,0 +1,80 @@
+use crate::assert_format;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::privilege::role::parse_role_statement;
+use crate::privilege::role::RoleFlag;
+use crate::privilege::role::RoleKind;
+use crate::privilege::role::RoleOption;
+use crate::privilege::role::RoleStatement;
+use crate::term::value::Value;
+
+#[test]
+fn test_parse_create_role() {
+    let input = "CREATE ROLE reader WITH LOGIN NOINHERIT CONNECTION LIMIT 3 PASSWORD 'secret'";
+    assert_eq!(
+        parse_role_statement(input),
+        Ok((
+            "",
+            RoleStatement::Create(
+                RoleKind::Role,
+                Name::Name("reader".to_string()),
+                List(vec!(
+                    RoleOption::Flag(true, RoleFlag::Login),
+                    RoleOption::Flag(false, RoleFlag::Inherit),
+                    RoleOption::ConnectionLimit(3),
+                    RoleOption::Password(Value::String("secret".to_string()))
+                ))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_parse_drop_role() {
+    assert_eq!(
+        parse_role_statement("DROP USER IF EXISTS alice, bob"),
+        Ok((
+            "",
+            RoleStatement::Drop(
+                RoleKind::User,
+                true,
+                List(vec!(
+                    Name::Name("alice".to_string()),
+                    Name::Name("bob".to_string())
+                ))
+            )
+        ))
+    );
+    assert_eq!(
+        parse_role_statement("ALTER ROLE reader CONNECTION LIMIT 10"),
+        Ok((
+            "",
+            RoleStatement::Alter(
+                RoleKind::Role,
+                Name::Name("reader".to_string()),
+                List(vec!(RoleOption::ConnectionLimit(10)))
+            )
+        ))
+    );
+}
+
+#[test]
+fn test_format_role_statement() {
+    assert_format!(
+        parse_role_statement("create user alice login createdb valid until '2030-01-01'"),
+        "CREATE USER alice WITH LOGIN CREATEDB VALID UNTIL '2030-01-01'"
+    );
+    assert_format!(
+        parse_role_statement("alter role reader with nologin"),
+        "ALTER ROLE reader WITH NOLOGIN"
+    );
+    assert_format!(
+        parse_role_statement("create role reader"),
+        "CREATE ROLE reader"
+    );
+    assert_format!(
+        parse_role_statement("drop role if exists reader"),
+        "DROP ROLE IF EXISTS reader"
+    );
+}
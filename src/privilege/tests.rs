@@ -0,0 +1,139 @@
+use crate::assert_format;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::privilege::parse_privilege_statement;
+use crate::privilege::parser::parse_grant_statement;
+use crate::privilege::parser::parse_privilege_object;
+use crate::privilege::parser::parse_revoke_statement;
+use crate::privilege::GrantStatement;
+use crate::privilege::Grantee;
+use crate::privilege::ObjectType;
+use crate::privilege::Privilege;
+use crate::privilege::PrivilegeObject;
+use crate::privilege::Privileges;
+use crate::privilege::RevokeStatement;
+use crate::table::create::TableRef;
+use crate::table::drop_table::DropBehavior;
+
+#[test]
+fn test_parse_grant_statement() {
+    let input = "GRANT SELECT, UPDATE (title) ON TABLE movies TO reader, PUBLIC WITH GRANT OPTION";
+    assert_eq!(
+        parse_grant_statement(input),
+        Ok((
+            "",
+            GrantStatement(
+                Privileges::List(List(vec!(
+                    Privilege::Select(None),
+                    Privilege::Update(Some(List(vec!(Name::Name("title".to_string())))))
+                ))),
+                PrivilegeObject::Objects(
+                    Some(ObjectType::Table),
+                    List(vec!(TableRef(None, Name::Name("movies".to_string()))))
+                ),
+                List(vec!(
+                    Grantee::Role(Name::Name("reader".to_string())),
+                    Grantee::Public
+                )),
+                true
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_parse_revoke_statement() {
+    let input = "REVOKE GRANT OPTION FOR ALL PRIVILEGES ON movies FROM reader CASCADE";
+    assert_eq!(
+        parse_revoke_statement(input),
+        Ok((
+            "",
+            RevokeStatement(
+                true,
+                Privileges::All,
+                PrivilegeObject::Objects(
+                    None,
+                    List(vec!(TableRef(None, Name::Name("movies".to_string()))))
+                ),
+                List(vec!(Grantee::Role(Name::Name("reader".to_string())))),
+                Some(DropBehavior::Cascade)
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_parse_privilege_object() {
+    assert_eq!(
+        parse_privilege_object("ALL TABLES IN SCHEMA public"),
+        Ok((
+            "",
+            PrivilegeObject::AllInSchema(
+                ObjectType::Table,
+                List(vec!(Name::Name("public".to_string())))
+            )
+        ))
+    );
+    assert_eq!(
+        parse_privilege_object("SEQUENCES"),
+        Ok(("", PrivilegeObject::Kind(ObjectType::Sequence)))
+    );
+}
+
+#[test]
+fn test_format_grant_statement() {
+    assert_format!(
+        parse_privilege_statement(
+            "grant select,insert on table movies to reader with grant option"
+        ),
+        "GRANT SELECT, INSERT
+   ON TABLE movies
+   TO reader
+ WITH GRANT OPTION"
+    );
+    assert_format!(
+        parse_privilege_statement("grant usage on all sequences in schema public to public"),
+        "GRANT USAGE
+   ON ALL SEQUENCES IN SCHEMA public
+   TO PUBLIC"
+    );
+}
+
+#[test]
+fn test_format_revoke_statement() {
+    assert_format!(
+        parse_privilege_statement("revoke all on movies from reader cascade"),
+        "REVOKE ALL PRIVILEGES
+    ON movies
+  FROM reader CASCADE"
+    );
+}
+
+#[test]
+fn test_format_role_membership() {
+    assert_format!(
+        parse_privilege_statement("grant admin, auditor to alice with admin option"),
+        "GRANT admin, auditor
+   TO alice
+ WITH ADMIN OPTION"
+    );
+    assert_format!(
+        parse_privilege_statement("revoke admin option for admin from alice"),
+        "REVOKE ADMIN OPTION FOR admin
+  FROM alice"
+    );
+}
+
+#[test]
+fn test_format_alter_default_privileges() {
+    assert_format!(
+        parse_privilege_statement(
+            "alter default privileges for role admin in schema public grant select on tables to reader"
+        ),
+        "ALTER DEFAULT PRIVILEGES FOR ROLE admin IN SCHEMA public
+GRANT SELECT
+   ON TABLES
+   TO reader"
+    );
+}
@@ -1,19 +1,45 @@
+use crate::alter::parse_alter_object_statement;
+use crate::alter::AlterObjectStatement;
+use crate::comment::on::parse_comment_on_statement;
+use crate::comment::on::CommentOnStatement;
+use crate::domain::parse_create_domain;
+use crate::domain::CreateDomainStatement;
+use crate::extension::parse_extension_statement;
+use crate::extension::ExtensionStatement;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
+use crate::function::create::parse_create_function;
+use crate::function::create::CreateFunctionStatement;
 use crate::insert::parse_insert_into_statement;
 use crate::insert::InsertStatement;
 use crate::list::List;
+use crate::merge::parse_merge_statement;
+use crate::merge::MergeStatement;
+use crate::privilege::parse_privilege_statement;
+use crate::privilege::role::parse_role_statement;
+use crate::privilege::role::RoleStatement;
+use crate::privilege::PrivilegeStatement;
 use crate::r#type::parse_user_defined_type_definition;
 use crate::r#type::UserDefinedTypeDefinition;
+use crate::schema::parse_schema_statement;
+use crate::schema::SchemaStatement;
 use crate::select::table_operator::combined_tables;
 use crate::select::table_operator::CombinedTables;
 use crate::select::{parse_select_statement, SelectStatement};
 use crate::sequence::parse_sequence;
 use crate::sequence::Sequence;
+use crate::set::parse_reset_statement;
 use crate::set::parse_set_statement;
+use crate::set::ResetStatement;
 use crate::set::SetStatement;
 use crate::table::parse_table;
 use crate::table::Table;
+use crate::transaction::parse_transaction_statement;
+use crate::transaction::TransactionStatement;
+use crate::trigger::parse_create_trigger;
+use crate::trigger::CreateTriggerStatement;
+use crate::truncate::parse_truncate_statement;
+use crate::truncate::TruncateStatement;
 use crate::update::parser::parse_update_statement;
 use crate::update::UpdateStatement;
 use crate::ws::ws;
@@ -26,7 +52,8 @@ use nom::bytes::complete::tag;
 use nom::combinator::map;
 use nom::IResult;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Statement(Query);
 
 impl Format for Statement {
@@ -46,11 +73,25 @@ impl Format for Query {
             Query::Sequence(c) => f.append_format(c),
             Query::UserDefinedType(c) => f.append_format(c),
             Query::Set(c) => f.append_format(c),
+            Query::Merge(c) => f.append_format(c),
+            Query::Transaction(c) => f.append_format(c),
+            Query::Reset(c) => f.append_format(c),
+            Query::Privilege(c) => f.append_format(c),
+            Query::Role(c) => f.append_format(c),
+            Query::Function(c) => f.append_format(c),
+            Query::Trigger(c) => f.append_format(c),
+            Query::Schema(c) => f.append_format(c),
+            Query::Extension(c) => f.append_format(c),
+            Query::CommentOn(c) => f.append_format(c),
+            Query::Truncate(c) => f.append_format(c),
+            Query::AlterObject(c) => f.append_format(c),
+            Query::Domain(c) => f.append_format(c),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Query {
     CombinedSelect(CombinedTables),
     Select(Box<SelectStatement>),
@@ -60,6 +101,19 @@ pub enum Query {
     Sequence(Sequence),
     UserDefinedType(UserDefinedTypeDefinition),
     Set(SetStatement),
+    Merge(MergeStatement),
+    Transaction(TransactionStatement),
+    Reset(ResetStatement),
+    Privilege(PrivilegeStatement),
+    Role(RoleStatement),
+    Function(CreateFunctionStatement),
+    Trigger(CreateTriggerStatement),
+    Schema(SchemaStatement),
+    Extension(ExtensionStatement),
+    CommentOn(CommentOnStatement),
+    Truncate(TruncateStatement),
+    AlterObject(AlterObjectStatement),
+    Domain(CreateDomainStatement),
 }
 
 pub fn parse_query(input: &str) -> IResult<&str, Query> {
@@ -71,7 +125,20 @@ pub fn parse_query(input: &str) -> IResult<&str, Query> {
         map(parse_insert_into_statement, Query::Insert),
         map(parse_sequence, Query::Sequence),
         map(parse_user_defined_type_definition, Query::UserDefinedType),
+        map(parse_transaction_statement, Query::Transaction),
         map(parse_set_statement, Query::Set),
+        map(parse_reset_statement, Query::Reset),
+        map(parse_merge_statement, Query::Merge),
+        map(parse_privilege_statement, Query::Privilege),
+        map(parse_alter_object_statement, Query::AlterObject),
+        map(parse_role_statement, Query::Role),
+        map(parse_create_function, Query::Function),
+        map(parse_create_trigger, Query::Trigger),
+        map(parse_schema_statement, Query::Schema),
+        map(parse_extension_statement, Query::Extension),
+        map(parse_comment_on_statement, Query::CommentOn),
+        map(parse_truncate_statement, Query::Truncate),
+        map(parse_create_domain, Query::Domain),
     ))(input)
 }
 
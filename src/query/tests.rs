@@ -5,6 +5,7 @@ use crate::expression::Operand;
 use crate::formatter::Format;
 use crate::identifier::Name;
 use crate::insert::InsertIntoClause;
+use crate::insert::InsertSource;
 use crate::insert::InsertStatement;
 use crate::insert::InsertValue;
 use crate::insert::ValuesClause;
@@ -35,6 +36,7 @@ fn test_parse_statements() {
             List(vec!(
                 Statement(Query::Select(Box::new(SelectStatement {
                     select: SelectClause(
+                        None,
                         None,
                         List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
                             Numeric::Int(1)
@@ -59,18 +61,22 @@ fn test_parse_statements() {
                             Name::Name(String::from("release_year"))
                         )))
                     ),
-                    ValuesClause(List(vec!(InsertValue::ParenthesisExpression(List(vec!(
-                        InsertValue::Expression(Box::new(Expression::Condition(
-                            Condition::Operand(Operand::Term(Term::Value(Value::String(
-                                "Aliento".to_string()
-                            ))))
-                        ))),
-                        InsertValue::Expression(Box::new(Expression::Condition(
-                            Condition::Operand(Operand::Term(Term::Value(Value::Num(
-                                Numeric::Int(2017)
-                            ))))
-                        ))),
-                    ))))))
+                    InsertSource::Values(ValuesClause(List(vec!(
+                        InsertValue::ParenthesisExpression(List(vec!(
+                            InsertValue::Expression(Box::new(Expression::Condition(
+                                Condition::Operand(Operand::Term(Term::Value(Value::String(
+                                    "Aliento".to_string()
+                                ))))
+                            ))),
+                            InsertValue::Expression(Box::new(Expression::Condition(
+                                Condition::Operand(Operand::Term(Term::Value(Value::Num(
+                                    Numeric::Int(2017)
+                                ))))
+                            ))),
+                        )))
+                    )))),
+                    None,
+                    None
                 )))
             ))
         ))
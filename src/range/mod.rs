@@ -0,0 +1,104 @@
+use crate::formatter::Format;
+use crate::formatter::FormatOptions;
+use crate::query::parse_query;
+use crate::query::Query;
+use crate::split::split_statements;
+use crate::ws::ws;
+use crate::Error;
+use crate::FResult;
+use nom::bytes::complete::tag;
+use nom::sequence::terminated;
+use nom::Parser;
+use std::ops::Range;
+
+// A statement and its byte offsets in the source, `query` is `None` when it cannot be parsed.
+#[derive(Debug, PartialEq, Clone)]
+pub struct StatementSpan {
+    pub start: usize,
+    pub end: usize,
+    pub query: Option<Query>,
+}
+
+// Replaces the bytes `start..end` of the source by `text`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+pub fn statement_spans(input: &str) -> Vec<StatementSpan> {
+    split_statements(input)
+        .into_iter()
+        .map(|r| {
+            let query = match terminated(parse_query, ws(tag(";"))).parse(&input[r.clone()]) {
+                Ok(("", q)) => Some(q),
+                _ => None,
+            };
+            StatementSpan {
+                start: r.start,
+                end: r.end,
+                query,
+            }
+        })
+        .collect()
+}
+
+pub fn format_range(
+    s: &str,
+    range: Range<usize>,
+    options: FormatOptions,
+) -> FResult<Vec<TextEdit>> {
+    let mut edits = vec![];
+    for span in statement_spans(s) {
+        if span.end < range.start || range.end < span.start {
+            continue;
+        }
+        let source = &s[span.start..span.end];
+        let formatted = match span.query {
+            Some(q) => format!("{};", q.output_with(options)),
+            None => return Err(Error::ParsingIncompleteError(source.to_string())),
+        };
+        if formatted != source {
+            edits.push(TextEdit {
+                start: span.start,
+                end: span.end,
+                text: formatted,
+            });
+        }
+    }
+    Ok(edits)
+}
+
+// Applies edits sorted by offset and not overlapping, as returned by `format_range`.
+pub fn apply_edits(s: &str, edits: &[TextEdit]) -> String {
+    let mut output = String::new();
+    let mut offset = 0;
+    for edit in edits {
+        output.push_str(&s[offset..edit.start]);
+        output.push_str(&edit.text);
+        offset = edit.end;
+    }
+    output.push_str(&s[offset..]);
+    output
+}
+
+// Converts an offset in UTF-16 code units, as in JavaScript strings, to a byte offset.
+pub fn byte_offset(s: &str, utf16: usize) -> usize {
+    let mut units = 0;
+    for (offset, c) in s.char_indices() {
+        if units >= utf16 {
+            return offset;
+        }
+        units += c.len_utf16();
+    }
+    s.len()
+}
+
+// Converts a byte offset to an offset in UTF-16 code units.
+pub fn utf16_offset(s: &str, offset: usize) -> usize {
+    s[..offset].encode_utf16().count()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,64 @@
+use crate::format_range;
+use crate::range::apply_edits;
+use crate::range::byte_offset;
+use crate::range::statement_spans;
+use crate::range::utf16_offset;
+use crate::range::TextEdit;
+use crate::Error;
+use crate::FormatOptions;
+
+#[test]
+fn test_statement_spans() {
+    let input = "  select 1 ;\nVACUUM movies;\n\nselect 2;\n";
+    let spans: Vec<(&str, bool)> = statement_spans(input)
+        .iter()
+        .map(|s| (&input[s.start..s.end], s.query.is_some()))
+        .collect();
+    assert_eq!(
+        spans,
+        vec!(
+            ("select 1 ;", true),
+            ("VACUUM movies;", false),
+            ("select 2;", true)
+        )
+    );
+}
+
+#[test]
+fn test_format_range() {
+    let input = "select 1;\nselect title from movies;\n";
+    assert_eq!(
+        format_range(input, 14..14, FormatOptions::default()),
+        Ok(vec!(TextEdit {
+            start: 10,
+            end: 35,
+            text: "SELECT title\n  FROM movies;".to_string()
+        }))
+    );
+    let edits = format_range(input, 0..input.len(), FormatOptions::default()).unwrap();
+    assert_eq!(
+        apply_edits(input, &edits),
+        "SELECT 1;\nSELECT title\n  FROM movies;\n"
+    );
+}
+
+#[test]
+fn test_format_range_untouched() {
+    let input = "SELECT 1;\nVACUUM movies;\nselect 2;";
+    let edits = format_range(input, 0..3, FormatOptions::default()).unwrap();
+    assert_eq!(edits, vec!());
+    assert_eq!(
+        format_range(input, 12..12, FormatOptions::default()),
+        Err(Error::ParsingIncompleteError("VACUUM movies;".to_string()))
+    );
+}
+
+#[test]
+fn test_byte_offset() {
+    let input = "SELECT 'é😀', 1;";
+    assert_eq!(byte_offset(input, 9), 10);
+    assert_eq!(byte_offset(input, 11), 14);
+    assert_eq!(byte_offset(input, 100), input.len());
+    assert_eq!(utf16_offset(input, 10), 9);
+    assert_eq!(utf16_offset(input, 14), 11);
+}
@@ -0,0 +1,41 @@
+use crate::character::parse_comma;
+use crate::clause::Clause;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::select::clause::parse_selected_expression;
+use crate::select::clause::SelectedExpression;
+use crate::ws::ws;
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::pair;
+use nom::IResult;
+
+// RETURNING id, created_at
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReturningClause(pub List<SelectedExpression>);
+
+impl Clause for ReturningClause {
+    const KEYWORD: &'static Keyword = &Keyword::Returning;
+}
+
+pub fn parse_returning_clause(input: &str) -> IResult<&str, ReturningClause> {
+    map(
+        pair(
+            ReturningClause::parse_keyword,
+            separated_list1(parse_comma, ws(parse_selected_expression)),
+        ),
+        |(_, l)| ReturningClause(List(l)),
+    )(input)
+}
+
+impl Format for ReturningClause {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.left_side(self.keyword()).ws().append_format(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests;
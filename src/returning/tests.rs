@@ -0,0 +1,31 @@
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::returning::parse_returning_clause;
+use crate::returning::ReturningClause;
+use crate::select::clause::SelectedExpression;
+use crate::term::column::ColumnRef;
+use crate::term::Term;
+
+#[test]
+fn test_parse_returning_clause() {
+    let input = "RETURNING id, *";
+    assert_eq!(
+        parse_returning_clause(input),
+        Ok((
+            "",
+            ReturningClause(List(vec!(
+                SelectedExpression::Term(Term::ColumnRef(ColumnRef::Name(Name::Name(
+                    "id".to_string()
+                )))),
+                SelectedExpression::All
+            )))
+        ))
+    )
+}
+
+#[test]
+fn test_format_returning_clause() {
+    let (_, t) = parse_returning_clause("returning   id,name").unwrap();
+    assert_eq!(t.output(), "RETURNING id, name")
+}
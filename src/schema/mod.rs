@@ -0,0 +1,125 @@
+use crate::character::parse_comma;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::table::drop_table::parse_drop_behavior;
+use crate::table::drop_table::DropBehavior;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::opt;
+use nom::multi::separated_list1;
+use nom::sequence::preceded;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchemaStatement {
+    // CREATE SCHEMA IF NOT EXISTS staging AUTHORIZATION admin
+    Create(bool, Name, Option<Name>),
+    // DROP SCHEMA IF EXISTS staging CASCADE
+    Drop(bool, List<Name>, Option<DropBehavior>),
+}
+
+pub fn parse_if_not_exists(input: &str) -> IResult<&str, bool> {
+    map(
+        opt(tuple((
+            parse_keyword(Keyword::If),
+            parse_keyword(Keyword::Not),
+            parse_keyword(Keyword::Exists),
+        ))),
+        |o| o.is_some(),
+    )(input)
+}
+
+pub fn parse_if_exists(input: &str) -> IResult<&str, bool> {
+    map(
+        opt(tuple((
+            parse_keyword(Keyword::If),
+            parse_keyword(Keyword::Exists),
+        ))),
+        |o| o.is_some(),
+    )(input)
+}
+
+pub fn parse_schema_statement(input: &str) -> IResult<&str, SchemaStatement> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::Create),
+                parse_keyword(Keyword::Schema),
+                parse_if_not_exists,
+                ws(parse_name),
+                opt(preceded(
+                    parse_keyword(Keyword::Authorization),
+                    ws(parse_name),
+                )),
+            )),
+            |(_, _, e, n, a)| SchemaStatement::Create(e, n, a),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Drop),
+                parse_keyword(Keyword::Schema),
+                parse_if_exists,
+                separated_list1(parse_comma, ws(parse_name)),
+                opt(parse_drop_behavior),
+            )),
+            |(_, _, e, n, b)| SchemaStatement::Drop(e, List(n), b),
+        ),
+    ))(input)
+}
+
+pub fn format_if_not_exists(f: &mut Formatter, if_not_exists: bool) -> &mut Formatter {
+    match if_not_exists {
+        true => f
+            .append(&Keyword::If)
+            .ws()
+            .append(&Keyword::Not)
+            .ws()
+            .append(&Keyword::Exists)
+            .ws(),
+        false => f,
+    }
+}
+
+pub fn format_if_exists(f: &mut Formatter, if_exists: bool) -> &mut Formatter {
+    match if_exists {
+        true => f.append(&Keyword::If).ws().append(&Keyword::Exists).ws(),
+        false => f,
+    }
+}
+
+impl Format for SchemaStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Create(e, n, a) => {
+                f.append(&Keyword::Create)
+                    .ws()
+                    .append(&Keyword::Schema)
+                    .ws();
+                format_if_not_exists(f, *e).append(n);
+                match a {
+                    Some(a) => f.ws().append(&Keyword::Authorization).ws().append(a),
+                    None => f,
+                }
+            }
+            Self::Drop(e, n, b) => {
+                f.append(&Keyword::Drop).ws().append(&Keyword::Schema).ws();
+                format_if_exists(f, *e).append(n);
+                match b {
+                    Some(b) => f.ws().append(b),
+                    None => f,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+use crate::assert_format;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::schema::parse_schema_statement;
+use crate::schema::SchemaStatement;
+use crate::table::drop_table::DropBehavior;
+
+#[test]
+fn test_parse_schema_statement() {
+    assert_eq!(
+        parse_schema_statement("CREATE SCHEMA IF NOT EXISTS staging AUTHORIZATION admin"),
+        Ok((
+            "",
+            SchemaStatement::Create(
+                true,
+                Name::Name("staging".to_string()),
+                Some(Name::Name("admin".to_string()))
+            )
+        ))
+    );
+    assert_eq!(
+        parse_schema_statement("DROP SCHEMA staging, archive CASCADE"),
+        Ok((
+            "",
+            SchemaStatement::Drop(
+                false,
+                List(vec!(
+                    Name::Name("staging".to_string()),
+                    Name::Name("archive".to_string())
+                )),
+                Some(DropBehavior::Cascade)
+            )
+        ))
+    );
+}
+
+#[test]
+fn test_format_schema_statement() {
+    assert_format!(
+        parse_schema_statement("create schema staging"),
+        "CREATE SCHEMA staging"
+    );
+    assert_format!(
+        parse_schema_statement("drop schema if exists staging restrict"),
+        "DROP SCHEMA IF EXISTS staging RESTRICT"
+    );
+}
@@ -7,6 +7,8 @@ use crate::identifier::Name;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
 use crate::list::List;
+use crate::select::limit::parse_top;
+use crate::select::limit::Top;
 use crate::term::{parse_term, Term};
 use crate::ws::ws;
 use nom::branch::alt;
@@ -17,21 +19,27 @@ use nom::combinator::opt;
 use nom::combinator::value;
 use nom::combinator::value as nomValue;
 use nom::multi::separated_list1;
-use nom::sequence::pair;
 use nom::sequence::tuple;
 use nom::IResult;
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct SelectClause(pub Option<SetQuantifier>, pub List<SelectedExpression>);
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectClause(
+    pub Option<SetQuantifier>,
+    pub Option<Top>,
+    pub List<SelectedExpression>,
+);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectedExpression {
     All,
     AllWithFamilyName(Name),
     Term(Term),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SetQuantifier {
     Distinct,
     All,
@@ -67,7 +75,11 @@ impl Format for SelectClause {
             Some(q) => f.append_format(q).ws(),
             None => f,
         };
-        f.append_format(&self.1)
+        match &self.1 {
+            Some(t) => f.append_format(t).ws(),
+            None => f,
+        };
+        f.append_format(&self.2)
     }
 }
 
@@ -81,15 +93,16 @@ pub fn parse_set_quantifier(input: &str) -> IResult<&str, SetQuantifier> {
 pub fn parse_select_clause(input: &str) -> IResult<&str, SelectClause> {
     let (input, _) = SelectClause::parse_keyword(input)?;
     map(
-        pair(
+        tuple((
             opt(parse_set_quantifier),
+            opt(parse_top),
             separated_list1(parse_comma, ws(parse_selected_expression)),
-        ),
-        |(q, v)| SelectClause(q, List(v)),
+        )),
+        |(q, t, v)| SelectClause(q, t, List(v)),
     )(input)
 }
 
-fn parse_selected_expression(input: &str) -> IResult<&str, SelectedExpression> {
+pub fn parse_selected_expression(input: &str) -> IResult<&str, SelectedExpression> {
     alt((
         nomValue(SelectedExpression::All, ws(tag("*"))),
         map(tuple((parse_name, char('.'), char('*'))), |(n, _, _)| {
@@ -16,6 +16,7 @@ fn test_parse_clause() {
             "",
             SelectClause(
                 Some(SetQuantifier::Distinct),
+                None,
                 List(vec!(SelectedExpression::Term(Term::ColumnRef(
                     ColumnRef::Name(Name::Name("price".to_string()))
                 )))),
@@ -13,22 +13,28 @@ use crate::select::join::JoinClause;
 use crate::ws::ws;
 use nom::branch::alt;
 use nom::combinator::map;
+use nom::combinator::not;
 use nom::combinator::opt;
 use nom::multi::separated_list1;
+use nom::sequence::pair;
+use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FromClause(pub List<TableExpression>, pub Option<List<JoinClause>>);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableName {
     Name(Name),
     AliasedName(Name, Name),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableExpression(pub TableName);
 
 impl fmt::Display for TableName {
@@ -75,12 +81,25 @@ pub fn table_expression(input: &str) -> IResult<&str, TableExpression> {
     map(parse_table_name, TableExpression)(input)
 }
 
-pub fn parse_table_name(input: &str) -> IResult<&str, TableName> {
+// OFFSET and FETCH are not reserved, without AS they start the next clause.
+fn parse_alias(input: &str) -> IResult<&str, Name> {
     alt((
-        map(
-            tuple((parse_name, opt(parse_keyword(Keyword::As)), ws(parse_name))),
-            |(n, _, a)| TableName::AliasedName(n, a),
+        preceded(parse_keyword(Keyword::As), ws(parse_name)),
+        preceded(
+            not(alt((
+                parse_keyword(Keyword::Offset),
+                parse_keyword(Keyword::Fetch),
+            ))),
+            ws(parse_name),
         ),
+    ))(input)
+}
+
+pub fn parse_table_name(input: &str) -> IResult<&str, TableName> {
+    alt((
+        map(pair(parse_name, parse_alias), |(n, a)| {
+            TableName::AliasedName(n, a)
+        }),
         map(parse_name, TableName::Name),
     ))(input)
 }
@@ -1,50 +1,84 @@
 use crate::character::parse_comma;
+use crate::character::parse_left_parenthesis;
+use crate::character::parse_right_parenthesis;
 use crate::clause::Clause;
-use crate::expression::parse_expression;
+use crate::expression::parse_expressions;
 use crate::expression::Expression;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
 use crate::list::List;
-use crate::term::column::parse_column_ref;
+use crate::select::clause::parse_set_quantifier;
+use crate::select::clause::SetQuantifier;
+use crate::select::order::ordinal;
+use crate::select::order::parse_key;
 use crate::term::column::ColumnRef;
+use crate::term::Term;
 use crate::ws::ws;
+use nom::branch::alt;
 use nom::combinator::map;
 use nom::combinator::opt;
+use nom::multi::separated_list0;
 use nom::multi::separated_list1;
+use nom::sequence::delimited;
+use nom::sequence::pair;
+use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct GroupByClause(pub List<GroupingElement>, pub Option<HavingClause>);
+// GROUP BY DISTINCT ROLLUP (year, genre) HAVING COUNT(*) > 1
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupByClause(
+    pub Option<SetQuantifier>,
+    pub List<GroupingElement>,
+    pub Option<HavingClause>,
+);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GroupingElement {
     ColumnRef(ColumnRef),
+    // GROUP BY 1
+    Ordinal(u16),
+    // GROUP BY date_trunc('month', created_at)
+    Term(Term),
+    // (year, genre), ()
+    Set(List<GroupingElement>),
+    // ROLLUP (year, genre)
+    Rollup(List<GroupingElement>),
+    // CUBE (year, genre)
+    Cube(List<GroupingElement>),
+    // GROUPING SETS ((year), (genre), ())
+    GroupingSets(List<GroupingElement>),
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct HavingClause(pub Expression);
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HavingClause(pub List<Expression>);
 
 impl Clause for GroupByClause {
     const KEYWORD: &'static Keyword = &Keyword::Group;
 }
 
+// Conditions are aligned on the river like the ones of `WhereClause`.
 impl Format for HavingClause {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        f.left_side(self.keyword()).ws().append_format(&self.0)
+        f.append_left_right(self.keyword(), &self.0)
     }
 }
 
 impl Format for GroupByClause {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        f.left_side(self.keyword())
-            .ws()
-            .append(&Keyword::By)
-            .ws()
-            .append_format(&self.0);
-        match &self.1 {
+        f.left_side(self.keyword()).ws().append(&Keyword::By);
+        if let Some(q) = &self.0 {
+            f.ws().append_format(q);
+        }
+        if !self.1 .0.is_empty() {
+            f.ws().append_format(&self.1);
+        }
+        match &self.2 {
             Some(i) => f.new_line().append_format(i),
             None => f,
         }
@@ -55,25 +89,64 @@ impl Clause for HavingClause {
     const KEYWORD: &'static Keyword = &Keyword::Having;
 }
 
+fn grouping_list(input: &str) -> IResult<&str, List<GroupingElement>> {
+    map(
+        delimited(
+            parse_left_parenthesis,
+            separated_list0(parse_comma, ws(parse_grouping_element)),
+            parse_right_parenthesis,
+        ),
+        List,
+    )(input)
+}
+
 pub fn parse_grouping_element(input: &str) -> IResult<&str, GroupingElement> {
-    map(parse_column_ref, GroupingElement::ColumnRef)(input)
+    alt((
+        map(
+            preceded(parse_keyword(Keyword::Rollup), grouping_list),
+            GroupingElement::Rollup,
+        ),
+        map(
+            preceded(parse_keyword(Keyword::Cube), grouping_list),
+            GroupingElement::Cube,
+        ),
+        map(
+            preceded(
+                pair(
+                    parse_keyword(Keyword::Grouping),
+                    parse_keyword(Keyword::Sets),
+                ),
+                grouping_list,
+            ),
+            GroupingElement::GroupingSets,
+        ),
+        map(grouping_list, GroupingElement::Set),
+        map(parse_key, |t| match (ordinal(&t), t) {
+            (Some(i), _) => GroupingElement::Ordinal(i),
+            (None, Term::ColumnRef(c)) => GroupingElement::ColumnRef(c),
+            (None, t) => GroupingElement::Term(t),
+        }),
+    ))(input)
 }
 
+// `GROUP BY ALL` without grouping elements groups by the columns that are not aggregated.
 pub fn parse_group_by_clause(input: &str) -> IResult<&str, GroupByClause> {
     let (input, _) = GroupByClause::parse_keyword(input)?;
-    map(
-        tuple((
-            parse_keyword(Keyword::By),
-            separated_list1(parse_comma, ws(parse_grouping_element)),
-            opt(parse_having_clause),
-        )),
-        |(_, e, h)| GroupByClause(List(e), h),
-    )(input)
+    let (input, _) = parse_keyword(Keyword::By)(input)?;
+    let (input, quantifier) = opt(parse_set_quantifier)(input)?;
+    let (input, elements) = match quantifier {
+        Some(SetQuantifier::All) => {
+            separated_list0(parse_comma, ws(parse_grouping_element))(input)?
+        }
+        _ => separated_list1(parse_comma, ws(parse_grouping_element))(input)?,
+    };
+    let (input, having) = opt(parse_having_clause)(input)?;
+    Ok((input, GroupByClause(quantifier, List(elements), having)))
 }
 
 pub fn parse_having_clause(input: &str) -> IResult<&str, HavingClause> {
     map(
-        tuple((parse_keyword(Keyword::Having), ws(parse_expression))),
+        tuple((parse_keyword(Keyword::Having), parse_expressions)),
         |(_, e)| HavingClause(e),
     )(input)
 }
@@ -82,6 +155,26 @@ impl Format for GroupingElement {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         match self {
             Self::ColumnRef(c) => f.append_format(c),
+            Self::Ordinal(i) => f.append(i),
+            Self::Term(t) => f.append_format(t),
+            Self::Set(l) => f.append_str("(").append_format(l).append_str(")"),
+            Self::Rollup(l) => f
+                .append(&Keyword::Rollup)
+                .append_str(" (")
+                .append_format(l)
+                .append_str(")"),
+            Self::Cube(l) => f
+                .append(&Keyword::Cube)
+                .append_str(" (")
+                .append_format(l)
+                .append_str(")"),
+            Self::GroupingSets(l) => f
+                .append(&Keyword::Grouping)
+                .ws()
+                .append(&Keyword::Sets)
+                .append_str(" (")
+                .append_format(l)
+                .append_str(")"),
         }
     }
 }
@@ -1,7 +1,10 @@
 use crate::expression::{Compare, RightOperand};
 use crate::formatter::Format;
 use crate::numeric::Numeric;
+use crate::query::parse_statements;
+use crate::select::clause::SetQuantifier;
 use crate::select::group::{GroupingElement, HavingClause};
+use crate::select::parse_select_statement;
 use crate::term::value::Value;
 use crate::{
     expression::{Condition, Expression, Operand},
@@ -19,6 +22,7 @@ fn test_group_by() {
         Ok((
             "",
             GroupByClause(
+                None,
                 List(vec!(
                     GroupingElement::ColumnRef(ColumnRef::Name(Name::Name("year".to_string()))),
                     GroupingElement::ColumnRef(ColumnRef::Name(Name::Name("title".to_string())))
@@ -37,11 +41,12 @@ fn test_group_by_having() {
         Ok((
             "",
             GroupByClause(
+                None,
                 List(vec!(
                     GroupingElement::ColumnRef(ColumnRef::Name(Name::Name("year".to_string()))),
                     GroupingElement::ColumnRef(ColumnRef::Name(Name::Name("title".to_string())))
                 )),
-                Some(HavingClause(Expression::Condition(
+                Some(HavingClause(List(vec!(Expression::Condition(
                     Condition::BinaryExpression(
                         Operand::Term(Term::ColumnRef(ColumnRef::Name(Name::Name(
                             "year".to_string()
@@ -51,7 +56,7 @@ fn test_group_by_having() {
                             Operand::Term(Term::Value(Value::Num(Numeric::Int(2001))))
                         )
                     )
-                )))
+                )))))
             )
         ))
     )
@@ -65,3 +70,70 @@ fn test_format_group_by() {
     let (_, t) = parse_group_by_clause("group by age, lastname").unwrap();
     assert_eq!(t.output(), "GROUP BY age, lastname")
 }
+
+#[test]
+fn test_group_by_grouping_sets() {
+    let input = "GROUP BY DISTINCT ROLLUP (year, (genre, studio)), GROUPING SETS ((year), ()), 2";
+    let column = |n: &str| GroupingElement::ColumnRef(ColumnRef::Name(Name::Name(n.to_string())));
+    assert_eq!(
+        parse_group_by_clause(input),
+        Ok((
+            "",
+            GroupByClause(
+                Some(SetQuantifier::Distinct),
+                List(vec!(
+                    GroupingElement::Rollup(List(vec!(
+                        column("year"),
+                        GroupingElement::Set(List(vec!(column("genre"), column("studio"))))
+                    ))),
+                    GroupingElement::GroupingSets(List(vec!(
+                        GroupingElement::Set(List(vec!(column("year")))),
+                        GroupingElement::Set(List(vec!()))
+                    ))),
+                    GroupingElement::Ordinal(2)
+                )),
+                None
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_group_by_extensions() {
+    let (_, t) = parse_group_by_clause("group by cube(a,b), lower(c)").unwrap();
+    assert_eq!(t.output(), "GROUP BY CUBE (a, b), lower(c)");
+
+    let (_, t) = parse_group_by_clause("group by all").unwrap();
+    assert_eq!(t.output(), "GROUP BY ALL");
+    assert!(parse_group_by_clause("group by distinct").is_err());
+}
+
+#[test]
+fn test_format_having_conditions() {
+    let (_, t) = parse_select_statement(
+        "select age from users group by age HAVING count(*) > 1 and max(age) < 99 or age = 0",
+    )
+    .unwrap();
+    assert_eq!(
+        t.output(),
+        "SELECT age\n  FROM users\n GROUP BY age\nHAVING COUNT(*) > 1\n   AND max(age) < 99\n    OR age = 0"
+    );
+}
+
+#[test]
+fn test_format_having_aggregate_operands() {
+    let (_, t) = parse_select_statement(
+        "select country from customers group by country having count(customer_id) > 5 and sum(amount) >= max(price)",
+    )
+    .unwrap();
+    assert_eq!(
+        t.output(),
+        "SELECT country\n  FROM customers\n GROUP BY country\nHAVING count(customer_id) > 5\n   AND sum(amount) >= max(price)"
+    );
+}
+
+#[test]
+fn test_group_by_alias() {
+    assert!(parse_group_by_clause("GROUP BY year AS y").is_err());
+    assert!(parse_statements("SELECT year FROM movies GROUP BY genre, year AS y;").is_err());
+}
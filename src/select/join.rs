@@ -28,24 +28,28 @@ use nom::IResult;
 use std::fmt;
 use std::ops::Deref;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JoinClause(
     pub JoinType,
     pub Box<TableExpression>,
     pub Option<JoinSpecification>,
 );
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OuterJoinType {
     Left,
     Right,
     Full,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OuterJoin(pub OuterJoinType, pub bool);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JoinType {
     Default,
     Inner,
@@ -85,7 +89,8 @@ impl fmt::Display for OuterJoin {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JoinSpecification {
     On(List<Expression>),
     Using(List<Expression>),
@@ -113,10 +118,11 @@ impl Format for JoinSpecification {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         match &self {
             Self::On(e) => {
+                let pad = f.pad;
                 f.right_side(&Keyword::On).space();
-                f.set_pad(f.pad + 7);
+                f.set_pad(pad + 7);
                 f.append_format(e);
-                f.set_pad(f.pad - 7)
+                f.set_pad(pad)
             }
             Self::Using(e) => f.right_side(&Keyword::Using).space().append_format(e),
         }
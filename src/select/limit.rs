@@ -1,43 +1,345 @@
+use crate::character::parse_comma;
+use crate::character::parse_left_parenthesis;
+use crate::character::parse_right_parenthesis;
 use crate::clause::Clause;
-
+use crate::formatter::Dialect;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::keyword::is_keyword;
+use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
-use crate::term::bind_parameter::parse_bind_parameter;
+use crate::numeric::Numeric;
 use crate::term::bind_parameter::BindParameter;
+use crate::term::parse_term;
+use crate::term::value::Value;
+use crate::term::Term;
+use crate::ws::ws;
 use nom::branch::alt;
-use nom::character::complete::u16;
 use nom::combinator::map;
+use nom::combinator::not;
+use nom::combinator::opt;
+use nom::combinator::peek;
+use nom::combinator::value;
+use nom::sequence::delimited;
 use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::terminated;
+use nom::sequence::tuple;
 use nom::IResult;
-use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+// LIMIT 10 OFFSET 20
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LimitClause {
-    Number(u16),
-    BindParameter(BindParameter),
+    // LIMIT 10 OFFSET 20, OFFSET 20
+    Limit(Option<Term>, Option<Offset>),
+    // OFFSET 20 LIMIT 10
+    OffsetLimit(Offset, Term),
+    // LIMIT 20, 10
+    OffsetCount(Term, Term),
+    // OFFSET 20 ROWS FETCH FIRST 10 ROWS ONLY
+    Fetch(Option<Offset>, Fetch),
 }
 
-pub fn parse_limit_clause(input: &str) -> IResult<&str, LimitClause> {
+// OFFSET 20 ROWS
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Offset {
+    pub count: Term,
+    pub rows: bool,
+}
+
+// FETCH NEXT 10 ROWS WITH TIES, without count a single row is fetched.
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fetch {
+    pub next: bool,
+    pub count: Option<Term>,
+    pub with_ties: bool,
+}
+
+// SELECT TOP 10 PERCENT WITH TIES
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Top {
+    pub count: Term,
+    pub percent: bool,
+    pub with_ties: bool,
+}
+
+impl Clause for LimitClause {
+    const KEYWORD: &'static Keyword = &Keyword::Limit;
+}
+
+fn count(input: &str) -> IResult<&str, Term> {
+    preceded(peek(not(is_keyword)), ws(parse_term))(input)
+}
+
+fn parse_limit(input: &str) -> IResult<&str, Term> {
+    preceded(LimitClause::parse_keyword, count)(input)
+}
+
+fn parse_offset(input: &str) -> IResult<&str, Offset> {
     map(
-        pair(
-            LimitClause::parse_keyword,
+        tuple((parse_keyword(Keyword::Offset), count, opt(parse_rows))),
+        |(_, count, rows)| Offset {
+            count,
+            rows: rows.is_some(),
+        },
+    )(input)
+}
+
+fn parse_rows(input: &str) -> IResult<&str, ()> {
+    alt((parse_keyword(Keyword::Rows), parse_keyword(Keyword::Row)))(input)
+}
+
+fn parse_fetch(input: &str) -> IResult<&str, Fetch> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Fetch),
+            alt((
+                value(false, parse_keyword(Keyword::First)),
+                value(true, parse_keyword(Keyword::Next)),
+            )),
             alt((
-                map(parse_bind_parameter, LimitClause::BindParameter),
-                map(u16, LimitClause::Number),
+                map(terminated(count, parse_rows), Some),
+                map(parse_rows, |_| None),
             )),
+            alt((
+                value(false, parse_keyword(Keyword::Only)),
+                value(
+                    true,
+                    pair(parse_keyword(Keyword::With), parse_keyword(Keyword::Ties)),
+                ),
+            )),
+        )),
+        |(_, next, count, with_ties)| Fetch {
+            next,
+            count,
+            with_ties,
+        },
+    )(input)
+}
+
+pub fn parse_limit_clause(input: &str) -> IResult<&str, LimitClause> {
+    alt((
+        map(
+            tuple((parse_limit, parse_comma, count)),
+            |(offset, _, count)| LimitClause::OffsetCount(offset, count),
         ),
-        |(_, s)| s,
+        map(pair(parse_limit, opt(parse_offset)), |(count, offset)| {
+            LimitClause::Limit(Some(count), offset)
+        }),
+        map(pair(opt(parse_offset), parse_fetch), |(offset, fetch)| {
+            LimitClause::Fetch(offset, fetch)
+        }),
+        map(pair(parse_offset, parse_limit), |(offset, count)| {
+            LimitClause::OffsetLimit(offset, count)
+        }),
+        map(parse_offset, |offset| {
+            LimitClause::Limit(None, Some(offset))
+        }),
+    ))(input)
+}
+
+pub fn parse_top(input: &str) -> IResult<&str, Top> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Top),
+            alt((
+                delimited(parse_left_parenthesis, count, parse_right_parenthesis),
+                count,
+            )),
+            opt(parse_keyword(Keyword::Percent)),
+            opt(pair(
+                parse_keyword(Keyword::With),
+                parse_keyword(Keyword::Ties),
+            )),
+        )),
+        |(_, count, percent, with_ties)| Top {
+            count,
+            percent: percent.is_some(),
+            with_ties: with_ties.is_some(),
+        },
     )(input)
 }
 
-impl fmt::Display for LimitClause {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+// TOP, LIMIT and FETCH FIRST written with the syntax of the dialect, `None` when the
+// pagination is already written that way or the dialect cannot express it. Set operations
+// have no TOP, their rows are limited after the last operand. SQL Server only accepts
+// OFFSET after an ORDER BY.
+pub fn paginate(
+    top: Option<&Top>,
+    limit: Option<&LimitClause>,
+    dialect: Dialect,
+    top_allowed: bool,
+    ordered: bool,
+) -> Option<(Option<Top>, Option<LimitClause>)> {
+    let (skip, count, with_ties) = match (top, limit) {
+        (Some(top), None) if !top.percent => (None, Some(top.count.clone()), top.with_ties),
+        (None, Some(LimitClause::Limit(count, offset))) => {
+            (offset.clone().map(|o| o.count), count.clone(), false)
+        }
+        (None, Some(LimitClause::OffsetCount(offset, count))) => {
+            (Some(offset.clone()), Some(count.clone()), false)
+        }
+        (None, Some(LimitClause::OffsetLimit(offset, count))) => {
+            (Some(offset.count.clone()), Some(count.clone()), false)
+        }
+        (None, Some(LimitClause::Fetch(offset, fetch))) => (
+            offset.clone().map(|o| o.count),
+            Some(fetch.count.clone().unwrap_or(Term::from(1))),
+            fetch.with_ties,
+        ),
+        _ => return None,
+    };
+    // Moving `?` or `%s` would bind the values to the wrong parameters.
+    if skip.iter().chain(&count).any(is_positional) {
+        return None;
+    }
+    let offset = |rows| skip.clone().map(|count| Offset { count, rows });
+    let fetch = |next| Fetch {
+        next,
+        count: count.clone(),
+        with_ties,
+    };
+    let pagination = match (dialect, &count) {
+        (Dialect::Generic, _) => return None,
+        (Dialect::MySql, _) if with_ties => return None,
+        (Dialect::Postgres | Dialect::MySql, _) if !with_ties => {
+            (None, Some(LimitClause::Limit(count.clone(), offset(false))))
+        }
+        (Dialect::SqlServer, Some(count)) if skip.is_none() && top_allowed => {
+            let top = Top {
+                count: count.clone(),
+                percent: false,
+                with_ties,
+            };
+            (Some(top), None)
+        }
+        (Dialect::SqlServer, _) if with_ties || !ordered => return None,
+        (_, None) => (None, Some(LimitClause::Limit(None, offset(true)))),
+        (Dialect::SqlServer, _) => {
+            let skip = offset(true).unwrap_or(Offset {
+                count: Term::from(0),
+                rows: true,
+            });
+            (None, Some(LimitClause::Fetch(Some(skip), fetch(true))))
+        }
+        _ => (None, Some(LimitClause::Fetch(offset(true), fetch(false)))),
+    };
+    match (pagination.0.as_ref(), pagination.1.as_ref()) == (top, limit) {
+        true => None,
+        false => Some(pagination),
+    }
+}
+
+fn is_positional(term: &Term) -> bool {
+    matches!(
+        term,
+        Term::BindParameter(BindParameter::Placeholder | BindParameter::Format)
+    )
+}
+
+impl Format for Offset {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Offset)
+            .space()
+            .append_format(&self.count);
+        match self.rows {
+            true => f.space().append(&Keyword::Rows),
+            false => f,
+        }
+    }
+}
+
+impl Format for Fetch {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Fetch).space();
+        match self.next {
+            true => f.append(&Keyword::Next),
+            false => f.append(&Keyword::First),
+        };
+        match &self.count {
+            Some(c @ Term::Value(Value::Num(Numeric::Int(1)))) => {
+                f.space().append_format(c).space().append(&Keyword::Row)
+            }
+            Some(c) => f.space().append_format(c).space().append(&Keyword::Rows),
+            None => f.space().append(&Keyword::Row),
+        };
+        match self.with_ties {
+            true => f
+                .space()
+                .append(&Keyword::With)
+                .space()
+                .append(&Keyword::Ties),
+            false => f.space().append(&Keyword::Only),
+        }
+    }
+}
+
+// MySQL has no OFFSET without LIMIT, the largest row count stands for all rows.
+const MYSQL_ALL_ROWS: &str = "18446744073709551615";
+
+impl Format for LimitClause {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         match self {
-            Self::Number(n) => write!(f, "{} {n}", Self::KEYWORD),
-            Self::BindParameter(b) => write!(f, "{} {b}", Self::KEYWORD),
+            Self::Limit(Some(count), offset) => {
+                f.append(self.keyword()).space().append_format(count);
+                match offset {
+                    Some(o) => f.new_line().append_format(o),
+                    None => f,
+                }
+            }
+            Self::Limit(None, Some(offset)) if f.options.dialect == Dialect::MySql => f
+                .append(self.keyword())
+                .space()
+                .append_str(MYSQL_ALL_ROWS)
+                .new_line()
+                .append_format(offset),
+            Self::Limit(None, Some(offset)) => f.append_format(offset),
+            Self::Limit(None, None) => f,
+            Self::OffsetCount(offset, count) => f
+                .append(self.keyword())
+                .space()
+                .append_format(offset)
+                .append_str(", ")
+                .append_format(count),
+            Self::OffsetLimit(offset, count) => f
+                .append_format(offset)
+                .new_line()
+                .append(self.keyword())
+                .space()
+                .append_format(count),
+            Self::Fetch(offset, fetch) => {
+                match offset {
+                    Some(o) => f.append_format(o).new_line(),
+                    None => f,
+                };
+                f.append_format(fetch)
+            }
         }
     }
 }
 
-impl Clause for LimitClause {
-    const KEYWORD: &'static Keyword = &Keyword::Limit;
+// Expressions other than numbers need parentheses in SQL Server.
+impl Format for Top {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Top).space();
+        match &self.count {
+            Term::Value(Value::Num(_)) => f.append_format(&self.count),
+            c => f.append_str("(").append_format(c).append_str(")"),
+        };
+        if self.percent {
+            f.space().append(&Keyword::Percent);
+        }
+        match self.with_ties {
+            true => f
+                .space()
+                .append(&Keyword::With)
+                .space()
+                .append(&Keyword::Ties),
+            false => f,
+        }
+    }
 }
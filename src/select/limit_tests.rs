@@ -1,12 +1,19 @@
 use crate::{
     formatter::Format,
-    select::limit::{parse_limit_clause, LimitClause},
+    formatter::{Dialect, FormatOptions},
+    query::parse_statements,
+    select::limit::{parse_limit_clause, parse_top, Fetch, LimitClause, Offset, Top},
+    term::bind_parameter::BindParameter,
+    term::Term,
 };
 
 #[test]
 fn test_parse_limit() {
     let input = "LIMIT 5";
-    assert_eq!(parse_limit_clause(input), Ok(("", LimitClause::Number(5))))
+    assert_eq!(
+        parse_limit_clause(input),
+        Ok(("", LimitClause::Limit(Some(Term::from(5)), None)))
+    )
 }
 
 #[test]
@@ -17,3 +24,232 @@ fn test_format_limit() {
     let input = "LIMIT    :1";
     assert_eq!(parse_limit_clause(input).unwrap().1.output(), "LIMIT :1")
 }
+
+#[test]
+fn test_parse_offset_and_fetch() {
+    assert_eq!(
+        parse_limit_clause("OFFSET $1 ROWS FETCH NEXT 10 ROWS WITH TIES"),
+        Ok((
+            "",
+            LimitClause::Fetch(
+                Some(Offset {
+                    count: Term::BindParameter(BindParameter::Dollar(1)),
+                    rows: true
+                }),
+                Fetch {
+                    next: true,
+                    count: Some(Term::from(10)),
+                    with_ties: true
+                }
+            )
+        ))
+    );
+    assert_eq!(
+        parse_limit_clause("LIMIT 20, 10"),
+        Ok(("", LimitClause::OffsetCount(Term::from(20), Term::from(10))))
+    );
+    assert_eq!(
+        parse_limit_clause("OFFSET 20 LIMIT 10"),
+        Ok((
+            "",
+            LimitClause::OffsetLimit(
+                Offset {
+                    count: Term::from(20),
+                    rows: false
+                },
+                Term::from(10)
+            )
+        ))
+    );
+}
+
+#[test]
+fn test_format_pagination() {
+    let clauses = [
+        ("limit 10 offset 20", "LIMIT 10\nOFFSET 20"),
+        ("limit 20,10", "LIMIT 20, 10"),
+        ("offset 5 row", "OFFSET 5 ROWS"),
+        ("fetch first row only", "FETCH FIRST ROW ONLY"),
+        (
+            "offset 20 rows fetch next 10 rows only",
+            "OFFSET 20 ROWS\nFETCH NEXT 10 ROWS ONLY",
+        ),
+        ("limit %(page_size)s", "LIMIT %(page_size)s"),
+        ("offset ? limit ?", "OFFSET ?\nLIMIT ?"),
+    ];
+    for (input, expected) in clauses {
+        assert_eq!(parse_limit_clause(input).unwrap().1.output(), expected);
+    }
+}
+
+#[test]
+fn test_offset_after_table() {
+    assert_eq!(
+        parse_statements("SELECT title FROM movies OFFSET 5 FETCH FIRST ROW ONLY;")
+            .unwrap()
+            .1
+            .output(),
+        "SELECT title\n  FROM movies\nOFFSET 5\nFETCH FIRST ROW ONLY;"
+    );
+}
+
+#[test]
+fn test_parse_top() {
+    assert_eq!(
+        parse_top("TOP (@n) PERCENT WITH TIES"),
+        Ok((
+            "",
+            Top {
+                count: Term::BindParameter(BindParameter::AtNamed("n".to_string())),
+                percent: true,
+                with_ties: true
+            }
+        ))
+    );
+    assert_eq!(
+        parse_statements("SELECT TOP 10 title FROM movies;")
+            .unwrap()
+            .1
+            .output(),
+        "SELECT TOP 10 title\n  FROM movies;"
+    );
+    assert_eq!(
+        parse_statements("SELECT top FROM movies;")
+            .unwrap()
+            .1
+            .output(),
+        "SELECT top\n  FROM movies;"
+    );
+}
+
+fn convert(input: &str, dialect: Dialect) -> String {
+    let (_, statements) = parse_statements(input).unwrap();
+    statements.output_with(FormatOptions {
+        dialect,
+        ..FormatOptions::default()
+    })
+}
+
+#[test]
+fn test_convert_pagination() {
+    let input = "SELECT title FROM movies ORDER BY title LIMIT 10 OFFSET 20;";
+    assert_eq!(
+        convert(input, Dialect::Ansi),
+        "SELECT title\n  FROM movies\n ORDER BY title\nOFFSET 20 ROWS\nFETCH FIRST 10 ROWS ONLY;"
+    );
+    assert_eq!(
+        convert(input, Dialect::SqlServer),
+        "SELECT title\n  FROM movies\n ORDER BY title\nOFFSET 20 ROWS\nFETCH NEXT 10 ROWS ONLY;"
+    );
+    assert_eq!(
+        convert(input, Dialect::Postgres),
+        convert(input, Dialect::Generic)
+    );
+
+    let input = "SELECT title FROM movies FETCH FIRST 5 ROWS ONLY;";
+    assert_eq!(
+        convert(input, Dialect::MySql),
+        "SELECT title\n  FROM movies\nLIMIT 5;"
+    );
+    assert_eq!(
+        convert(input, Dialect::SqlServer),
+        "SELECT TOP 5 title\n  FROM movies;"
+    );
+    assert_eq!(
+        convert("SELECT title FROM movies LIMIT 20, 10;", Dialect::Postgres),
+        "SELECT title\n  FROM movies\nLIMIT 10\nOFFSET 20;"
+    );
+}
+
+#[test]
+fn test_convert_pagination_unsupported() {
+    let input = "SELECT TOP 10 PERCENT title FROM movies;";
+    assert_eq!(
+        convert(input, Dialect::Postgres),
+        "SELECT TOP 10 PERCENT title\n  FROM movies;"
+    );
+    let input = "SELECT TOP 3 WITH TIES title FROM movies ORDER BY year;";
+    assert_eq!(
+        convert(input, Dialect::MySql),
+        "SELECT TOP 3 WITH TIES title\n  FROM movies\n ORDER BY year;"
+    );
+    assert_eq!(
+        convert(input, Dialect::Postgres),
+        "SELECT title\n  FROM movies\n ORDER BY year\nFETCH FIRST 3 ROWS WITH TIES;"
+    );
+}
+
+#[test]
+fn test_convert_pagination_positional_parameters() {
+    let input = "SELECT title FROM movies OFFSET ? ROWS FETCH FIRST ? ROWS ONLY;";
+    assert_eq!(
+        convert(input, Dialect::Postgres),
+        "SELECT title\n  FROM movies\nOFFSET ? ROWS\nFETCH FIRST ? ROWS ONLY;"
+    );
+    let input = "SELECT title FROM movies LIMIT %s OFFSET %s;";
+    assert_eq!(
+        convert(input, Dialect::Ansi),
+        "SELECT title\n  FROM movies\nLIMIT %s\nOFFSET %s;"
+    );
+    let input = "SELECT title FROM movies OFFSET 20 LIMIT 10;";
+    assert_eq!(
+        convert(input, Dialect::Postgres),
+        "SELECT title\n  FROM movies\nLIMIT 10\nOFFSET 20;"
+    );
+}
+
+#[test]
+fn test_convert_offset_mysql() {
+    let input = "SELECT title FROM movies OFFSET 5 ROWS;";
+    assert_eq!(
+        convert(input, Dialect::MySql),
+        "SELECT title\n  FROM movies\nLIMIT 18446744073709551615\nOFFSET 5;"
+    );
+}
+
+#[test]
+fn test_convert_combined_pagination() {
+    let input = "SELECT title FROM movies UNION SELECT title FROM series ORDER BY title LIMIT 10 OFFSET 20;";
+    assert_eq!(
+        convert(input, Dialect::SqlServer),
+        "SELECT title\n  FROM movies\n\n UNION\n\nSELECT title\n  FROM series\n ORDER BY title\nOFFSET 20 ROWS\nFETCH NEXT 10 ROWS ONLY;"
+    );
+    let input = "SELECT title FROM movies UNION SELECT title FROM series ORDER BY title LIMIT 10;";
+    assert_eq!(
+        convert(input, Dialect::SqlServer),
+        "SELECT title\n  FROM movies\n\n UNION\n\nSELECT title\n  FROM series\n ORDER BY title\nOFFSET 0 ROWS\nFETCH NEXT 10 ROWS ONLY;"
+    );
+    let input = "SELECT title FROM movies UNION SELECT title FROM series FETCH FIRST 5 ROWS ONLY;";
+    assert_eq!(
+        convert(input, Dialect::Postgres),
+        "SELECT title\n  FROM movies\n\n UNION\n\nSELECT title\n  FROM series\nLIMIT 5;"
+    );
+}
+
+#[test]
+fn test_convert_offset_sqlserver_without_order() {
+    let input = "SELECT title FROM movies LIMIT 10 OFFSET 5;";
+    assert_eq!(
+        convert(input, Dialect::SqlServer),
+        "SELECT title\n  FROM movies\nLIMIT 10\nOFFSET 5;"
+    );
+    let input = "SELECT title FROM movies UNION SELECT title FROM series LIMIT 10;";
+    assert_eq!(
+        convert(input, Dialect::SqlServer),
+        "SELECT title\n  FROM movies\n\n UNION\n\nSELECT title\n  FROM series\nLIMIT 10;"
+    );
+}
+
+#[test]
+fn test_convert_top_in_set_operation() {
+    let input = "SELECT TOP 3 a FROM t UNION SELECT b FROM u;";
+    assert_eq!(
+        convert(input, Dialect::Postgres),
+        "SELECT TOP 3 a\n  FROM t\n\n UNION\n\nSELECT b\n  FROM u;"
+    );
+    let input = "(SELECT TOP 3 a FROM t) UNION SELECT b FROM u;";
+    assert_eq!(
+        convert(input, Dialect::Postgres),
+        "(SELECT a\n   FROM t\n LIMIT 3)\n\n UNION\n\nSELECT b\n  FROM u;"
+    );
+}
@@ -5,37 +5,51 @@ use crate::formatter::Formatter;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
 use crate::list::List;
-use crate::term::column::parse_column_ref;
+use crate::numeric::Numeric;
 use crate::term::column::ColumnRef;
+use crate::term::parse_term;
+use crate::term::value::Value;
+use crate::term::Term;
 use crate::ws::ws;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom::combinator::opt;
 use nom::combinator::value;
+use nom::combinator::verify;
 use nom::multi::separated_list1;
 use nom::sequence::tuple;
 use nom::IResult;
 use nom::Parser;
+use std::convert::TryFrom;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderByClause(pub List<Order>);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Order(pub SortKey, pub Option<OrderSort>, pub Option<NullsSort>);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OrderSort {
     Asc,
     Desc,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SortKey {
     ColumnRef(ColumnRef),
+    // ORDER BY 2
+    Ordinal(u16),
+    // ORDER BY lower(name), ORDER BY CASE ... END
+    Term(Term),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NullsSort {
     First,
     Last,
@@ -74,8 +88,25 @@ pub fn parse_order_by_clause(input: &str) -> IResult<&str, OrderByClause> {
     )(input)
 }
 
+// Keys of ORDER BY and GROUP BY are terms without alias.
+pub fn parse_key(input: &str) -> IResult<&str, Term> {
+    verify(parse_term, |t| !matches!(t, Term::AliasedTerm(..)))(input)
+}
+
 pub fn parse_sort_key(input: &str) -> IResult<&str, SortKey> {
-    map(parse_column_ref, SortKey::ColumnRef)(input)
+    map(parse_key, |t| match (ordinal(&t), t) {
+        (Some(i), _) => SortKey::Ordinal(i),
+        (None, Term::ColumnRef(c)) => SortKey::ColumnRef(c),
+        (None, t) => SortKey::Term(t),
+    })(input)
+}
+
+// `ORDER BY 2` and `GROUP BY 2` refer to the second selected column.
+pub fn ordinal(term: &Term) -> Option<u16> {
+    match term {
+        Term::Value(Value::Num(Numeric::Int(i))) => u16::try_from(*i).ok(),
+        _ => None,
+    }
 }
 
 impl fmt::Display for OrderSort {
@@ -96,10 +127,21 @@ impl fmt::Display for OrderSort {
     }
 }*/
 
+impl fmt::Display for NullsSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::First => write!(f, "{} {}", Keyword::Nulls, Keyword::First),
+            Self::Last => write!(f, "{} {}", Keyword::Nulls, Keyword::Last),
+        }
+    }
+}
+
 impl Format for SortKey {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         match self {
             Self::ColumnRef(c) => f.append_format(c),
+            Self::Ordinal(i) => f.append(i),
+            Self::Term(t) => f.append_format(t),
         }
     }
 }
@@ -107,9 +149,12 @@ impl Format for SortKey {
 impl Format for Order {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         f.append_format(&self.0);
-        match &self.1 {
+        if let Some(sort) = &self.1 {
+            f.ws().append_format(sort);
+        }
+        match &self.2 {
             None => f,
-            Some(sort) => f.ws().append_format(sort),
+            Some(nulls) => f.ws().append_format(nulls),
         }
     }
 }
@@ -128,6 +173,17 @@ impl Format for OrderByClause {
     }
 }
 
+impl OrderByClause {
+    // Inside aggregates and window specifications, without river alignment.
+    pub fn format_inline<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(self.keyword())
+            .ws()
+            .append(&Keyword::By)
+            .ws()
+            .append_format(&self.0)
+    }
+}
+
 impl Format for List<Order> {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         for (pos, i) in self.0.iter().enumerate() {
@@ -1,9 +1,12 @@
 use crate::{
     formatter::Format,
+    function::{AggregateFunction, Function},
     identifier::Name,
     list::List,
+    query::parse_statements,
     select::order::{parse_order_by_clause, NullsSort, Order, OrderByClause, OrderSort, SortKey},
     term::column::ColumnRef,
+    term::Term,
 };
 
 #[test]
@@ -114,3 +117,51 @@ fn test_format_order_by() {
         "ORDER BY age, year"
     )
 }
+
+#[test]
+fn test_order_by_ordinal_and_expressions() {
+    let input = "ORDER BY 2, lower(name)";
+    assert_eq!(
+        parse_order_by_clause(input),
+        Ok((
+            "",
+            OrderByClause(List(vec!(
+                Order(SortKey::Ordinal(2), None, None),
+                Order(
+                    SortKey::Term(Term::Function(AggregateFunction::Function(Function(
+                        "lower".to_string(),
+                        List(vec!(Term::ColumnRef(ColumnRef::Name(Name::Name(
+                            "name".to_string()
+                        )))))
+                    )))),
+                    None,
+                    None
+                )
+            )))
+        ))
+    )
+}
+
+#[test]
+fn test_format_order_by_modifiers() {
+    assert_eq!(
+        parse_order_by_clause("ORDER BY username DESC NULLS LAST, 1 nulls first")
+            .unwrap()
+            .1
+            .output(),
+        "ORDER BY username DESC NULLS LAST, 1 NULLS FIRST"
+    );
+    assert_eq!(
+        parse_order_by_clause("ORDER BY coalesce(rating, 0) asc")
+            .unwrap()
+            .1
+            .output(),
+        "ORDER BY coalesce(rating, 0) ASC"
+    )
+}
+
+#[test]
+fn test_order_by_alias() {
+    assert!(parse_order_by_clause("ORDER BY title AS t").is_err());
+    assert!(parse_statements("SELECT title FROM movies ORDER BY year, title AS t;").is_err());
+}
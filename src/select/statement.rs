@@ -2,11 +2,13 @@ use super::clause::SelectClause;
 use super::parse_select_clause;
 use crate::expression::parse_where_clause;
 use crate::expression::WhereClause;
+use crate::formatter::Dialect;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
 use crate::keyword::Keyword;
 use crate::select::group::parse_group_by_clause;
 use crate::select::group::GroupByClause;
+use crate::select::limit::paginate;
 use crate::select::limit::parse_limit_clause;
 use crate::select::limit::LimitClause;
 use crate::select::order::parse_order_by_clause;
@@ -17,7 +19,8 @@ use crate::statement::Statement;
 use nom::combinator::opt;
 use nom::IResult;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectStatement {
     pub select: SelectClause,
     pub from: Option<FromClause>,
@@ -28,12 +31,25 @@ pub struct SelectStatement {
 }
 
 pub fn parse_select_statement(input: &str) -> IResult<&str, SelectStatement> {
+    let (input, select) = parse_simple_select(input)?;
+    let (input, order_by) = opt(parse_order_by_clause)(input)?;
+    let (input, limit) = opt(parse_limit_clause)(input)?;
+    Ok((
+        input,
+        SelectStatement {
+            order_by,
+            limit,
+            ..select
+        },
+    ))
+}
+
+// Without ORDER BY and LIMIT, which apply to the whole result of a set operation.
+pub fn parse_simple_select(input: &str) -> IResult<&str, SelectStatement> {
     let (input, select) = parse_select_clause(input)?;
     let (input, from) = opt(parse_from_clause)(input)?;
     let (input, where_clause) = opt(parse_where_clause)(input)?;
     let (input, group_by) = opt(parse_group_by_clause)(input)?;
-    let (input, order_by) = opt(parse_order_by_clause)(input)?;
-    let (input, limit) = opt(parse_limit_clause)(input)?;
     Ok((
         input,
         SelectStatement {
@@ -41,8 +57,8 @@ pub fn parse_select_statement(input: &str) -> IResult<&str, SelectStatement> {
             from,
             r#where: where_clause,
             group_by,
-            order_by,
-            limit,
+            order_by: None,
+            limit: None,
         },
     ))
 }
@@ -53,8 +69,25 @@ impl Statement for SelectStatement {
     }
 }
 
-impl Format for SelectStatement {
-    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+impl SelectStatement {
+    // TOP, LIMIT and FETCH FIRST written with the syntax of the dialect, `None` when the
+    // statement is already written that way or the dialect cannot express its pagination.
+    pub fn paginate(&self, dialect: Dialect) -> Option<SelectStatement> {
+        let (top, limit) = paginate(
+            self.select.1.as_ref(),
+            self.limit.as_ref(),
+            dialect,
+            true,
+            self.order_by.is_some(),
+        )?;
+        let mut statement = self.clone();
+        statement.select.1 = top;
+        statement.limit = limit;
+        Some(statement)
+    }
+
+    // Formats the clauses as written, operands of set operations cannot take a LIMIT.
+    pub fn format_clauses<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         f.set_pad(self.ok());
         f.append_format(&self.select);
         match &self.from {
@@ -79,3 +112,12 @@ impl Format for SelectStatement {
         }
     }
 }
+
+impl Format for SelectStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self.paginate(f.options.dialect) {
+            Some(s) => s.format_clauses(f),
+            None => self.format_clauses(f),
+        }
+    }
+}
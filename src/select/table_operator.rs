@@ -1,23 +1,41 @@
+use crate::character::parse_comma;
 use crate::character::parse_left_parenthesis;
 use crate::character::parse_right_parenthesis;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
+use crate::list::List;
+use crate::nesting::nested;
+use crate::select::limit::paginate;
+use crate::select::limit::parse_limit_clause;
+use crate::select::limit::LimitClause;
+use crate::select::order::parse_order_by_clause;
+use crate::select::order::OrderByClause;
 use crate::select::parse_select_statement;
+use crate::select::statement::parse_simple_select;
 use crate::select::SelectStatement;
 use crate::ws::ws;
 use nom::branch::alt;
 use nom::combinator::map;
 use nom::combinator::not;
+use nom::combinator::opt;
+use nom::combinator::peek;
 use nom::combinator::value;
+use nom::combinator::verify;
+use nom::multi::separated_list1;
 use nom::sequence::delimited;
+use nom::sequence::preceded;
+use nom::sequence::terminated;
 use nom::sequence::tuple;
 use nom::IResult;
 
 use std::ops::Deref;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableOperator {
     Union(bool),
     Intersect,
@@ -25,14 +43,33 @@ pub enum TableOperator {
     Except,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QueryTerm {
     Select(Box<SelectStatement>),
     Parenthesis(Box<QueryTerm>),
+    // SELECT 1 UNION ALL CORRESPONDING SELECT 2
+    Combined(
+        Box<QueryTerm>,
+        TableOperator,
+        Option<Corresponding>,
+        Box<QueryTerm>,
+    ),
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct CombinedTables(pub QueryTerm, pub TableOperator, pub QueryTerm);
+// CORRESPONDING BY (id, title)
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Corresponding(pub Option<List<Name>>);
+
+// The ORDER BY and LIMIT clauses apply to the result of the whole set operation.
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CombinedTables {
+    pub body: QueryTerm,
+    pub order_by: Option<OrderByClause>,
+    pub limit: Option<LimitClause>,
+}
 
 impl Format for TableOperator {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
@@ -54,27 +91,64 @@ impl Format for TableOperator {
     }
 }
 
+impl Format for Corresponding {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Corresponding);
+        match &self.0 {
+            Some(names) => f
+                .ws()
+                .append(&Keyword::By)
+                .append_str(" (")
+                .append(names)
+                .append_str(")"),
+            None => f,
+        }
+    }
+}
+
 impl Format for CombinedTables {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        f.append_format(&self.0)
-            .new_line()
-            .new_line()
-            .append_format(&self.1)
-            .new_line()
-            .new_line()
-            .append_format(&self.2)
+        f.append_format(&self.body).set_pad(Keyword::Select.len());
+        match &self.order_by {
+            Some(o) => f.append_clause(o),
+            None => f,
+        };
+        let ordered = self.order_by.is_some();
+        let limit = match paginate(None, self.limit.as_ref(), f.options.dialect, false, ordered) {
+            Some((_, limit)) => limit,
+            None => self.limit.clone(),
+        };
+        match &limit {
+            Some(l) => f.append_clause(l),
+            None => f,
+        }
     }
 }
 
 impl Format for QueryTerm {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         match self {
-            Self::Select(s) => f.append_format(s.deref()),
+            Self::Select(s) => s.format_clauses(f),
+            // The clauses inside keep their river, shifted by the parenthesis.
             Self::Parenthesis(s) => {
-                f.set_pad(f.pad + 1);
-                f.append_str("(").append_format(s.deref()).append_str(")");
-                f.set_pad(f.pad - 1);
-                f
+                f.append_str("(");
+                match s.deref() {
+                    Self::Select(s) => f.append_indented(s.deref()),
+                    s => f.append_indented(s),
+                };
+                f.append_str(")")
+            }
+            Self::Combined(l, operator, corresponding, r) => {
+                // The operator stays on the river of SELECT whatever ends the left operand.
+                f.append_format(l.deref())
+                    .new_line()
+                    .new_line()
+                    .set_pad(Keyword::Select.len())
+                    .append_format(operator);
+                if let Some(c) = corresponding {
+                    f.ws().append_format(c);
+                }
+                f.new_line().new_line().append_format(r.deref())
             }
         }
     }
@@ -82,13 +156,16 @@ impl Format for QueryTerm {
 
 pub fn parse_query_term(input: &str) -> IResult<&str, QueryTerm> {
     alt((
-        map(ws(parse_select_statement), |s| {
-            QueryTerm::Select(Box::new(s))
-        }),
+        map(ws(parse_simple_select), |s| QueryTerm::Select(Box::new(s))),
         map(
             delimited(
                 parse_left_parenthesis,
-                parse_query_term,
+                nested(alt((
+                    terminated(union_term, peek(parse_right_parenthesis)),
+                    map(ws(parse_select_statement), |s| {
+                        QueryTerm::Select(Box::new(s))
+                    }),
+                ))),
                 parse_right_parenthesis,
             ),
             |s| QueryTerm::Parenthesis(Box::new(s)),
@@ -96,17 +173,69 @@ pub fn parse_query_term(input: &str) -> IResult<&str, QueryTerm> {
     ))(input)
 }
 
-pub fn combined_tables(input: &str) -> IResult<&str, CombinedTables> {
+fn corresponding(input: &str) -> IResult<&str, Corresponding> {
     map(
-        tuple((
-            ws(parse_query_term),
-            ws(table_operator),
-            ws(parse_query_term),
-        )),
-        |(l, o, r)| CombinedTables(l, o, r),
+        preceded(
+            parse_keyword(Keyword::Corresponding),
+            opt(preceded(
+                parse_keyword(Keyword::By),
+                delimited(
+                    parse_left_parenthesis,
+                    separated_list1(parse_comma, ws(parse_name)),
+                    parse_right_parenthesis,
+                ),
+            )),
+        ),
+        |names| Corresponding(names.map(List)),
     )(input)
 }
 
+// Operands are combined from left to right, `level` parses the operands binding tighter.
+fn combine<'a>(
+    input: &'a str,
+    operator: fn(&'a str) -> IResult<&'a str, TableOperator>,
+    level: fn(&'a str) -> IResult<&'a str, QueryTerm>,
+) -> IResult<&'a str, QueryTerm> {
+    let (mut input, mut term) = level(input)?;
+    loop {
+        match tuple((ws(operator), opt(corresponding), level))(input) {
+            Ok((rest, (o, c, right))) => {
+                term = QueryTerm::Combined(Box::new(term), o, c, Box::new(right));
+                input = rest;
+            }
+            Err(nom::Err::Error(_)) => return Ok((input, term)),
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// INTERSECT binds tighter than UNION, EXCEPT and MINUS.
+fn intersect_term(input: &str) -> IResult<&str, QueryTerm> {
+    combine(
+        input,
+        |i| value(TableOperator::Intersect, parse_keyword(Keyword::Intersect))(i),
+        parse_query_term,
+    )
+}
+
+fn union_term(input: &str) -> IResult<&str, QueryTerm> {
+    combine(input, table_operator, intersect_term)
+}
+
+pub fn combined_tables(input: &str) -> IResult<&str, CombinedTables> {
+    let (input, body) = verify(union_term, |t| matches!(t, QueryTerm::Combined(..)))(input)?;
+    let (input, order_by) = opt(parse_order_by_clause)(input)?;
+    let (input, limit) = opt(parse_limit_clause)(input)?;
+    Ok((
+        input,
+        CombinedTables {
+            body,
+            order_by,
+            limit,
+        },
+    ))
+}
+
 fn table_operator_all(input: &str) -> IResult<&str, bool> {
     alt((
         map(parse_keyword(Keyword::All), |_| true),
@@ -120,7 +249,6 @@ fn table_operator(input: &str) -> IResult<&str, TableOperator> {
             tuple((parse_keyword(Keyword::Union), table_operator_all)),
             |(_, all)| TableOperator::Union(all),
         ),
-        value(TableOperator::Intersect, parse_keyword(Keyword::Intersect)),
         value(TableOperator::Minus, parse_keyword(Keyword::Minus)),
         value(TableOperator::Except, parse_keyword(Keyword::Except)),
     ))(input)
@@ -1,15 +1,49 @@
 use crate::{
     formatter::Format,
+    identifier::Name,
     list::List,
     numeric::Numeric,
+    query::parse_statements,
     select::{
         clause::{SelectClause, SelectedExpression},
-        table_operator::{combined_tables, CombinedTables, QueryTerm, TableOperator},
+        limit::LimitClause,
+        table_operator::{
+            combined_tables, CombinedTables, Corresponding, QueryTerm, TableOperator,
+        },
         SelectStatement,
     },
     term::{value::Value, Term},
 };
 
+fn select(i: i32) -> QueryTerm {
+    QueryTerm::Select(Box::new(SelectStatement {
+        select: SelectClause(
+            None,
+            None,
+            List(vec![SelectedExpression::Term(Term::Value(Value::Num(
+                Numeric::Int(i),
+            )))]),
+        ),
+        from: None,
+        r#where: None,
+        group_by: None,
+        order_by: None,
+        limit: None,
+    }))
+}
+
+fn combined(left: QueryTerm, operator: TableOperator, right: QueryTerm) -> QueryTerm {
+    QueryTerm::Combined(Box::new(left), operator, None, Box::new(right))
+}
+
+fn body(body: QueryTerm) -> CombinedTables {
+    CombinedTables {
+        body,
+        order_by: None,
+        limit: None,
+    }
+}
+
 #[test]
 fn test_union() {
     let input = "SELECT 1 UNION SELECT 2";
@@ -17,35 +51,7 @@ fn test_union() {
         combined_tables(input),
         Ok((
             "",
-            CombinedTables(
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(1)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                })),
-                TableOperator::Union(false),
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(2)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                }))
-            )
+            body(combined(select(1), TableOperator::Union(false), select(2)))
         ))
     )
 }
@@ -57,35 +63,7 @@ fn test_union_all() {
         combined_tables(input),
         Ok((
             "",
-            CombinedTables(
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(1)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                })),
-                TableOperator::Union(true),
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(2)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                }))
-            )
+            body(combined(select(1), TableOperator::Union(true), select(2)))
         ))
     )
 }
@@ -97,35 +75,7 @@ fn test_intersect() {
         combined_tables(input),
         Ok((
             "",
-            CombinedTables(
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(1)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                })),
-                TableOperator::Intersect,
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(2)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                }))
-            )
+            body(combined(select(1), TableOperator::Intersect, select(2)))
         ))
     )
 }
@@ -137,35 +87,7 @@ fn test_minus() {
         combined_tables(input),
         Ok((
             "",
-            CombinedTables(
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(1)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                })),
-                TableOperator::Minus,
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(2)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                }))
-            )
+            body(combined(select(1), TableOperator::Minus, select(2)))
         ))
     )
 }
@@ -177,39 +99,99 @@ fn test_except() {
         combined_tables(input),
         Ok((
             "",
-            CombinedTables(
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(1)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                })),
-                TableOperator::Except,
-                QueryTerm::Select(Box::new(SelectStatement {
-                    select: SelectClause(
-                        None,
-                        List(vec!(SelectedExpression::Term(Term::Value(Value::Num(
-                            Numeric::Int(2)
-                        )))))
-                    ),
-                    from: None,
-                    r#where: None,
-                    group_by: None,
-                    order_by: None,
-                    limit: None
-                }))
-            )
+            body(combined(select(1), TableOperator::Except, select(2)))
         ))
     )
 }
 
+#[test]
+fn test_chained_operators() {
+    assert_eq!(
+        combined_tables("SELECT 1 UNION SELECT 2 EXCEPT SELECT 3"),
+        Ok((
+            "",
+            body(combined(
+                combined(select(1), TableOperator::Union(false), select(2)),
+                TableOperator::Except,
+                select(3)
+            ))
+        ))
+    );
+    assert_eq!(
+        combined_tables("SELECT 1 UNION SELECT 2 INTERSECT SELECT 3"),
+        Ok((
+            "",
+            body(combined(
+                select(1),
+                TableOperator::Union(false),
+                combined(select(2), TableOperator::Intersect, select(3))
+            ))
+        ))
+    );
+    assert_eq!(
+        combined_tables("(SELECT 1 EXCEPT SELECT 2) INTERSECT SELECT 3"),
+        Ok((
+            "",
+            body(combined(
+                QueryTerm::Parenthesis(Box::new(combined(
+                    select(1),
+                    TableOperator::Except,
+                    select(2)
+                ))),
+                TableOperator::Intersect,
+                select(3)
+            ))
+        ))
+    );
+}
+
+#[test]
+fn test_corresponding() {
+    assert_eq!(
+        combined_tables("SELECT 1 UNION ALL CORRESPONDING BY (id, title) SELECT 2"),
+        Ok((
+            "",
+            body(QueryTerm::Combined(
+                Box::new(select(1)),
+                TableOperator::Union(true),
+                Some(Corresponding(Some(List(vec![
+                    Name::Name("id".to_string()),
+                    Name::Name("title".to_string())
+                ])))),
+                Box::new(select(2))
+            ))
+        ))
+    );
+    assert_eq!(
+        combined_tables("SELECT 1 except corresponding SELECT 2")
+            .unwrap()
+            .1
+            .output(),
+        "SELECT 1\n\nEXCEPT CORRESPONDING\n\nSELECT 2"
+    );
+}
+
+#[test]
+fn test_order_by_and_limit_of_combined_tables() {
+    let (_, tables) = combined_tables("SELECT 1 UNION SELECT 2 ORDER BY 1 LIMIT 1").unwrap();
+    assert_eq!(
+        tables.body,
+        combined(select(1), TableOperator::Union(false), select(2))
+    );
+    assert!(tables.order_by.is_some());
+    assert_eq!(
+        tables.limit,
+        Some(LimitClause::Limit(Some(Term::from(1)), None))
+    );
+    assert_eq!(
+        parse_statements("select title from movies union select title from series order by title limit 10;")
+            .unwrap()
+            .1
+            .output(),
+        "SELECT title\n  FROM movies\n\n UNION\n\nSELECT title\n  FROM series\n ORDER BY title\nLIMIT 10;"
+    );
+}
+
 #[test]
 fn test_format_combined_tables() {
     assert_eq!(
@@ -238,7 +220,14 @@ fn test_format_combined_tables() {
             .unwrap()
             .1
             .output(),
-        "(SELECT 1)\n\nMINUS\n\n(SELECT 2)"
+        "(SELECT 1)\n\n MINUS\n\n(SELECT 2)"
+    );
+    assert_eq!(
+        combined_tables("(SELECT 1 union SELECT 2) union SELECT 3")
+            .unwrap()
+            .1
+            .output(),
+        "(SELECT 1\n\n  UNION\n\n SELECT 2)\n\n UNION\n\nSELECT 3"
     );
     assert_eq!(
         combined_tables("SELECT 1 intersect SELECT 2")
@@ -247,4 +236,11 @@ fn test_format_combined_tables() {
             .output(),
         "SELECT 1\n\nINTERSECT\n\nSELECT 2"
     );
+    assert_eq!(
+        combined_tables("SELECT 1 union SELECT 2 union SELECT 3")
+            .unwrap()
+            .1
+            .output(),
+        "SELECT 1\n\n UNION\n\nSELECT 2\n\n UNION\n\nSELECT 3"
+    );
 }
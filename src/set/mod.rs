@@ -1,45 +1,194 @@
+use crate::character::parse_comma;
 use crate::clause::Clause;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
 use crate::identifier::parse_name;
 use crate::identifier::Name;
+use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
+use crate::list::List;
 use crate::term::value::parse_value;
 use crate::term::value::Value;
 use crate::ws::ws;
+use nom::branch::alt;
 use nom::bytes::complete::tag;
+use nom::character::complete::char;
 use nom::combinator::map;
+use nom::combinator::peek;
+use nom::combinator::value;
+use nom::multi::separated_list1;
+use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct SetStatement(pub Name, pub Value);
+// SET LOCAL search_path TO public, extensions
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetStatement(
+    pub Option<SetScope>,
+    pub Name,
+    pub SetOperator,
+    pub List<ConfigurationValue>,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SetScope {
+    Local,
+    Session,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SetOperator {
+    Equal,
+    To,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfigurationValue {
+    Value(Value),
+    Name(Name),
+    Default,
+}
+
+// RESET ALL
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResetStatement(pub Option<Name>);
 
 impl Clause for SetStatement {
     const KEYWORD: &'static Keyword = &Keyword::Set;
 }
 
-pub fn parse_set_statement(input: &str) -> IResult<&str, SetStatement> {
+impl Clause for ResetStatement {
+    const KEYWORD: &'static Keyword = &Keyword::Reset;
+}
+
+fn parse_set_scope(input: &str) -> IResult<&str, SetScope> {
+    alt((
+        value(SetScope::Local, parse_keyword(Keyword::Local)),
+        value(SetScope::Session, parse_keyword(Keyword::Session)),
+    ))(input)
+}
+
+fn parse_set_operator(input: &str) -> IResult<&str, SetOperator> {
+    alt((
+        value(SetOperator::Equal, ws(tag("="))),
+        value(SetOperator::To, parse_keyword(Keyword::To)),
+    ))(input)
+}
+
+// A double-quoted value is an identifier, `"$user"` in a search path.
+fn parse_configuration_value(input: &str) -> IResult<&str, ConfigurationValue> {
+    alt((
+        map(
+            preceded(peek(char('"')), parse_name),
+            ConfigurationValue::Name,
+        ),
+        map(parse_value, ConfigurationValue::Value),
+        value(ConfigurationValue::Default, parse_keyword(Keyword::Default)),
+        map(parse_name, ConfigurationValue::Name),
+    ))(input)
+}
+
+fn parse_configuration(
+    input: &str,
+) -> IResult<&str, (Name, SetOperator, List<ConfigurationValue>)> {
     map(
         tuple((
-            SetStatement::parse_keyword,
-            parse_name,
-            ws(tag("=")),
-            ws(parse_value),
+            ws(parse_name),
+            parse_set_operator,
+            separated_list1(parse_comma, ws(parse_configuration_value)),
+        )),
+        |(n, o, v)| (n, o, List(v)),
+    )(input)
+}
+
+pub fn parse_set_statement(input: &str) -> IResult<&str, SetStatement> {
+    let (input, _) = SetStatement::parse_keyword(input)?;
+    alt((
+        map(
+            tuple((parse_set_scope, parse_configuration)),
+            |(s, (n, o, v))| SetStatement(Some(s), n, o, v),
+        ),
+        map(parse_configuration, |(n, o, v)| SetStatement(None, n, o, v)),
+    ))(input)
+}
+
+pub fn parse_reset_statement(input: &str) -> IResult<&str, ResetStatement> {
+    preceded(
+        ResetStatement::parse_keyword,
+        alt((
+            value(ResetStatement(None), parse_keyword(Keyword::All)),
+            map(ws(parse_name), |n| ResetStatement(Some(n))),
         )),
-        |(_, n, _, v)| SetStatement(n, v),
     )(input)
 }
 
 impl Format for SetStatement {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        f.append(self.keyword())
-            .ws()
-            .append_format(&self.0)
+        f.append(self.keyword()).ws();
+        match &self.0 {
+            Some(s) => f.append_format(s).ws(),
+            None => f,
+        };
+        f.append_format(&self.1)
             .ws()
-            .append_str("=")
+            .append_format(&self.2)
             .ws()
-            .append_format(&self.1)
+            .append_format(&self.3)
+    }
+}
+
+impl Format for SetScope {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Local => f.append(&Keyword::Local),
+            Self::Session => f.append(&Keyword::Session),
+        }
+    }
+}
+
+impl Format for SetOperator {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Equal => f.append_str("="),
+            Self::To => f.append(&Keyword::To),
+        }
+    }
+}
+
+impl Format for ConfigurationValue {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Value(v) => f.append_format(v),
+            Self::Name(n) => f.append_format(n),
+            Self::Default => f.append(&Keyword::Default),
+        }
+    }
+}
+
+impl Format for List<ConfigurationValue> {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        for (pos, i) in self.0.iter().enumerate() {
+            match pos {
+                0 => f.append_format(i),
+                _ => f.append_str(", ").append_format(i),
+            };
+        }
+        f
+    }
+}
+
+impl Format for ResetStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(self.keyword()).ws();
+        match &self.0 {
+            Some(n) => f.append_format(n),
+            None => f.append(&Keyword::All),
+        }
     }
 }
 
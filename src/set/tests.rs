@@ -1,7 +1,13 @@
 use crate::formatter::Format;
 use crate::identifier::Name;
+use crate::list::List;
 use crate::numeric::Numeric;
+use crate::set::parse_reset_statement;
 use crate::set::parse_set_statement;
+use crate::set::ConfigurationValue;
+use crate::set::ResetStatement;
+use crate::set::SetOperator;
+use crate::set::SetScope;
 use crate::set::SetStatement;
 use crate::term::value::Value;
 
@@ -13,15 +19,76 @@ fn test_parse_set_statement() {
         Ok((
             "",
             SetStatement(
+                None,
                 Name::Name("idle_in_transaction_session_timeout".to_string()),
-                Value::Num(Numeric::Int(0))
+                SetOperator::Equal,
+                List(vec!(ConfigurationValue::Value(Value::Num(Numeric::Int(0)))))
             )
         ))
     )
 }
 
+#[test]
+fn test_parse_set_statement_to_list() {
+    let input = "SET LOCAL search_path TO public, extensions";
+    assert_eq!(
+        parse_set_statement(input),
+        Ok((
+            "",
+            SetStatement(
+                Some(SetScope::Local),
+                Name::Name("search_path".to_string()),
+                SetOperator::To,
+                List(vec!(
+                    ConfigurationValue::Name(Name::Name("public".to_string())),
+                    ConfigurationValue::Name(Name::Name("extensions".to_string()))
+                ))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_parse_set_statement_non_reserved_name() {
+    let input = "SET local = 1";
+    assert!(parse_set_statement(input).is_ok())
+}
+
+#[test]
+fn test_parse_reset_statement() {
+    assert_eq!(
+        parse_reset_statement("RESET ALL"),
+        Ok(("", ResetStatement(None)))
+    );
+    assert_eq!(
+        parse_reset_statement("reset search_path"),
+        Ok((
+            "",
+            ResetStatement(Some(Name::Name("search_path".to_string())))
+        ))
+    )
+}
+
 #[test]
 fn test_format_set_statement() {
     let (_, t) = parse_set_statement("SET    idle_in_transaction_session_timeout = 0").unwrap();
-    assert_eq!(t.output(), "SET idle_in_transaction_session_timeout = 0")
+    assert_eq!(t.output(), "SET idle_in_transaction_session_timeout = 0");
+
+    let (_, t) = parse_set_statement("set session search_path to   public,extensions").unwrap();
+    assert_eq!(t.output(), "SET SESSION search_path TO public, extensions");
+
+    let (_, t) = parse_set_statement("SET statement_timeout = default").unwrap();
+    assert_eq!(t.output(), "SET statement_timeout = DEFAULT");
+
+    let (_, t) = parse_set_statement("SET search_path = \"$user\", public").unwrap();
+    assert_eq!(t.output(), "SET search_path = \"$user\", public");
+
+    let (_, t) = parse_set_statement("SET application_name = 'lint'").unwrap();
+    assert_eq!(t.output(), "SET application_name = 'lint'")
+}
+
+#[test]
+fn test_format_reset_statement() {
+    let (_, t) = parse_reset_statement("reset all").unwrap();
+    assert_eq!(t.output(), "RESET ALL")
 }
@@ -0,0 +1,89 @@
+use std::ops::Range;
+
+// Splits the input on the `;` outside of strings, quoted identifiers, dollar-quoted
+// bodies and comments. Comments and whitespace between statements are not part of
+// any range, a last statement without `;` ends with the input.
+pub fn split_statements(input: &str) -> Vec<Range<usize>> {
+    let bytes = input.as_bytes();
+    let mut ranges = vec![];
+    let mut start = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let next = match bytes[i] {
+            b if b.is_ascii_whitespace() => i + 1,
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                input[i..].find('\n').map_or(input.len(), |n| i + n + 1)
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => block_comment(input, i),
+            _ => {
+                start.get_or_insert(i);
+                match bytes[i] {
+                    b'\'' => quoted(input, i, '\''),
+                    b'"' => quoted(input, i, '"'),
+                    b'$' => dollar_quoted(input, i),
+                    b';' => {
+                        ranges.push(start.take().unwrap_or(i)..i + 1);
+                        i + 1
+                    }
+                    _ => i + 1,
+                }
+            }
+        };
+        i = next;
+    }
+    if let Some(s) = start {
+        ranges.push(s..input.trim_end().len());
+    }
+    ranges
+}
+
+// `'It''s'` is read as two strings, which does not change where it ends.
+fn quoted(input: &str, i: usize, quote: char) -> usize {
+    input[i + 1..]
+        .find(quote)
+        .map_or(input.len(), |n| i + 1 + n + 1)
+}
+
+// Block comments nest as in PostgreSQL.
+fn block_comment(input: &str, i: usize) -> usize {
+    let bytes = input.as_bytes();
+    let mut depth = 0;
+    let mut j = i;
+    while j + 1 < bytes.len() {
+        match (bytes[j], bytes[j + 1]) {
+            (b'/', b'*') => {
+                depth += 1;
+                j += 2;
+            }
+            (b'*', b'/') => {
+                depth -= 1;
+                j += 2;
+                if depth == 0 {
+                    return j;
+                }
+            }
+            _ => j += 1,
+        }
+    }
+    input.len()
+}
+
+// `$$ ... $$` or `$body$ ... $body$`, `$1` is a bind parameter.
+fn dollar_quoted(input: &str, i: usize) -> usize {
+    let rest = &input[i + 1..];
+    let tag_len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    let tag = &rest[..tag_len];
+    if !rest[tag_len..].starts_with('$') || tag.starts_with(|c: char| c.is_ascii_digit()) {
+        return i + 1;
+    }
+    let delimiter = format!("${}$", tag);
+    let body = i + delimiter.len();
+    input[body..]
+        .find(&delimiter)
+        .map_or(input.len(), |n| body + n + delimiter.len())
+}
+
+#[cfg(test)]
+mod tests;
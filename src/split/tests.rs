@@ -0,0 +1,51 @@
+use crate::split::split_statements;
+
+fn split(input: &str) -> Vec<&str> {
+    split_statements(input)
+        .into_iter()
+        .map(|r| &input[r])
+        .collect()
+}
+
+#[test]
+fn test_split_statements() {
+    assert_eq!(
+        split("SELECT 1;\n\n  SELECT 2 ;SELECT 3"),
+        vec!("SELECT 1;", "SELECT 2 ;", "SELECT 3")
+    );
+    assert_eq!(split("  \n"), Vec::<&str>::new());
+}
+
+#[test]
+fn test_split_strings() {
+    assert_eq!(
+        split("SELECT 'a;b', 'It''s;' FROM \"x;y\"; SELECT 2;"),
+        vec!("SELECT 'a;b', 'It''s;' FROM \"x;y\";", "SELECT 2;")
+    );
+    assert_eq!(
+        split("SELECT 'unterminated;"),
+        vec!("SELECT 'unterminated;")
+    );
+}
+
+#[test]
+fn test_split_comments() {
+    assert_eq!(
+        split("-- first; statement\nSELECT 1; /* a /* nested; */ comment */ SELECT /* ; */ 2;\n-- end;"),
+        vec!("SELECT 1;", "SELECT /* ; */ 2;")
+    );
+}
+
+#[test]
+fn test_split_dollar_quoted() {
+    let function =
+        "CREATE FUNCTION f() RETURNS integer AS $body$ SELECT 1; $$ ; $body$ LANGUAGE sql;";
+    assert_eq!(
+        split(&format!("{} SELECT $1, $a;", function)),
+        vec!(function, "SELECT $1, $a;")
+    );
+    assert_eq!(
+        split("DO $$ BEGIN; END $$; SELECT 1;"),
+        vec!("DO $$ BEGIN; END $$;", "SELECT 1;")
+    );
+}
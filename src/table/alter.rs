@@ -1,13 +1,19 @@
 use crate::character::parse_comma;
+use crate::character::parse_raw_expression;
+use crate::data_type::parse_data_type;
+use crate::data_type::DataType;
+use crate::schema::format_if_exists;
+use crate::schema::parse_if_exists;
 use crate::table::constraint::parse_table_constraint_definition;
 use crate::table::constraint::TableConstraintDefinition;
+use crate::table::create::DefaultOption;
 use crate::table::Formatter;
 use crate::term::column::parse_column_ref;
 use crate::term::column::ColumnRef;
 use crate::term::value::parse_value;
-use crate::term::value::Value;
 use nom::combinator::value;
 use nom::sequence::pair;
+use nom::sequence::preceded;
 
 use nom::{
     branch::alt,
@@ -29,7 +35,8 @@ use super::{
     drop_table::{parse_drop_behavior, DropBehavior},
 };
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AlterTable(pub TableRef, pub Vec<AlterTableAction>);
 
 pub fn parse_alter_table(input: &str) -> IResult<&str, AlterTable> {
@@ -57,18 +64,43 @@ pub fn parse_alter_table_action(input: &str) -> IResult<&str, AlterTableAction>
         map(
             tuple((
                 parse_keyword(Keyword::Drop),
-                parse_keyword(Keyword::Column),
-                parse_name,
+                parse_keyword(Keyword::Constraint),
+                parse_if_exists,
+                ws(parse_name),
                 opt(parse_drop_behavior),
             )),
-            |(_, _, c, b)| AlterTableAction::DropColumnDefinition(c, b),
+            |(_, _, e, n, b)| AlterTableAction::DropConstraint(e, n, b),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Drop),
+                opt(parse_keyword(Keyword::Column)),
+                parse_if_exists,
+                ws(parse_name),
+                opt(parse_drop_behavior),
+            )),
+            |(_, _, e, c, b)| AlterTableAction::DropColumnDefinition(e, c, b),
         ),
         map(
             tuple((
                 parse_keyword(Keyword::Add),
                 parse_table_constraint_definition,
+                opt(pair(
+                    parse_keyword(Keyword::Not),
+                    parse_keyword(Keyword::Valid),
+                )),
             )),
-            |(_, t)| AlterTableAction::AddTableConstraintDefinition(t),
+            |(_, t, v)| AlterTableAction::AddTableConstraintDefinition(t, v.is_some()),
+        ),
+        map(
+            preceded(
+                pair(
+                    parse_keyword(Keyword::Validate),
+                    parse_keyword(Keyword::Constraint),
+                ),
+                ws(parse_name),
+            ),
+            AlterTableAction::ValidateConstraint,
         ),
         map(
             tuple((
@@ -79,6 +111,23 @@ pub fn parse_alter_table_action(input: &str) -> IResult<&str, AlterTableAction>
             )),
             |(_, _, t, a)| AlterTableAction::AlterColumnDefinition(t, a),
         ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Rename),
+                opt(parse_keyword(Keyword::Column)),
+                ws(parse_name),
+                parse_keyword(Keyword::To),
+                ws(parse_name),
+            )),
+            |(_, _, c, _, n)| AlterTableAction::RenameColumn(c, n),
+        ),
+        map(
+            preceded(
+                pair(parse_keyword(Keyword::Set), parse_keyword(Keyword::Schema)),
+                ws(parse_name),
+            ),
+            AlterTableAction::SetSchema,
+        ),
     ))(input)
 }
 
@@ -89,8 +138,11 @@ impl Format for AlterTable {
             .append(&Keyword::Table)
             .ws()
             .append_format(&self.0);
-        for i in self.1.iter() {
-            f.new_line().indent(i);
+        for (pos, i) in self.1.iter().enumerate() {
+            match pos {
+                0 => f.new_line().indent(i),
+                _ => f.append_str(",").new_line().indent(i),
+            };
         }
         f
     }
@@ -105,18 +157,24 @@ impl Format for AlterTableAction {
                 .append_format(&Keyword::Column)
                 .ws()
                 .append_format(c),
-            Self::DropColumnDefinition(n, o) => {
+            Self::DropColumnDefinition(e, n, o) => {
                 f.indent(&Keyword::Drop)
                     .ws()
                     .append_format(&Keyword::Column)
-                    .ws()
-                    .append_format(n);
+                    .ws();
+                format_if_exists(f, *e).append_format(n);
                 match o {
                     Some(e) => f.ws().append(e),
                     None => f,
                 }
             }
-            Self::AddTableConstraintDefinition(c) => f.indent(&Keyword::Add).ws().append_format(c),
+            Self::AddTableConstraintDefinition(c, v) => {
+                f.indent(&Keyword::Add).ws().append_format(c);
+                match v {
+                    true => f.ws().append(&Keyword::Not).ws().append(&Keyword::Valid),
+                    false => f,
+                }
+            }
             Self::AlterColumnDefinition(c, a) => f
                 .indent(&Keyword::Alter)
                 .ws()
@@ -125,26 +183,64 @@ impl Format for AlterTableAction {
                 .append_format(c)
                 .ws()
                 .append_format(a),
+            Self::DropConstraint(e, n, o) => {
+                f.indent(&Keyword::Drop)
+                    .ws()
+                    .append(&Keyword::Constraint)
+                    .ws();
+                format_if_exists(f, *e).append(n);
+                match o {
+                    Some(e) => f.ws().append(e),
+                    None => f,
+                }
+            }
+            Self::ValidateConstraint(n) => f
+                .indent(&Keyword::Validate)
+                .ws()
+                .append(&Keyword::Constraint)
+                .ws()
+                .append(n),
+            Self::RenameColumn(c, n) => f
+                .indent(&Keyword::Rename)
+                .ws()
+                .append(&Keyword::Column)
+                .ws()
+                .append(c)
+                .ws()
+                .append(&Keyword::To)
+                .ws()
+                .append(n),
+            Self::SetSchema(n) => f
+                .indent(&Keyword::Set)
+                .ws()
+                .append(&Keyword::Schema)
+                .ws()
+                .append(n),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlterTableAction {
     AddColumnDefinition(ColumnDef),
-    DropColumnDefinition(Name, Option<DropBehavior>),
-    AddTableConstraintDefinition(TableConstraintDefinition),
+    DropColumnDefinition(bool, Name, Option<DropBehavior>),
+    AddTableConstraintDefinition(TableConstraintDefinition, bool),
     AlterColumnDefinition(ColumnRef, AlterColumnAction),
+    DropConstraint(bool, Name, Option<DropBehavior>),
+    ValidateConstraint(Name),
+    RenameColumn(Name, Name),
+    SetSchema(Name),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlterColumnAction {
     SetDefaultColumnClause(DefaultOption),
-}
-
-#[derive(Debug, PartialEq, Clone)]
-pub enum DefaultOption {
-    Value(Value),
+    DropDefault,
+    SetNotNull,
+    DropNotNull,
+    SetDataType(DataType, Option<String>),
 }
 
 pub fn parse_default_option(input: &str) -> IResult<&str, DefaultOption> {
@@ -152,22 +248,54 @@ pub fn parse_default_option(input: &str) -> IResult<&str, DefaultOption> {
 }
 
 pub fn parse_alter_column_action(input: &str) -> IResult<&str, AlterColumnAction> {
-    map(
-        tuple((
-            parse_keyword(Keyword::Set),
-            parse_keyword(Keyword::Default),
-            parse_default_option,
-        )),
-        |(_, _, d)| AlterColumnAction::SetDefaultColumnClause(d),
-    )(input)
-}
-
-impl Format for DefaultOption {
-    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        match self {
-            Self::Value(v) => f.append_format(v),
-        }
-    }
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::Set),
+                parse_keyword(Keyword::Default),
+                parse_default_option,
+            )),
+            |(_, _, d)| AlterColumnAction::SetDefaultColumnClause(d),
+        ),
+        value(
+            AlterColumnAction::DropDefault,
+            pair(
+                parse_keyword(Keyword::Drop),
+                parse_keyword(Keyword::Default),
+            ),
+        ),
+        value(
+            AlterColumnAction::SetNotNull,
+            tuple((
+                parse_keyword(Keyword::Set),
+                parse_keyword(Keyword::Not),
+                parse_keyword(Keyword::Null),
+            )),
+        ),
+        value(
+            AlterColumnAction::DropNotNull,
+            tuple((
+                parse_keyword(Keyword::Drop),
+                parse_keyword(Keyword::Not),
+                parse_keyword(Keyword::Null),
+            )),
+        ),
+        map(
+            tuple((
+                opt(pair(
+                    parse_keyword(Keyword::Set),
+                    parse_keyword(Keyword::Data),
+                )),
+                parse_keyword(Keyword::Type),
+                parse_data_type,
+                opt(preceded(
+                    parse_keyword(Keyword::Using),
+                    parse_raw_expression,
+                )),
+            )),
+            |(_, _, t, u)| AlterColumnAction::SetDataType(t, u.map(String::from)),
+        ),
+    ))(input)
 }
 
 impl Format for AlterColumnAction {
@@ -179,11 +307,32 @@ impl Format for AlterColumnAction {
                 .append(&Keyword::Default)
                 .ws()
                 .append_format(c),
+            Self::DropDefault => f.append(&Keyword::Drop).ws().append(&Keyword::Default),
+            Self::SetNotNull => f
+                .append(&Keyword::Set)
+                .ws()
+                .append(&Keyword::Not)
+                .ws()
+                .append(&Keyword::Null),
+            Self::DropNotNull => f
+                .append(&Keyword::Drop)
+                .ws()
+                .append(&Keyword::Not)
+                .ws()
+                .append(&Keyword::Null),
+            Self::SetDataType(t, u) => {
+                f.append(&Keyword::Type).ws().append_format(t);
+                match u {
+                    Some(u) => f.ws().append(&Keyword::Using).ws().append(u),
+                    None => f,
+                }
+            }
         }
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReferentialTriggeredAction {
     Update(ReferentialAction),
     Delete(ReferentialAction),
@@ -265,7 +414,8 @@ pub fn parse_referential_action(input: &str) -> IResult<&str, ReferentialAction>
     ))(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReferentialAction {
     Cascade,
     SetNull,
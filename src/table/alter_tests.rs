@@ -8,10 +8,12 @@ use crate::identifier::Name;
 use crate::list::List;
 use crate::numeric::Numeric;
 use crate::table::alter::parse_alter_table;
+use crate::table::alter::AlterColumnAction;
 use crate::table::alter::AlterTable;
 use crate::table::alter::AlterTableAction;
 use crate::table::create::ColumnDef;
 use crate::table::create::TableRef;
+use crate::term::column::ColumnRef;
 use crate::term::value::Value;
 
 #[test]
@@ -30,7 +32,8 @@ fn test_parse_alter_table_statement() {
                         Some(List(vec!(Value::Num(Numeric::Int(255)))))
                     ),
                     None,
-                    None
+                    None,
+                    List(vec!())
                 )))
             )
         ))
@@ -60,3 +63,64 @@ fn test_format_alter_table_statement() {
         "ALTER TABLE public.users\n        ALTER COLUMN age SET DEFAULT 18"
     )
 }
+
+#[test]
+fn test_parse_alter_table_actions() {
+    let input = "ALTER TABLE movies DROP IF EXISTS producer, RENAME title TO name, ALTER year TYPE integer USING year::integer";
+    assert_eq!(
+        parse_alter_table(input),
+        Ok((
+            "",
+            AlterTable(
+                TableRef(None, Name::Name("movies".to_string())),
+                vec!(
+                    AlterTableAction::DropColumnDefinition(
+                        true,
+                        Name::Name("producer".to_string()),
+                        None
+                    ),
+                    AlterTableAction::RenameColumn(
+                        Name::Name("title".to_string()),
+                        Name::Name("name".to_string())
+                    ),
+                    AlterTableAction::AlterColumnDefinition(
+                        ColumnRef::Name(Name::Name("year".to_string())),
+                        AlterColumnAction::SetDataType(
+                            DataType(PredefinedType::Integer, None),
+                            Some("year::integer".to_string())
+                        )
+                    )
+                )
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_alter_table_actions() {
+    assert_format!(
+        parse_alter_table(
+            "alter table movies drop column producer cascade, alter column name set not null, alter budget drop not null, alter column year set data type bigint"
+        ),
+        "ALTER TABLE movies
+        DROP COLUMN producer CASCADE,
+        ALTER COLUMN name SET NOT NULL,
+        ALTER COLUMN budget DROP NOT NULL,
+        ALTER COLUMN year TYPE BIGINT"
+    );
+    assert_format!(
+        parse_alter_table(
+            "alter table movies add constraint year_check check (year > 1800) not valid, validate constraint year_check, drop constraint if exists old_check restrict"
+        ),
+        "ALTER TABLE movies
+        ADD CONSTRAINT year_check CHECK(year > 1800) NOT VALID,
+        VALIDATE CONSTRAINT year_check,
+        DROP CONSTRAINT IF EXISTS old_check RESTRICT"
+    );
+    // Renaming the table cannot be combined with other actions, see `AlterObjectStatement`.
+    assert!(parse_alter_table("alter table movies rename to films").is_err());
+    assert_format!(
+        parse_alter_table("alter table movies set schema archive"),
+        "ALTER TABLE movies\n        SET SCHEMA archive"
+    );
+}
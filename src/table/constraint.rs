@@ -1,3 +1,4 @@
+use crate::character::parse_balanced;
 use crate::character::parse_comma;
 use crate::character::parse_left_parenthesis;
 use crate::character::parse_right_parenthesis;
@@ -16,12 +17,13 @@ use crate::term::column::parse_column_ref;
 use crate::term::column::ColumnRef;
 use crate::ws::ws;
 use nom::branch::alt;
-use nom::bytes::complete::take_while1;
 use nom::combinator::map;
 use nom::combinator::opt;
+use nom::combinator::value;
 use nom::multi::separated_list1;
 use nom::sequence::delimited;
 use nom::sequence::pair;
+use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
 use std::fmt;
@@ -94,27 +96,33 @@ impl fmt::Display for UniqueSpecification {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UniqueSpecification {
     Unique,
     PrimaryKey,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableConstraintDefinition(Option<ConstraintNameDefinition>, TableConstraint);
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniqueConstraintDefinition(UniqueSpecification, List<ColumnRef>);
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReferentialConstraintDefinition(List<ColumnRef>, ReferencesSpecification);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReferencesSpecification(
     pub TableRef,
     pub List<ColumnRef>,
     Option<ReferentialTriggeredAction>,
 );
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableConstraint {
     Unique(UniqueConstraintDefinition),
     Reference(ReferentialConstraintDefinition),
@@ -126,14 +134,14 @@ pub fn parse_references_specification(input: &str) -> IResult<&str, ReferencesSp
         tuple((
             parse_keyword(Keyword::References),
             ws(parse_table_ref),
-            delimited(
+            opt(delimited(
                 parse_left_parenthesis,
                 separated_list1(parse_comma, parse_column_ref),
                 parse_right_parenthesis,
-            ),
+            )),
             opt(parse_referential_triggered_action),
         )),
-        |(_, t, l, r)| ReferencesSpecification(t, List(l), r),
+        |(_, t, l, r)| ReferencesSpecification(t, List(l.unwrap_or_default()), r),
     )(input)
 }
 
@@ -163,21 +171,26 @@ pub fn parse_table_constraint(input: &str) -> IResult<&str, TableConstraint> {
         map(parse_unique_constraint_definition, |u| {
             TableConstraint::Unique(u)
         }),
-        map(
-            pair(
-                parse_keyword(Keyword::Check),
-                delimited(
-                    parse_left_parenthesis,
-                    take_while1(|c| c != ')'),
-                    parse_right_parenthesis,
-                ),
+        map(parse_check_constraint, TableConstraint::Check),
+    ))(input)
+}
+
+pub fn parse_check_constraint(input: &str) -> IResult<&str, String> {
+    map(
+        pair(
+            parse_keyword(Keyword::Check),
+            delimited(
+                parse_left_parenthesis,
+                parse_balanced,
+                parse_right_parenthesis,
             ),
-            |(_, p)| TableConstraint::Check(p.to_string()),
         ),
-    ))(input)
+        |(_, p)| p.to_string(),
+    )(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConstraintNameDefinition(pub Name);
 
 pub fn parse_constraint_name_definition(input: &str) -> IResult<&str, ConstraintNameDefinition> {
@@ -207,14 +220,9 @@ impl Format for ReferencesSpecification {
         f.append_format(&Keyword::References)
             .ws()
             .append_format(&self.0);
-        f.append_str("(");
-        for (pos, i) in self.1 .0.iter().enumerate() {
-            match pos {
-                0 => f.append_format(i),
-                _ => f.append_str(", ").append_format(i),
-            };
+        if !self.1 .0.is_empty() {
+            f.append_str("(").append_format(&self.1).append_str(")");
         }
-        f.append_str(")");
         match &self.2 {
             Some(s) => f.ws().append_format(s),
             None => f,
@@ -266,18 +274,69 @@ impl Format for ColumnConstraint {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         match self {
             Self::NotNull => f.append(&Keyword::Not).ws().append(&Keyword::Null),
+            Self::Null => f.append(&Keyword::Null),
             Self::Unique(u) => f.append(u),
+            Self::Check(c) => f
+                .append(&Keyword::Check)
+                .append_str("(")
+                .append(c)
+                .append_str(")"),
+            Self::References(r) => f.append_format(r),
+            Self::Generated(g) => f.append_format(g),
+        }
+    }
+}
+
+impl Format for GeneratedColumn {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Generated).ws();
+        match self {
+            Self::Identity(always, o) => {
+                match always {
+                    true => f.append(&Keyword::Always),
+                    false => f.append(&Keyword::By).ws().append(&Keyword::Default),
+                };
+                f.ws().append(&Keyword::As).ws().append(&Keyword::Identity);
+                match o {
+                    Some(o) => f.ws().append_str("(").append(o).append_str(")"),
+                    None => f,
+                }
+            }
+            Self::Stored(e) => f
+                .append(&Keyword::Always)
+                .ws()
+                .append(&Keyword::As)
+                .ws()
+                .append_str("(")
+                .append(e)
+                .append_str(")")
+                .ws()
+                .append(&Keyword::Stored),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnConstraint {
     NotNull,
+    Null,
     Unique(UniqueSpecification),
+    Check(String),
+    References(ReferencesSpecification),
+    Generated(GeneratedColumn),
+}
+
+// GENERATED BY DEFAULT AS IDENTITY (START WITH 10)
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GeneratedColumn {
+    Identity(bool, Option<String>),
+    Stored(String),
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnConstraintDefinition(pub Option<ConstraintNameDefinition>, pub ColumnConstraint);
 
 pub fn parse_column_constraint_definition(
@@ -298,9 +357,54 @@ pub fn parse_column_constraint(input: &str) -> IResult<&str, ColumnConstraint> {
             pair(parse_keyword(Keyword::Not), parse_keyword(Keyword::Null)),
             |_| ColumnConstraint::NotNull,
         ),
+        map(parse_keyword(Keyword::Null), |_| ColumnConstraint::Null),
         map(parse_unique_specification, ColumnConstraint::Unique),
+        map(parse_check_constraint, ColumnConstraint::Check),
+        map(parse_references_specification, ColumnConstraint::References),
+        map(parse_generated_column, ColumnConstraint::Generated),
     ))(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
+pub fn parse_generated_column(input: &str) -> IResult<&str, GeneratedColumn> {
+    preceded(
+        parse_keyword(Keyword::Generated),
+        alt((
+            map(
+                tuple((
+                    alt((
+                        value(true, parse_keyword(Keyword::Always)),
+                        value(
+                            false,
+                            pair(parse_keyword(Keyword::By), parse_keyword(Keyword::Default)),
+                        ),
+                    )),
+                    parse_keyword(Keyword::As),
+                    parse_keyword(Keyword::Identity),
+                    opt(delimited(
+                        parse_left_parenthesis,
+                        parse_balanced,
+                        parse_right_parenthesis,
+                    )),
+                )),
+                |(a, _, _, o)| GeneratedColumn::Identity(a, o.map(|o| o.trim().to_string())),
+            ),
+            map(
+                tuple((
+                    parse_keyword(Keyword::Always),
+                    parse_keyword(Keyword::As),
+                    delimited(
+                        parse_left_parenthesis,
+                        parse_balanced,
+                        parse_right_parenthesis,
+                    ),
+                    parse_keyword(Keyword::Stored),
+                )),
+                |(_, _, e, _)| GeneratedColumn::Stored(e.trim().to_string()),
+            ),
+        )),
+    )(input)
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Definition(Name);
@@ -1,11 +1,15 @@
+use crate::character::parse_balanced;
 use crate::character::parse_comma;
 use crate::character::parse_left_parenthesis;
+use crate::character::parse_raw_expression;
 use crate::character::parse_right_parenthesis;
 use crate::clause::Clause;
 use crate::data_type::parse_data_type;
 use crate::data_type::DataType;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
+use crate::function::parse_function;
+use crate::function::Function;
 use crate::identifier::parse_delimited_identifier;
 use crate::identifier::parse_name;
 use crate::identifier::Delimitedidentifier;
@@ -15,6 +19,8 @@ use crate::keyword::Keyword;
 use crate::list::List;
 use crate::query::parse_query;
 use crate::query::Query;
+use crate::schema::format_if_not_exists;
+use crate::schema::parse_if_not_exists;
 use crate::table::constraint::parse_column_constraint_definition;
 use crate::table::constraint::parse_table_constraint_definition;
 use crate::table::constraint::ColumnConstraintDefinition;
@@ -23,24 +29,81 @@ use crate::term::value::parse_value;
 use crate::term::value::Value;
 use crate::ws::ws;
 use nom::branch::alt;
+use nom::bytes::complete::is_not;
+use nom::bytes::complete::take_while1;
 use nom::character::complete::char;
 use nom::combinator::map;
+use nom::combinator::map_opt;
+use nom::combinator::map_res;
 use nom::combinator::opt;
+use nom::combinator::recognize;
+use nom::combinator::value;
+use nom::multi::many0;
 use nom::multi::separated_list1;
 use nom::sequence::delimited;
 use nom::sequence::pair;
+use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
+use pad::PadStr;
 use std::fmt;
 use std::ops::Deref;
+use std::str::FromStr;
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct CreateTableStatement(pub TableRef, pub TableContentsSource);
+// CREATE TEMPORARY TABLE IF NOT EXISTS movies (...) PARTITION BY RANGE (year)
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateTableStatement(
+    pub bool,
+    pub bool,
+    pub TableRef,
+    pub TableContentsSource,
+    pub Option<PartitionClause>,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartitionClause(pub PartitionStrategy, pub List<String>);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+}
+
+// LIKE movies INCLUDING ALL EXCLUDING COMMENTS
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LikeClause(pub TableRef, pub List<LikeOption>);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LikeOption(pub bool, pub LikeProperty);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, EnumString, IntoStaticStr, Clone, Copy, PartialEq, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(ascii_case_insensitive, serialize_all = "UPPERCASE")]
+pub enum LikeProperty {
+    All,
+    Comments,
+    Compression,
+    Constraints,
+    Defaults,
+    Generated,
+    Identity,
+    Indexes,
+    Statistics,
+    Storage,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Subquery(pub Box<Query>);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableContentsSource {
     TableElementList(List<TableElement>),
     As(Subquery),
@@ -74,11 +137,60 @@ pub fn parse_create_table(input: &str) -> IResult<&str, CreateTableStatement> {
     map(
         tuple((
             parse_keyword(Keyword::Create),
+            opt(alt((
+                parse_keyword(Keyword::Temporary),
+                parse_keyword(Keyword::Temp),
+            ))),
             parse_keyword(Keyword::Table),
+            parse_if_not_exists,
             ws(parse_table_ref),
             parse_table_contents_source,
+            opt(parse_partition_clause),
+        )),
+        |(_, tmp, _, e, t, c, p)| CreateTableStatement(tmp.is_some(), e, t, c, p),
+    )(input)
+}
+
+pub fn parse_partition_clause(input: &str) -> IResult<&str, PartitionClause> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Partition),
+            parse_keyword(Keyword::By),
+            alt((
+                value(PartitionStrategy::Range, parse_keyword(Keyword::Range)),
+                value(PartitionStrategy::List, parse_keyword(Keyword::List)),
+                value(PartitionStrategy::Hash, parse_keyword(Keyword::Hash)),
+            )),
+            delimited(
+                parse_left_parenthesis,
+                separated_list1(parse_comma, ws(parse_raw_expression)),
+                parse_right_parenthesis,
+            ),
+        )),
+        |(_, _, s, e)| PartitionClause(s, List(e.into_iter().map(String::from).collect())),
+    )(input)
+}
+
+pub fn parse_like_clause(input: &str) -> IResult<&str, LikeClause> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Like),
+            ws(parse_table_ref),
+            many0(map(
+                pair(
+                    alt((
+                        value(true, parse_keyword(Keyword::Including)),
+                        value(false, parse_keyword(Keyword::Excluding)),
+                    )),
+                    ws(map_res(
+                        take_while1(|c: char| c.is_alphabetic()),
+                        |s: &str| LikeProperty::from_str(s),
+                    )),
+                ),
+                |(i, p)| LikeOption(i, p),
+            )),
         )),
-        |(_, _, t, e)| CreateTableStatement(t, e),
+        |(_, t, o)| LikeClause(t, List(o)),
     )(input)
 }
 
@@ -87,19 +199,56 @@ pub fn parse_table_element(input: &str) -> IResult<&str, TableElement> {
         map(parse_table_constraint_definition, |c| {
             TableElement::TableConstraintDefinition(c)
         }),
+        map(parse_like_clause, TableElement::Like),
         map(parse_column_def, TableElement::ColumnDef),
     ))(input)
 }
 
 impl Format for CreateTableStatement {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        f.append(&Keyword::Create)
-            .ws()
-            .append(&Keyword::Table)
-            .ws()
-            .append(&self.0)
+        f.append(&Keyword::Create).ws();
+        if self.0 {
+            f.append(&Keyword::Temporary).ws();
+        }
+        f.append(&Keyword::Table).ws();
+        format_if_not_exists(f, self.1)
+            .append(&self.2)
             .ws()
-            .append_format(&self.1)
+            .append_format(&self.3);
+        match &self.4 {
+            Some(p) => f.ws().append_format(p),
+            None => f,
+        }
+    }
+}
+
+impl Format for PartitionClause {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Partition).ws().append(&Keyword::By).ws();
+        match self.0 {
+            PartitionStrategy::Range => f.append(&Keyword::Range),
+            PartitionStrategy::List => f.append(&Keyword::List),
+            PartitionStrategy::Hash => f.append(&Keyword::Hash),
+        };
+        f.ws()
+            .append_str("(")
+            .append_str(&self.1 .0.join(", "))
+            .append_str(")")
+    }
+}
+
+impl Format for LikeClause {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Like).ws().append(&self.0);
+        for o in self.1 .0.iter() {
+            match o.0 {
+                true => f.ws().append(&Keyword::Including),
+                false => f.ws().append(&Keyword::Excluding),
+            };
+            let p: &'static str = o.1.into();
+            f.ws().append(&p);
+        }
+        f
     }
 }
 
@@ -113,10 +262,16 @@ impl Format for TableContentsSource {
             Self::TableElementList(l) => {
                 f.append_str("(").new_line();
                 f.set_pad(4);
+                let widths = l.column_widths(f);
                 for (pos, line) in l.0.iter().enumerate() {
-                    match pos {
-                        0 => f.indent(line),
-                        _ => f.append_str(",").new_line().indent(line),
+                    if pos > 0 {
+                        f.append_str(",").new_line();
+                    }
+                    match (f.options.align_columns, line) {
+                        (true, TableElement::ColumnDef(c)) => {
+                            f.indent(&AlignedColumnDef(c, widths))
+                        }
+                        _ => f.indent(line),
                     };
                 }
                 f.new_line().append_str(")")
@@ -140,24 +295,73 @@ impl Format for TableElement {
         match self {
             Self::ColumnDef(c) => f.indent(c),
             Self::TableConstraintDefinition(c) => f.indent(c),
+            Self::Like(c) => f.indent(c),
         }
     }
 }
 
-impl Format for ColumnDef {
+impl List<TableElement> {
+    // Widest column name and data type, for aligned output.
+    fn column_widths(&self, f: &Formatter) -> (usize, usize) {
+        self.0.iter().fold((0, 0), |(n, t), e| match e {
+            TableElement::ColumnDef(c) => (
+                n.max(f.render(&c.0).chars().count()),
+                t.max(f.render(&c.1).chars().count()),
+            ),
+            _ => (n, t),
+        })
+    }
+}
+
+struct AlignedColumnDef<'a>(&'a ColumnDef, (usize, usize));
+
+impl Format for AlignedColumnDef<'_> {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        f.append_format(&self.0).ws().append_format(&self.1);
-        match &self.2 {
-            Some(c) => f.ws().append_format(c),
-            None => f,
-        };
-        match &self.3 {
-            Some(c) => f.ws().append_format(c),
-            None => f,
+        let c = self.0;
+        let name = f.render(&c.0).pad_to_width(self.1 .0);
+        let data_type = f.render(&c.1);
+        f.indent(&name).ws();
+        match c.has_options() {
+            true => f
+                .append_str(&data_type.pad_to_width(self.1 .1))
+                .append_format(&ColumnOptions(c)),
+            false => f.append_str(&data_type),
         }
     }
 }
 
+struct ColumnOptions<'a>(&'a ColumnDef);
+
+impl Format for ColumnOptions<'_> {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        if let Some(c) = &self.0 .2 {
+            f.ws().append(&Keyword::Collate).ws().append(c);
+        }
+        if let Some(d) = &self.0 .3 {
+            f.ws().append_format(d);
+        }
+        for c in self.0 .4 .0.iter() {
+            f.ws().append_format(c);
+        }
+        f
+    }
+}
+
+impl ColumnDef {
+    fn has_options(&self) -> bool {
+        self.2.is_some() || self.3.is_some() || !self.4 .0.is_empty()
+    }
+}
+
+impl Format for ColumnDef {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append_format(&self.0)
+            .ws()
+            .append_format(&self.1)
+            .append_format(&ColumnOptions(self))
+    }
+}
+
 impl fmt::Display for TableRef {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.0 {
@@ -167,7 +371,8 @@ impl fmt::Display for TableRef {
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableRef(pub Option<Name>, pub Name);
 
 pub fn parse_table_ref(input: &str) -> IResult<&str, TableRef> {
@@ -180,21 +385,28 @@ pub fn parse_table_ref(input: &str) -> IResult<&str, TableRef> {
     ))(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnDef(
     pub Delimitedidentifier,
     pub DataType,
+    pub Option<String>,
     pub Option<DefaultClause>,
-    pub Option<ColumnConstraintDefinition>,
+    pub List<ColumnConstraintDefinition>,
 );
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DefaultOption {
     Value(Value),
+    Function(Function),
+    Name(Name),
+    Expression(String),
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct DefaultClause(DefaultOption);
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefaultClause(pub DefaultOption);
 
 impl Clause for DefaultClause {
     const KEYWORD: &'static Keyword = &Keyword::Default;
@@ -210,28 +422,61 @@ impl Format for DefaultOption {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         match self {
             Self::Value(v) => f.append(v),
+            Self::Function(fu) => f.append_format(fu),
+            Self::Name(n) => f.append(n),
+            Self::Expression(e) => f.append_str("(").append(e).append_str(")"),
         }
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableElement {
     ColumnDef(ColumnDef),
     TableConstraintDefinition(TableConstraintDefinition),
+    Like(LikeClause),
 }
 
+// A column has at most one DEFAULT clause, wherever it is among the constraints.
 pub fn parse_column_def(input: &str) -> IResult<&str, ColumnDef> {
-    map(
+    map_opt(
         tuple((
             ws(parse_delimited_identifier),
             ws(parse_data_type),
-            opt(ws(parse_default_clause)),
-            opt(ws(parse_column_constraint_definition)),
+            opt(preceded(
+                parse_keyword(Keyword::Collate),
+                ws(parse_collation),
+            )),
+            many0(alt((
+                map(ws(parse_default_clause), |d| (Some(d), None)),
+                map(ws(parse_column_constraint_definition), |c| (None, Some(c))),
+            ))),
         )),
-        |(name, d, def, c)| ColumnDef(name, d, def, c),
+        |(name, d, col, o)| {
+            let (def, c): (Vec<_>, Vec<_>) = o.into_iter().unzip();
+            let mut def = def.into_iter().flatten();
+            let default = def.next();
+            match def.next() {
+                Some(_) => None,
+                None => Some(ColumnDef(
+                    name,
+                    d,
+                    col.map(String::from),
+                    default,
+                    List(c.into_iter().flatten().collect()),
+                )),
+            }
+        },
     )(input)
 }
 
+fn parse_collation(input: &str) -> IResult<&str, &str> {
+    alt((
+        recognize(delimited(char('"'), is_not("\""), char('"'))),
+        recognize(parse_name),
+    ))(input)
+}
+
 pub fn parse_default_clause(input: &str) -> IResult<&str, DefaultClause> {
     map(
         pair(DefaultClause::parse_keyword, parse_default_option),
@@ -240,5 +485,17 @@ pub fn parse_default_clause(input: &str) -> IResult<&str, DefaultClause> {
 }
 
 pub fn parse_default_option(input: &str) -> IResult<&str, DefaultOption> {
-    map(parse_value, DefaultOption::Value)(input)
+    alt((
+        map(parse_value, DefaultOption::Value),
+        map(parse_function, DefaultOption::Function),
+        map(
+            delimited(
+                parse_left_parenthesis,
+                parse_balanced,
+                parse_right_parenthesis,
+            ),
+            |e| DefaultOption::Expression(e.trim().to_string()),
+        ),
+        map(parse_name, DefaultOption::Name),
+    ))(input)
 }
@@ -1,4 +1,6 @@
 use crate::assert_format;
+use crate::data_type::DateTimeOption;
+use crate::data_type::DateTimeType;
 use crate::expression::Compare;
 use crate::expression::Condition;
 use crate::expression::Expression;
@@ -6,6 +8,8 @@ use crate::expression::Operand;
 use crate::expression::RightOperand;
 use crate::expression::WhereClause;
 use crate::formatter::Format;
+use crate::formatter::FormatOptions;
+use crate::function::Function;
 use crate::numeric::Numeric;
 use crate::query::Query;
 use crate::select::clause::SelectClause;
@@ -14,6 +18,14 @@ use crate::select::from::TableExpression;
 use crate::select::from::TableName;
 use crate::select::FromClause;
 use crate::select::SelectStatement;
+use crate::table::constraint::GeneratedColumn;
+use crate::table::create::DefaultClause;
+use crate::table::create::DefaultOption;
+use crate::table::create::LikeClause;
+use crate::table::create::LikeOption;
+use crate::table::create::LikeProperty;
+use crate::table::create::PartitionClause;
+use crate::table::create::PartitionStrategy;
 use crate::table::create::Subquery;
 use crate::table::create::TableContentsSource;
 use crate::term::value::Value;
@@ -39,15 +51,19 @@ fn test_create_table() {
         Ok((
             "",
             CreateTableStatement(
+                false,
+                false,
                 TableRef(None, Name::Name("movies".to_string())),
                 TableContentsSource::TableElementList(List(vec!(TableElement::ColumnDef(
                     ColumnDef(
                         Delimitedidentifier::Name(Name::Name("id".to_string())),
                         DataType(PredefinedType::Integer, None),
                         None,
-                        None
+                        None,
+                        List(vec!())
                     )
-                ))))
+                )))),
+                None
             )
         ))
     )
@@ -61,10 +77,12 @@ fn test_create_table_as() {
         Ok((
             "",
             CreateTableStatement(
+                false,
+                false,
                 TableRef(None, Name::Name("saucisse".to_string())),
                 TableContentsSource::As(Subquery(Box::new(Query::Select(Box::new(
                     SelectStatement {
-                        select: SelectClause(None, List(vec!(SelectedExpression::All))),
+                        select: SelectClause(None, None, List(vec!(SelectedExpression::All))),
                         from: Some(FromClause(
                             List(vec!(TableExpression(TableName::Name(Name::Name(
                                 "food".to_string()
@@ -84,7 +102,8 @@ fn test_create_table_as() {
                         order_by: None,
                         limit: None
                     }
-                )))))
+                ))))),
+                None
             )
         ))
     )
@@ -98,18 +117,22 @@ fn test_create_table_primary_key() {
         Ok((
             "",
             CreateTableStatement(
+                false,
+                false,
                 TableRef(None, Name::Name("movies".to_string())),
                 TableContentsSource::TableElementList(List(vec!(TableElement::ColumnDef(
                     ColumnDef(
                         Delimitedidentifier::Name(Name::Name("id".to_string())),
                         DataType(PredefinedType::Integer, None),
                         None,
-                        Some(ColumnConstraintDefinition(
+                        None,
+                        List(vec!(ColumnConstraintDefinition(
                             None,
                             ColumnConstraint::Unique(UniqueSpecification::PrimaryKey)
-                        ))
+                        )))
                     )
-                ))))
+                )))),
+                None
             )
         ))
     )
@@ -123,18 +146,22 @@ fn test_create_table_constraint_name() {
         Ok((
             "",
             CreateTableStatement(
+                false,
+                false,
                 TableRef(None, Name::Name("movies".to_string())),
                 TableContentsSource::TableElementList(List(vec!(TableElement::ColumnDef(
                     ColumnDef(
                         Delimitedidentifier::Name(Name::Name("id".to_string())),
                         DataType(PredefinedType::Integer, None),
                         None,
-                        Some(ColumnConstraintDefinition(
+                        None,
+                        List(vec!(ColumnConstraintDefinition(
                             Some(ConstraintNameDefinition(Name::Name("c_unique".to_string()))),
                             ColumnConstraint::Unique(UniqueSpecification::Unique)
-                        ))
+                        )))
                     )
-                ))))
+                )))),
+                None
             )
         ))
     )
@@ -156,3 +183,127 @@ fn assert_format_create_table() {
         "CREATE TABLE movies (\n        id INTEGER,\n        year INTEGER DEFAULT 2022\n)"
     )
 }
+
+#[test]
+fn test_create_table_column_constraints() {
+    let input = "CREATE TABLE IF NOT EXISTS movies (id bigint GENERATED ALWAYS AS IDENTITY PRIMARY KEY NOT NULL, created_at timestamp DEFAULT now())";
+    assert_eq!(
+        parse_create_table(input),
+        Ok((
+            "",
+            CreateTableStatement(
+                false,
+                true,
+                TableRef(None, Name::Name("movies".to_string())),
+                TableContentsSource::TableElementList(List(vec!(
+                    TableElement::ColumnDef(ColumnDef(
+                        Delimitedidentifier::Name(Name::Name("id".to_string())),
+                        DataType(PredefinedType::Bigint, None),
+                        None,
+                        None,
+                        List(vec!(
+                            ColumnConstraintDefinition(
+                                None,
+                                ColumnConstraint::Generated(GeneratedColumn::Identity(true, None))
+                            ),
+                            ColumnConstraintDefinition(
+                                None,
+                                ColumnConstraint::Unique(UniqueSpecification::PrimaryKey)
+                            ),
+                            ColumnConstraintDefinition(None, ColumnConstraint::NotNull)
+                        ))
+                    )),
+                    TableElement::ColumnDef(ColumnDef(
+                        Delimitedidentifier::Name(Name::Name("created_at".to_string())),
+                        DataType(
+                            PredefinedType::DateTime(DateTimeType::Timestamp(
+                                DateTimeOption::default()
+                            )),
+                            None
+                        ),
+                        None,
+                        Some(DefaultClause(DefaultOption::Function(Function(
+                            "now".to_string(),
+                            List(vec!())
+                        )))),
+                        List(vec!())
+                    ))
+                ))),
+                None
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_create_table_repeated_default() {
+    assert!(parse_create_table(
+        "CREATE TABLE movies (year integer DEFAULT 2022 NOT NULL DEFAULT 1999)"
+    )
+    .is_err());
+}
+
+#[test]
+fn test_create_table_like_and_partition() {
+    let input = "CREATE TEMP TABLE events (LIKE templates INCLUDING ALL) PARTITION BY LIST (kind)";
+    assert_eq!(
+        parse_create_table(input),
+        Ok((
+            "",
+            CreateTableStatement(
+                true,
+                false,
+                TableRef(None, Name::Name("events".to_string())),
+                TableContentsSource::TableElementList(List(vec!(TableElement::Like(LikeClause(
+                    TableRef(None, Name::Name("templates".to_string())),
+                    List(vec!(LikeOption(true, LikeProperty::All)))
+                ))))),
+                Some(PartitionClause(
+                    PartitionStrategy::List,
+                    List(vec!("kind".to_string()))
+                ))
+            )
+        ))
+    )
+}
+
+#[test]
+fn assert_format_create_table_extensions() {
+    assert_format!(
+        parse_create_table(
+            "create temporary table if not exists prices (id integer generated by default as identity (start with 10), amount numeric(10, 2) not null default 0 check (amount > (0)), label text collate \"C\", total integer generated always as (amount * 2) stored, movie_id integer references movies on delete cascade) partition by range (id, amount)"
+        ),
+        "CREATE TEMPORARY TABLE IF NOT EXISTS prices (
+        id INTEGER GENERATED BY DEFAULT AS IDENTITY (start with 10),
+        amount NUMERIC(10, 2) DEFAULT 0 NOT NULL CHECK(amount > (0)),
+        label TEXT COLLATE \"C\",
+        total INTEGER GENERATED ALWAYS AS (amount * 2) STORED,
+        movie_id INTEGER REFERENCES movies ON DELETE CASCADE
+) PARTITION BY RANGE (id, amount)"
+    );
+    assert_format!(
+        parse_create_table("create table t (like other including defaults excluding indexes, updated_at timestamp default current_timestamp)"),
+        "CREATE TABLE t (
+        LIKE other INCLUDING DEFAULTS EXCLUDING INDEXES,
+        updated_at TIMESTAMP DEFAULT current_timestamp
+)"
+    );
+}
+
+#[test]
+fn test_format_create_table_aligned() {
+    let input = "create table movies (id integer primary key, title varchar(255) not null, year integer, primary key (id))";
+    let (_, t) = parse_create_table(input).unwrap();
+    assert_eq!(
+        t.output_with(FormatOptions {
+            align_columns: true,
+            ..FormatOptions::default()
+        }),
+        "CREATE TABLE movies (
+        id    INTEGER      PRIMARY KEY,
+        title VARCHAR(255) NOT NULL,
+        year  INTEGER,
+        PRIMARY KEY(id)
+)"
+    );
+}
@@ -14,7 +14,8 @@ use nom::sequence::tuple;
 use nom::IResult;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DropTable(pub TableRef, pub Option<DropBehavior>);
 
 pub fn parse_drop_table(input: &str) -> IResult<&str, DropTable> {
@@ -36,7 +37,8 @@ pub fn parse_drop_behavior(input: &str) -> IResult<&str, DropBehavior> {
     ))(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DropBehavior {
     Cascade,
     Restrict,
@@ -14,8 +14,8 @@ pub mod constraint;
 pub mod create;
 pub mod drop_table;
 
-#[derive(Debug, PartialEq, Clone)]
-
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Table {
     Create(CreateTableStatement),
     Alter(AlterTable),
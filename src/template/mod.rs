@@ -0,0 +1,278 @@
+use crate::format_fragment;
+use crate::FormatOptions;
+use crate::Warning;
+
+// Expressions become identifiers the parser accepts, `{{ ref('orders') }}` is `__tpl0__`.
+const PLACEHOLDER: &str = "__tpl";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Placement {
+    // {% if is_incremental() %}, {% set x = 1 %}, {# comment #}
+    BeforeNextToken,
+    // {% endif %}, {% else %} and the branch it starts
+    AfterPreviousToken,
+}
+
+// A tag removed before parsing, `offset` is its position in `Template::sql`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tag {
+    pub offset: usize,
+    pub text: String,
+    pub placement: Placement,
+}
+
+// SQL without its Jinja syntax, the first branch of conditionals is kept.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    pub sql: String,
+    pub expressions: Vec<String>,
+    pub tags: Vec<Tag>,
+}
+
+pub fn format_template(s: &str, options: FormatOptions) -> (String, Vec<Warning>) {
+    let template = Template::new(s);
+    if template.sql.trim().is_empty() {
+        return (s.trim().to_string(), vec![]);
+    }
+    let (formatted, warnings) = format_fragment(&template.sql, options);
+    let warnings = warnings
+        .into_iter()
+        .map(|w| Warning {
+            statement: template.restore(&w.statement),
+            ..w
+        })
+        .collect();
+    (template.render(&formatted), warnings)
+}
+
+fn tag_end(s: &str, i: usize) -> Option<usize> {
+    let rest = &s[i..];
+    let close = [("{{", "}}"), ("{%", "%}"), ("{#", "#}")]
+        .iter()
+        .find(|(open, _)| rest.starts_with(open))?
+        .1;
+    rest[2..].find(close).map(|n| i + 2 + n + 2)
+}
+
+// `{%- endif -%}` is `endif`.
+fn tag_name(tag: &str) -> &str {
+    tag[2..tag.len() - 2]
+        .trim_matches(|c: char| c == '-' || c == '+' || c.is_whitespace())
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+}
+
+// Start of the `{% endif %}` closing the conditional containing `i`.
+fn endif(s: &str, mut i: usize) -> Option<usize> {
+    let mut depth = 0;
+    while let Some(n) = s[i..].find('{') {
+        i += n;
+        let end = match tag_end(s, i) {
+            Some(end) => end,
+            None => {
+                i += 1;
+                continue;
+            }
+        };
+        match (s[i..].starts_with("{%"), tag_name(&s[i..end])) {
+            (true, "if") => depth += 1,
+            (true, "endif") if depth == 0 => return Some(i),
+            (true, "endif") => depth -= 1,
+            _ => (),
+        };
+        i = end;
+    }
+    None
+}
+
+// Tags are replaced by spaces, keeping the lines and offsets of the SQL.
+fn blank(s: &str) -> String {
+    s.chars()
+        .map(|c| if c == '\n' { '\n' } else { ' ' })
+        .collect()
+}
+
+impl Template {
+    pub fn new(s: &str) -> Self {
+        let mut template = Template {
+            sql: String::with_capacity(s.len()),
+            expressions: vec![],
+            tags: vec![],
+        };
+        let mut i = 0;
+        while i < s.len() {
+            let end = match tag_end(s, i) {
+                Some(end) => end,
+                None => {
+                    let c = s[i..].chars().next().unwrap();
+                    template.sql.push(c);
+                    i += c.len_utf8();
+                    continue;
+                }
+            };
+            let text = &s[i..end];
+            let sql_before = !template.sql.trim().is_empty();
+            if text.starts_with("{{") && sql_before {
+                template.sql.push_str(&format!(
+                    "{}{}__{}",
+                    PLACEHOLDER,
+                    template.expressions.len(),
+                    "\n".repeat(text.matches('\n').count())
+                ));
+                template.expressions.push(text.to_string());
+                i = end;
+                continue;
+            }
+            let name = tag_name(text);
+            let (end, placement) = match name {
+                "else" | "elif" if text.starts_with("{%") => (
+                    endif(s, end).unwrap_or(s.len()),
+                    Placement::AfterPreviousToken,
+                ),
+                n if n.starts_with("end") && text.starts_with("{%") => {
+                    (end, Placement::AfterPreviousToken)
+                }
+                _ => (end, Placement::BeforeNextToken),
+            };
+            template.tags.push(Tag {
+                offset: template.sql.len(),
+                text: s[i..end].trim_end().to_string(),
+                placement,
+            });
+            template.sql.push_str(&blank(&s[i..end]));
+            i = end;
+        }
+        template
+    }
+
+    pub fn restore(&self, s: &str) -> String {
+        let mut output = s.to_string();
+        for (i, e) in self.expressions.iter().enumerate() {
+            output = output.replace(&format!("{}{}__", PLACEHOLDER, i), e);
+        }
+        output
+    }
+
+    // Puts the tags back around the tokens of the formatted SQL they were next to.
+    pub fn render(&self, formatted: &str) -> String {
+        let input = tokens(&self.sql);
+        let output = tokens(formatted);
+        let aligned = align(&self.sql, &input, formatted, &output);
+        let mut inserts: Vec<(usize, String)> = vec![];
+        for tag in &self.tags {
+            let next = input.partition_point(|t| t.0 < tag.offset);
+            let insert = match tag.placement {
+                Placement::BeforeNextToken => match (next..input.len()).find_map(|i| aligned[i]) {
+                    Some(o) => before(formatted, output[o].0, &tag.text),
+                    None if formatted.is_empty() => (0, tag.text.clone()),
+                    None => (formatted.len(), format!("\n{}", tag.text)),
+                },
+                Placement::AfterPreviousToken => match (0..next).rev().find_map(|i| aligned[i]) {
+                    Some(o) => after(formatted, output[o].1, &tag.text),
+                    None => (0, format!("{}\n", tag.text)),
+                },
+            };
+            inserts.push(insert);
+        }
+        inserts.sort_by_key(|i| i.0);
+        let mut rendered = String::new();
+        let mut offset = 0;
+        for (position, text) in inserts {
+            rendered.push_str(&formatted[offset..position]);
+            rendered.push_str(&text);
+            offset = position;
+        }
+        rendered.push_str(&formatted[offset..]);
+        self.restore(&rendered)
+    }
+}
+
+// A tag before the first token of a line gets its own line.
+fn before(s: &str, position: usize, text: &str) -> (usize, String) {
+    let line_start = s[..position].rfind('\n').map_or(0, |i| i + 1);
+    match s[line_start..position].trim().is_empty() {
+        true => (line_start, format!("{}\n", text)),
+        false => (position, format!("{} ", text)),
+    }
+}
+
+fn after(s: &str, position: usize, text: &str) -> (usize, String) {
+    match s[position..].starts_with('\n') || position == s.len() {
+        true => (position, format!("\n{}", text)),
+        false => (position, format!(" {}", text)),
+    }
+}
+
+// Words, quoted strings and identifiers, and single characters.
+fn tokens(s: &str) -> Vec<(usize, usize)> {
+    let mut tokens = vec![];
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let mut end = start + c.len_utf8();
+        match c {
+            c if c.is_whitespace() => continue,
+            '\'' | '"' => {
+                for (i, n) in chars.by_ref() {
+                    end = i + n.len_utf8();
+                    if n == c {
+                        break;
+                    }
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                while let Some((i, n)) = chars.peek() {
+                    if !(n.is_alphanumeric() || *n == '_') {
+                        break;
+                    }
+                    end = i + n.len_utf8();
+                    chars.next();
+                }
+            }
+            _ => (),
+        }
+        tokens.push((start, end));
+    }
+    tokens
+}
+
+// Matches the input tokens with the formatted ones, the formatter only changes the
+// case of keywords and adds or removes a few tokens, `AS` for instance.
+fn align(
+    input: &str,
+    input_tokens: &[(usize, usize)],
+    output: &str,
+    output_tokens: &[(usize, usize)],
+) -> Vec<Option<usize>> {
+    const LOOKAHEAD: usize = 8;
+    let eq = |i: usize, o: usize| {
+        let (a, b) = (input_tokens[i], output_tokens[o]);
+        input[a.0..a.1].eq_ignore_ascii_case(&output[b.0..b.1])
+    };
+    let mut aligned = vec![None; input_tokens.len()];
+    let (mut i, mut o) = (0, 0);
+    while i < input_tokens.len() && o < output_tokens.len() {
+        if eq(i, o) {
+            aligned[i] = Some(o);
+            i += 1;
+            o += 1;
+            continue;
+        }
+        let inserted = (1..=LOOKAHEAD).find(|d| o + d < output_tokens.len() && eq(i, o + d));
+        let removed = (1..=LOOKAHEAD).find(|d| i + d < input_tokens.len() && eq(i + d, o));
+        match (inserted, removed) {
+            (Some(a), Some(b)) if a <= b => o += a,
+            (Some(a), None) => o += a,
+            (_, Some(b)) => i += b,
+            (None, None) => {
+                aligned[i] = Some(o);
+                i += 1;
+                o += 1;
+            }
+        }
+    }
+    aligned
+}
+
+#[cfg(test)]
+mod tests;
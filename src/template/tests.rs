@@ -0,0 +1,106 @@
+use crate::template::format_template;
+use crate::template::Placement;
+use crate::template::Template;
+use crate::FormatOptions;
+use crate::Warning;
+
+fn format(s: &str) -> String {
+    let (formatted, warnings) = format_template(s, FormatOptions::default());
+    assert_eq!(warnings, vec!());
+    let (reformatted, _) = format_template(&formatted, FormatOptions::default());
+    assert_eq!(reformatted, formatted, "formatting is not idempotent");
+    formatted
+}
+
+#[test]
+fn test_template_new() {
+    let template = Template::new("{% if full %}select * from {{ ref('orders') }}{%- endif %}");
+    assert_eq!(
+        template.sql,
+        format!("{}select * from __tpl0__{}", " ".repeat(13), " ".repeat(12))
+    );
+    assert_eq!(
+        template.expressions,
+        vec!("{{ ref('orders') }}".to_string())
+    );
+    assert_eq!(
+        template
+            .tags
+            .iter()
+            .map(|t| (t.offset, t.text.as_str(), t.placement.clone()))
+            .collect::<Vec<_>>(),
+        vec!(
+            (0, "{% if full %}", Placement::BeforeNextToken),
+            (35, "{%- endif %}", Placement::AfterPreviousToken)
+        )
+    );
+}
+
+#[test]
+fn test_format_dbt_model() {
+    let model = "{{ config(materialized='incremental') }}
+
+select id, amount from {{ ref('orders') }} where amount > {{ var('min_amount') }}
+{% if is_incremental() %}
+and updated_at > {{ var('since') }}
+{% endif %}";
+    assert_eq!(
+        format(model),
+        "{{ config(materialized='incremental') }}
+SELECT id, amount
+  FROM {{ ref('orders') }}
+ WHERE amount > {{ var('min_amount') }}
+{% if is_incremental() %}
+   AND updated_at > {{ var('since') }}
+{% endif %}"
+    );
+}
+
+#[test]
+fn test_format_block_around_clause() {
+    let model = "{# orders #}\nselect id from {{ ref('orders') }}\n{% if is_incremental() %}\nwhere updated_at > now()\n{% endif %}";
+    assert_eq!(
+        format(model),
+        "{# orders #}
+SELECT id
+  FROM {{ ref('orders') }}
+{% if is_incremental() %}
+ WHERE updated_at > now()
+{% endif %}"
+    );
+}
+
+#[test]
+fn test_format_loop_and_else() {
+    assert_eq!(
+        format("select\n{% for c in columns %}\n{{ c }},\n{% endfor %}\nid from users"),
+        "SELECT {% for c in columns %} {{ c }}, {% endfor %} id\n  FROM users"
+    );
+    assert_eq!(
+        format("select id from users where {% if admin %} a = 1 {% else %} b = 2 {% endif %}"),
+        "SELECT id\n  FROM users\n WHERE {% if admin %} a = 1\n{% else %} b = 2\n{% endif %}"
+    );
+}
+
+#[test]
+fn test_format_non_ascii() {
+    assert_eq!(
+        format("select 'café', '€' from {{ ref('menu') }} where label = 'été'"),
+        "SELECT 'café', '€'\n  FROM {{ ref('menu') }}\n WHERE label = 'été'"
+    );
+}
+
+#[test]
+fn test_format_template_warnings() {
+    let (formatted, warnings) =
+        format_template("vacuum {{ ref('orders') }}", FormatOptions::default());
+    assert_eq!(formatted, "vacuum {{ ref('orders') }}");
+    assert_eq!(
+        warnings,
+        vec!(Warning {
+            line: 1,
+            column: 1,
+            statement: "vacuum {{ ref('orders') }};".to_string()
+        })
+    );
+}
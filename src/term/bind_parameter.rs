@@ -1,18 +1,42 @@
+use crate::visitor::Node;
+use crate::visitor::Visit;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
+use nom::character::complete::alpha1;
+use nom::character::complete::alphanumeric1;
 use nom::character::complete::digit1;
+use nom::character::complete::u16;
 use nom::combinator::map;
-use nom::combinator::map_res;
+use nom::combinator::not;
 use nom::combinator::recognize;
 use nom::combinator::value;
-use nom::sequence::tuple;
+use nom::multi::many0;
+use nom::sequence::delimited;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::terminated;
 use nom::IResult;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BindParameter {
+    // ?
     Placeholder,
-    Index(u8),
+    // :1
+    Index(u16),
+    // $1
+    Dollar(u16),
+    // ?1
+    Numbered(u16),
+    // :name
+    Named(String),
+    // @name
+    AtNamed(String),
+    // %(name)s
+    Pyformat(String),
+    // %s
+    Format,
 }
 
 impl fmt::Display for BindParameter {
@@ -20,16 +44,59 @@ impl fmt::Display for BindParameter {
         match self {
             Self::Placeholder => write!(f, "?"),
             Self::Index(i) => write!(f, ":{i}"),
+            Self::Dollar(i) => write!(f, "${i}"),
+            Self::Numbered(i) => write!(f, "?{i}"),
+            Self::Named(n) => write!(f, ":{n}"),
+            Self::AtNamed(n) => write!(f, "@{n}"),
+            Self::Pyformat(n) => write!(f, "%({n})s"),
+            Self::Format => write!(f, "%s"),
         }
     }
 }
 
+// Parameter names are not identifiers: `:user` or `@limit` are valid.
+fn parameter_name(input: &str) -> IResult<&str, String> {
+    map(
+        recognize(pair(
+            alt((alpha1, tag("_"))),
+            many0(alt((alphanumeric1, tag("_")))),
+        )),
+        String::from,
+    )(input)
+}
+
 pub fn parse_bind_parameter(input: &str) -> IResult<&str, BindParameter> {
     alt((
-        value(BindParameter::Placeholder, tag("?")),
+        map(preceded(tag("?"), u16), BindParameter::Numbered),
+        // `?123456` overflows, it is not `?` followed by a number.
+        value(
+            BindParameter::Placeholder,
+            terminated(tag("?"), not(digit1)),
+        ),
+        map(preceded(tag(":"), u16), BindParameter::Index),
+        map(preceded(tag(":"), parameter_name), BindParameter::Named),
+        map(preceded(tag("$"), u16), BindParameter::Dollar),
+        map(preceded(tag("@"), parameter_name), BindParameter::AtNamed),
         map(
-            tuple((tag(":"), map_res(recognize(digit1), str::parse))),
-            |(_, i)| BindParameter::Index(i),
+            delimited(tag("%("), parameter_name, tag(")s")),
+            BindParameter::Pyformat,
         ),
+        value(BindParameter::Format, tag("%s")),
     ))(input)
 }
+
+#[derive(Default)]
+struct BindParameters(Vec<BindParameter>);
+
+impl Visit for BindParameters {
+    fn visit_bind_parameter(&mut self, node: &BindParameter) {
+        self.0.push(node.clone())
+    }
+}
+
+// The parameters of a statement in the order they appear, to check the arguments passed with it.
+pub fn bind_parameters<N: Node + ?Sized>(node: &N) -> Vec<BindParameter> {
+    let mut parameters = BindParameters::default();
+    node.accept(&mut parameters);
+    parameters.0
+}
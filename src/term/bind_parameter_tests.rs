@@ -1,4 +1,6 @@
 use crate::formatter::Format;
+use crate::query::parse_statements;
+use crate::term::bind_parameter::bind_parameters;
 use crate::term::parse_bind_parameter;
 use crate::term::BindParameter;
 
@@ -23,3 +25,50 @@ fn test_format_bind_parameter() {
     assert_eq!(BindParameter::Index(1).output(), ":1");
     assert_eq!(BindParameter::Placeholder.output(), "?");
 }
+
+#[test]
+fn test_parse_bind_parameter_styles() {
+    let parameters = [
+        ("$12", BindParameter::Dollar(12)),
+        (":1000", BindParameter::Index(1000)),
+        ("?3", BindParameter::Numbered(3)),
+        (":user_id", BindParameter::Named("user_id".to_string())),
+        ("@limit", BindParameter::AtNamed("limit".to_string())),
+        ("%(name)s", BindParameter::Pyformat("name".to_string())),
+        ("%s", BindParameter::Format),
+    ];
+    for (input, parameter) in parameters {
+        assert_eq!(parse_bind_parameter(input), Ok(("", parameter.clone())));
+        assert_eq!(parameter.output(), input);
+    }
+    assert!(parse_bind_parameter(":").is_err());
+    assert!(parse_bind_parameter("@1").is_err());
+    assert!(parse_bind_parameter("?123456").is_err());
+    assert!(parse_bind_parameter("$123456").is_err());
+    assert!(parse_statements("SELECT a FROM t WHERE b = ?123456;").is_err());
+}
+
+#[test]
+fn test_bind_parameters() {
+    let (_, statements) = parse_statements(
+        "SELECT title FROM movies WHERE year > $1 AND id IN (SELECT movie_id FROM awards WHERE name = $2) LIMIT $3;",
+    )
+    .unwrap();
+    assert_eq!(
+        bind_parameters(&statements),
+        vec!(
+            BindParameter::Dollar(1),
+            BindParameter::Dollar(2),
+            BindParameter::Dollar(3)
+        )
+    );
+    let (_, statements) =
+        parse_statements("UPDATE movies SET title = :title WHERE id = :id;").unwrap();
+    assert_eq!(
+        bind_parameters(&statements),
+        vec!(
+            BindParameter::Named("title".to_string()),
+            BindParameter::Named("id".to_string())
+        )
+    );
+}
@@ -11,7 +11,8 @@ use nom::sequence::separated_pair;
 use nom::IResult;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnRef {
     Name(Name),
     WithFamily(Name, Name),
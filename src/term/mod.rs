@@ -16,6 +16,7 @@ use crate::identifier::Name;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
 use crate::list::List;
+use crate::nesting::nested;
 use crate::select::parse_select_statement;
 use crate::select::SelectStatement;
 use crate::term::bind_parameter::parse_bind_parameter;
@@ -23,9 +24,10 @@ use crate::term::column::parse_column_ref;
 use crate::term::value::parse_value;
 use crate::ws::ws;
 use nom::branch::alt;
+use nom::character::complete::multispace0;
 use nom::combinator::map;
 use nom::sequence::delimited;
-use nom::sequence::tuple;
+use nom::sequence::preceded;
 use nom::IResult;
 
 pub mod bind_parameter;
@@ -33,7 +35,8 @@ pub mod case;
 pub mod column;
 pub mod value;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Term {
     Value(Value),
     Case(CaseExpression),
@@ -75,29 +78,27 @@ impl Format for Term {
                 .append(a),
             Self::Subquery(s) => f
                 .new_line()
-                .new_context()
                 .right_side(&"(".to_string())
-                .set_offset(2)
-                .append_format(s.deref())
-                .append_str(")")
-                .set_offset(0)
-                .pop_context(),
+                .append_indented(s.deref())
+                .append_str(")"),
         }
     }
 }
 
+// The term is parsed once: retrying it without the alias is exponential on nested subqueries.
 pub fn parse_term(input: &str) -> IResult<&str, Term> {
-    alt((
-        map(
-            tuple((ws(term), parse_keyword(Keyword::As), ws(parse_name))),
-            |(t, _, a)| Term::AliasedTerm(Box::new(t), a),
-        ),
-        term,
-    ))(input)
+    let (after_space, _) = multispace0(input)?;
+    let (rest, t) = term(after_space)?;
+    match preceded(parse_keyword(Keyword::As), ws(parse_name))(rest) {
+        Ok((rest, a)) => Ok((rest, Term::AliasedTerm(Box::new(t), a))),
+        Err(nom::Err::Error(_)) if after_space.len() == input.len() => Ok((rest, t)),
+        Err(nom::Err::Error(_)) => term(input),
+        Err(e) => Err(e),
+    }
 }
 
 fn term(input: &str) -> IResult<&str, Term> {
-    alt((
+    nested(alt((
         map(parse_value, Term::Value),
         map(parse_case_expression, Term::Case),
         map(parse_aggregate_function, Term::Function),
@@ -111,7 +112,7 @@ fn term(input: &str) -> IResult<&str, Term> {
             ),
             |s| Term::Subquery(Box::new(s)),
         ),
-    ))(input)
+    )))(input)
 }
 
 impl Format for List<Term> {
@@ -4,6 +4,7 @@ use crate::expression::Operand;
 use crate::function::Function;
 use crate::identifier::Name;
 use crate::list::List;
+use crate::nesting::MAX_DEPTH;
 use crate::numeric::Numeric;
 use crate::term::case::Case;
 use crate::term::case::When;
@@ -71,6 +72,38 @@ fn test_term_column_ref() {
     )
 }
 
+#[test]
+fn test_term_quoted_column() {
+    assert_eq!(
+        parse_term("\"id\""),
+        Ok((
+            "",
+            Term::ColumnRef(ColumnRef::Name(Name::QuotedName("id".to_string())))
+        ))
+    )
+}
+
+#[test]
+fn test_term_column_named_like_a_number() {
+    assert_eq!(
+        parse_term("nan.a"),
+        Ok((
+            "",
+            Term::ColumnRef(ColumnRef::WithFamily(
+                Name::Name("nan".to_string()),
+                Name::Name("a".to_string())
+            ))
+        ))
+    );
+    assert_eq!(
+        parse_term("inf"),
+        Ok((
+            "",
+            Term::ColumnRef(ColumnRef::Name(Name::Name("inf".to_string())))
+        ))
+    )
+}
+
 #[test]
 fn test_term_function() {
     let input = "AVG(price)";
@@ -104,3 +137,14 @@ fn test_term_aliased_term() {
         ))
     )
 }
+
+#[test]
+fn test_term_nesting_limit() {
+    let nested = |n: usize| format!("{}1{}", "(SELECT ".repeat(n), ")".repeat(n));
+    assert!(parse_term(&nested(MAX_DEPTH - 1)).is_ok());
+    assert!(matches!(
+        parse_term(&nested(MAX_DEPTH)),
+        Err(nom::Err::Failure(_))
+    ));
+    assert!(parse_term(&nested(10_000)).is_err());
+}
@@ -6,13 +6,18 @@ use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::bytes::complete::take_until;
 use nom::character::complete::char;
+use nom::character::complete::satisfy;
 use nom::combinator::map;
+use nom::combinator::not;
+use nom::combinator::peek;
 use nom::combinator::value as nomValue;
 use nom::sequence::delimited;
+use nom::sequence::terminated;
 use nom::IResult;
 use std::fmt;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Value {
     Null,
     Num(Numeric),
@@ -34,7 +39,7 @@ impl fmt::Display for Value {
 pub fn parse_value(input: &str) -> IResult<&str, Value> {
     use Value::*;
     alt((
-        nomValue(Null, tag_no_case("null")),
+        nomValue(Null, word("null")),
         map(parse_numeric, Num),
         map(string, String),
         map(boolean, Bool),
@@ -42,20 +47,21 @@ pub fn parse_value(input: &str) -> IResult<&str, Value> {
 }
 
 fn boolean(input: &str) -> IResult<&str, bool> {
-    alt((
-        nomValue(false, tag_no_case("false")),
-        nomValue(true, tag_no_case("true")),
-    ))(input)
+    alt((nomValue(false, word("false")), nomValue(true, word("true"))))(input)
 }
 
-fn string_delimiter(input: &str) -> IResult<&str, char> {
-    alt((char('"'), char('\'')))(input)
+// Matches `w` only when it is not the prefix of an identifier, e.g. `nullable`.
+fn word<'a>(w: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    terminated(
+        tag_no_case(w),
+        not(peek(satisfy(|c: char| c.is_alphanumeric() || c == '_'))),
+    )
 }
 
+// Double quotes delimit identifiers, `"id"` is a column.
 fn string(input: &str) -> IResult<&str, String> {
-    let build_string = alt((take_until("\""), take_until("\'")));
     map(
-        delimited(string_delimiter, build_string, string_delimiter),
+        delimited(char('\''), take_until("'"), char('\'')),
         String::from,
     )(input)
 }
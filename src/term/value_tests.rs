@@ -1,3 +1,4 @@
+use crate::numeric::Numeric;
 use crate::term::value::parse_value;
 use crate::term::value::Value;
 
@@ -27,11 +28,26 @@ fn test_value_bool_string() {
         Ok(("", Value::String("Swiss army man".to_string())))
     )
 }
-//#[test]
-//fn test_value_numeric() {
-//    let input = "3.14";
-//    assert_eq!(
-//        parse_value(input),
-//        Ok(("", Value::Num(Numeric::Float(3.14))))
-//    )
-//}
+
+#[test]
+fn test_value_numeric() {
+    let input = "2.5";
+    assert_eq!(
+        parse_value(input),
+        Ok(("", Value::Num(Numeric::Float(2.5))))
+    )
+}
+
+#[test]
+fn test_value_null_prefix() {
+    assert!(parse_value("nullable").is_err())
+}
+
+#[test]
+fn test_value_string_delimiters() {
+    let input = "'a' AND b = \"c\"";
+    assert_eq!(
+        parse_value(input),
+        Ok((" AND b = \"c\"", Value::String("a".to_string())))
+    )
+}
@@ -1,4 +1,12 @@
 use crate::format;
+use crate::format_sql_range;
+use crate::format_tolerant;
+use crate::format_with_options;
+use crate::parse;
+use crate::Error;
+use crate::FormatOptions;
+use crate::RangeEdit;
+use crate::Warning;
 
 #[macro_export]
 macro_rules! assert_format {
@@ -17,6 +25,98 @@ macro_rules! assert_format_not_empty {
 #[test]
 fn test_format() {
     assert_eq!(format("select 1;"), Ok("SELECT 1;".to_string()));
-    let cannot_parse = "CREATE EXTENSION IF NOT EXISTS pgcrypto WITH SCHEMA public;";
+    let extension = "CREATE EXTENSION IF NOT EXISTS pgcrypto WITH SCHEMA public;";
+    assert_eq!(format(extension), Ok(extension.to_string()));
+    let cannot_parse = "VACUUM movies;";
     assert!(format(cannot_parse).is_err())
 }
+
+#[test]
+fn test_format_with_options() {
+    let input = "update movies set title = 'Matrix', release_year = 1999;";
+    assert_eq!(
+        format_with_options(input, FormatOptions::default()),
+        format(input)
+    );
+    assert_eq!(
+        format_with_options(
+            input,
+            FormatOptions {
+                align_columns: true,
+                ..FormatOptions::default()
+            }
+        ),
+        Ok(
+            "UPDATE movies\n   SET title        = 'Matrix',\n       release_year = 1999;"
+                .to_string()
+        )
+    )
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(parse("select 1; select 2;").map(|ast| ast.0.len()), Ok(2));
+    assert_eq!(
+        parse("select 1; vacuum"),
+        Err(Error::ParsingIncompleteError("vacuum".to_string()))
+    )
+}
+
+#[test]
+fn test_format_tolerant() {
+    let input = "-- movies\nselect 1;\n\n  VACUUM  movies;\nselect 'a;b'; -- done\n";
+    let (formatted, warnings) = format_tolerant(input, FormatOptions::default());
+    assert_eq!(
+        formatted,
+        "-- movies\nSELECT 1;\n\nVACUUM  movies;\n\nSELECT 'a;b'; -- done"
+    );
+    assert_eq!(
+        warnings,
+        vec!(Warning {
+            line: 4,
+            column: 3,
+            statement: "VACUUM  movies;".to_string()
+        })
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "Unable to parse SQL at line 4, column 3, left unchanged: 'VACUUM  movies;'"
+    );
+}
+
+#[test]
+fn test_format_tolerant_comments_between_statements() {
+    let format = |s| format_tolerant(s, FormatOptions::default()).0;
+    assert_eq!(
+        format("select a from t; /* c; */ select b from u;"),
+        "SELECT a\n  FROM t; /* c; */\n\nSELECT b\n  FROM u;"
+    );
+    assert_eq!(
+        format("select 1; -- trailing\nselect 2;"),
+        "SELECT 1; -- trailing\n\nSELECT 2;"
+    );
+    assert_eq!(
+        format("select 1;\n-- about 2\nselect 2;\n\n-- detached\n\nselect 3;"),
+        "SELECT 1;\n\n-- about 2\nSELECT 2;\n\n-- detached\n\nSELECT 3;"
+    );
+}
+
+#[test]
+fn test_format_tolerant_without_statement() {
+    let (formatted, warnings) = format_tolerant("\n-- nothing\n", FormatOptions::default());
+    assert_eq!(formatted, "-- nothing");
+    assert_eq!(warnings, vec!());
+}
+
+#[test]
+fn test_format_sql_range() {
+    let input = "select 'é';\nselect title from movies;\n";
+    assert_eq!(
+        format_sql_range(input, 15, 15),
+        Ok(vec!(RangeEdit {
+            start: 12,
+            end: 37,
+            text: "SELECT title\n  FROM movies;".to_string()
+        }))
+    );
+}
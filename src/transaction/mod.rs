@@ -0,0 +1,253 @@
+use crate::character::parse_comma;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::opt;
+use nom::combinator::value;
+use nom::multi::many0;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::terminated;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransactionStatement {
+    Begin(List<TransactionMode>),
+    StartTransaction(List<TransactionMode>),
+    SetTransaction(List<TransactionMode>),
+    Commit,
+    Rollback(Option<Name>),
+    Savepoint(Name),
+    Release(Name),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransactionMode {
+    IsolationLevel(IsolationLevel),
+    ReadOnly,
+    ReadWrite,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+fn parse_work(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        opt(alt((
+            parse_keyword(Keyword::Work),
+            parse_keyword(Keyword::Transaction),
+        ))),
+    )(input)
+}
+
+pub fn parse_isolation_level(input: &str) -> IResult<&str, IsolationLevel> {
+    alt((
+        value(
+            IsolationLevel::ReadUncommitted,
+            pair(
+                parse_keyword(Keyword::Read),
+                parse_keyword(Keyword::Uncommitted),
+            ),
+        ),
+        value(
+            IsolationLevel::ReadCommitted,
+            pair(
+                parse_keyword(Keyword::Read),
+                parse_keyword(Keyword::Committed),
+            ),
+        ),
+        value(
+            IsolationLevel::RepeatableRead,
+            pair(
+                parse_keyword(Keyword::Repeatable),
+                parse_keyword(Keyword::Read),
+            ),
+        ),
+        value(
+            IsolationLevel::Serializable,
+            parse_keyword(Keyword::Serializable),
+        ),
+    ))(input)
+}
+
+pub fn parse_transaction_mode(input: &str) -> IResult<&str, TransactionMode> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::Isolation),
+                parse_keyword(Keyword::Level),
+                parse_isolation_level,
+            )),
+            |(_, _, l)| TransactionMode::IsolationLevel(l),
+        ),
+        value(
+            TransactionMode::ReadOnly,
+            pair(parse_keyword(Keyword::Read), parse_keyword(Keyword::Only)),
+        ),
+        value(
+            TransactionMode::ReadWrite,
+            pair(parse_keyword(Keyword::Read), parse_keyword(Keyword::Write)),
+        ),
+    ))(input)
+}
+
+fn parse_transaction_modes(input: &str) -> IResult<&str, List<TransactionMode>> {
+    map(
+        many0(terminated(parse_transaction_mode, opt(parse_comma))),
+        List,
+    )(input)
+}
+
+pub fn parse_transaction_statement(input: &str) -> IResult<&str, TransactionStatement> {
+    alt((
+        map(
+            tuple((
+                parse_keyword(Keyword::Begin),
+                parse_work,
+                parse_transaction_modes,
+            )),
+            |(_, _, m)| TransactionStatement::Begin(m),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Start),
+                parse_keyword(Keyword::Transaction),
+                parse_transaction_modes,
+            )),
+            |(_, _, m)| TransactionStatement::StartTransaction(m),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Set),
+                parse_keyword(Keyword::Transaction),
+                parse_transaction_modes,
+            )),
+            |(_, _, m)| TransactionStatement::SetTransaction(m),
+        ),
+        value(
+            TransactionStatement::Commit,
+            pair(parse_keyword(Keyword::Commit), parse_work),
+        ),
+        map(
+            tuple((
+                parse_keyword(Keyword::Rollback),
+                parse_work,
+                opt(preceded(
+                    pair(
+                        parse_keyword(Keyword::To),
+                        opt(parse_keyword(Keyword::Savepoint)),
+                    ),
+                    ws(parse_name),
+                )),
+            )),
+            |(_, _, n)| TransactionStatement::Rollback(n),
+        ),
+        map(
+            preceded(parse_keyword(Keyword::Savepoint), ws(parse_name)),
+            TransactionStatement::Savepoint,
+        ),
+        map(
+            preceded(
+                pair(
+                    parse_keyword(Keyword::Release),
+                    opt(parse_keyword(Keyword::Savepoint)),
+                ),
+                ws(parse_name),
+            ),
+            TransactionStatement::Release,
+        ),
+    ))(input)
+}
+
+impl Format for TransactionStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Begin(m) => f.append(&Keyword::Begin).append_format(m),
+            Self::StartTransaction(m) => f
+                .append(&Keyword::Start)
+                .ws()
+                .append(&Keyword::Transaction)
+                .append_format(m),
+            Self::SetTransaction(m) => f
+                .append(&Keyword::Set)
+                .ws()
+                .append(&Keyword::Transaction)
+                .append_format(m),
+            Self::Commit => f.append(&Keyword::Commit),
+            Self::Rollback(None) => f.append(&Keyword::Rollback),
+            Self::Rollback(Some(n)) => f
+                .append(&Keyword::Rollback)
+                .ws()
+                .append(&Keyword::To)
+                .ws()
+                .append(&Keyword::Savepoint)
+                .ws()
+                .append(n),
+            Self::Savepoint(n) => f.append(&Keyword::Savepoint).ws().append(n),
+            Self::Release(n) => f
+                .append(&Keyword::Release)
+                .ws()
+                .append(&Keyword::Savepoint)
+                .ws()
+                .append(n),
+        }
+    }
+}
+
+impl Format for List<TransactionMode> {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        for (pos, i) in self.0.iter().enumerate() {
+            match pos {
+                0 => f.ws().append_format(i),
+                _ => f.append_str(", ").append_format(i),
+            };
+        }
+        f
+    }
+}
+
+impl Format for TransactionMode {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::IsolationLevel(l) => f
+                .append(&Keyword::Isolation)
+                .ws()
+                .append(&Keyword::Level)
+                .ws()
+                .append_format(l),
+            Self::ReadOnly => f.append(&Keyword::Read).ws().append(&Keyword::Only),
+            Self::ReadWrite => f.append(&Keyword::Read).ws().append(&Keyword::Write),
+        }
+    }
+}
+
+impl Format for IsolationLevel {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::ReadUncommitted => f.append(&Keyword::Read).ws().append(&Keyword::Uncommitted),
+            Self::ReadCommitted => f.append(&Keyword::Read).ws().append(&Keyword::Committed),
+            Self::RepeatableRead => f.append(&Keyword::Repeatable).ws().append(&Keyword::Read),
+            Self::Serializable => f.append(&Keyword::Serializable),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
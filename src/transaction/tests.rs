@@ -0,0 +1,76 @@
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::transaction::parse_transaction_statement;
+use crate::transaction::IsolationLevel;
+use crate::transaction::TransactionMode;
+use crate::transaction::TransactionStatement;
+
+#[test]
+fn test_parse_begin() {
+    assert_eq!(
+        parse_transaction_statement("BEGIN"),
+        Ok(("", TransactionStatement::Begin(List(vec!()))))
+    );
+    assert_eq!(
+        parse_transaction_statement("BEGIN WORK"),
+        Ok(("", TransactionStatement::Begin(List(vec!()))))
+    )
+}
+
+#[test]
+fn test_parse_start_transaction() {
+    let input = "START TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY";
+    assert_eq!(
+        parse_transaction_statement(input),
+        Ok((
+            "",
+            TransactionStatement::StartTransaction(List(vec!(
+                TransactionMode::IsolationLevel(IsolationLevel::RepeatableRead),
+                TransactionMode::ReadOnly
+            )))
+        ))
+    )
+}
+
+#[test]
+fn test_parse_rollback_to_savepoint() {
+    assert_eq!(
+        parse_transaction_statement("ROLLBACK TO before_import"),
+        Ok((
+            "",
+            TransactionStatement::Rollback(Some(Name::Name("before_import".to_string())))
+        ))
+    )
+}
+
+#[test]
+fn test_format_transaction_statement() {
+    let cases = vec![
+        ("begin transaction", "BEGIN"),
+        (
+            "begin isolation level serializable",
+            "BEGIN ISOLATION LEVEL SERIALIZABLE",
+        ),
+        (
+            "start transaction read write isolation level read committed",
+            "START TRANSACTION READ WRITE, ISOLATION LEVEL READ COMMITTED",
+        ),
+        (
+            "set transaction isolation level read uncommitted",
+            "SET TRANSACTION ISOLATION LEVEL READ UNCOMMITTED",
+        ),
+        ("commit work", "COMMIT"),
+        ("rollback", "ROLLBACK"),
+        (
+            "rollback work to savepoint before_import",
+            "ROLLBACK TO SAVEPOINT before_import",
+        ),
+        ("savepoint before_import", "SAVEPOINT before_import"),
+        ("release before_import", "RELEASE SAVEPOINT before_import"),
+    ];
+    for (input, expected) in cases {
+        let (_, t) = parse_transaction_statement(input).unwrap();
+        assert_eq!(t.output(), expected)
+    }
+}
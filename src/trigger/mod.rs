@@ -0,0 +1,217 @@
+use crate::character::parse_comma;
+use crate::character::parse_left_parenthesis;
+use crate::character::parse_right_parenthesis;
+use crate::expression::condition::parse_expressions;
+use crate::expression::Expression;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::function::create::parse_routine_kind;
+use crate::function::create::RoutineKind;
+use crate::function::parse_function;
+use crate::function::Function;
+use crate::identifier::parse_name;
+use crate::identifier::Name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::table::create::parse_table_ref;
+use crate::table::create::TableRef;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::opt;
+use nom::combinator::value;
+use nom::multi::separated_list1;
+use nom::sequence::delimited;
+use nom::sequence::pair;
+use nom::sequence::preceded;
+use nom::sequence::tuple;
+use nom::IResult;
+
+// CREATE TRIGGER touch_movies BEFORE UPDATE ON movies FOR EACH ROW EXECUTE FUNCTION touch()
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CreateTriggerStatement(
+    pub bool,
+    pub Name,
+    pub TriggerTiming,
+    pub List<TriggerEvent>,
+    pub TableRef,
+    pub Option<TriggerLevel>,
+    pub Option<List<Expression>>,
+    pub RoutineKind,
+    pub Function,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerTiming {
+    Before,
+    After,
+    InsteadOf,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerEvent {
+    Insert,
+    Update(Option<List<Name>>),
+    Delete,
+    Truncate,
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerLevel {
+    Row,
+    Statement,
+}
+
+fn parse_trigger_timing(input: &str) -> IResult<&str, TriggerTiming> {
+    alt((
+        value(TriggerTiming::Before, parse_keyword(Keyword::Before)),
+        value(TriggerTiming::After, parse_keyword(Keyword::After)),
+        value(
+            TriggerTiming::InsteadOf,
+            pair(parse_keyword(Keyword::Instead), parse_keyword(Keyword::Of)),
+        ),
+    ))(input)
+}
+
+fn parse_trigger_event(input: &str) -> IResult<&str, TriggerEvent> {
+    alt((
+        value(TriggerEvent::Insert, parse_keyword(Keyword::Insert)),
+        map(
+            preceded(
+                parse_keyword(Keyword::Update),
+                opt(preceded(
+                    parse_keyword(Keyword::Of),
+                    map(separated_list1(parse_comma, ws(parse_name)), List),
+                )),
+            ),
+            TriggerEvent::Update,
+        ),
+        value(TriggerEvent::Delete, parse_keyword(Keyword::Delete)),
+        value(TriggerEvent::Truncate, parse_keyword(Keyword::Truncate)),
+    ))(input)
+}
+
+fn parse_trigger_level(input: &str) -> IResult<&str, TriggerLevel> {
+    preceded(
+        pair(
+            parse_keyword(Keyword::For),
+            opt(parse_keyword(Keyword::Each)),
+        ),
+        alt((
+            value(TriggerLevel::Row, parse_keyword(Keyword::Row)),
+            value(TriggerLevel::Statement, parse_keyword(Keyword::Statement)),
+        )),
+    )(input)
+}
+
+pub fn parse_create_trigger(input: &str) -> IResult<&str, CreateTriggerStatement> {
+    map(
+        tuple((
+            parse_keyword(Keyword::Create),
+            opt(pair(
+                parse_keyword(Keyword::Or),
+                parse_keyword(Keyword::Replace),
+            )),
+            parse_keyword(Keyword::Trigger),
+            ws(parse_name),
+            parse_trigger_timing,
+            separated_list1(parse_keyword(Keyword::Or), parse_trigger_event),
+            parse_keyword(Keyword::On),
+            ws(parse_table_ref),
+            opt(parse_trigger_level),
+            opt(preceded(
+                parse_keyword(Keyword::When),
+                delimited(
+                    parse_left_parenthesis,
+                    parse_expressions,
+                    parse_right_parenthesis,
+                ),
+            )),
+            parse_keyword(Keyword::Execute),
+            parse_routine_kind,
+            ws(parse_function),
+        )),
+        |(_, r, _, n, t, e, _, o, l, w, _, k, c)| {
+            CreateTriggerStatement(r.is_some(), n, t, List(e), o, l, w, k, c)
+        },
+    )(input)
+}
+
+impl Format for CreateTriggerStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::Create).ws();
+        if self.0 {
+            f.append(&Keyword::Or).ws().append(&Keyword::Replace).ws();
+        }
+        f.append(&Keyword::Trigger)
+            .ws()
+            .append(&self.1)
+            .new_line()
+            .append_format(&self.2);
+        for (pos, e) in self.3 .0.iter().enumerate() {
+            match pos {
+                0 => f.ws().append_format(e),
+                _ => f.ws().append(&Keyword::Or).ws().append_format(e),
+            };
+        }
+        f.ws().append(&Keyword::On).ws().append(&self.4);
+        if let Some(l) = &self.5 {
+            f.new_line().append_format(l);
+        }
+        if let Some(c) = &self.6 {
+            f.new_line().append(&Keyword::When).ws().append_str("(");
+            c.format_inline(f).append_str(")");
+        }
+        f.new_line()
+            .append(&Keyword::Execute)
+            .ws()
+            .append(&self.7.keyword())
+            .ws()
+            .append_format(&self.8)
+    }
+}
+
+impl Format for TriggerTiming {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Before => f.append(&Keyword::Before),
+            Self::After => f.append(&Keyword::After),
+            Self::InsteadOf => f.append(&Keyword::Instead).ws().append(&Keyword::Of),
+        }
+    }
+}
+
+impl Format for TriggerEvent {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Insert => f.append(&Keyword::Insert),
+            Self::Update(Some(c)) => f
+                .append(&Keyword::Update)
+                .ws()
+                .append(&Keyword::Of)
+                .ws()
+                .append(c),
+            Self::Update(None) => f.append(&Keyword::Update),
+            Self::Delete => f.append(&Keyword::Delete),
+            Self::Truncate => f.append(&Keyword::Truncate),
+        }
+    }
+}
+
+impl Format for TriggerLevel {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(&Keyword::For).ws().append(&Keyword::Each).ws();
+        match self {
+            Self::Row => f.append(&Keyword::Row),
+            Self::Statement => f.append(&Keyword::Statement),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
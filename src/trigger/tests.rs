@@ -0,0 +1,60 @@
+use crate::assert_format;
+use crate::formatter::Format;
+use crate::function::create::RoutineKind;
+use crate::function::Function;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::table::create::TableRef;
+use crate::trigger::parse_create_trigger;
+use crate::trigger::CreateTriggerStatement;
+use crate::trigger::TriggerEvent;
+use crate::trigger::TriggerLevel;
+use crate::trigger::TriggerTiming;
+
+#[test]
+fn test_parse_create_trigger() {
+    let input = "CREATE TRIGGER touch_movies AFTER INSERT OR UPDATE OF title ON movies FOR EACH ROW EXECUTE FUNCTION touch()";
+    assert_eq!(
+        parse_create_trigger(input),
+        Ok((
+            "",
+            CreateTriggerStatement(
+                false,
+                Name::Name("touch_movies".to_string()),
+                TriggerTiming::After,
+                List(vec!(
+                    TriggerEvent::Insert,
+                    TriggerEvent::Update(Some(List(vec!(Name::Name("title".to_string())))))
+                )),
+                TableRef(None, Name::Name("movies".to_string())),
+                Some(TriggerLevel::Row),
+                None,
+                RoutineKind::Function,
+                Function("touch".to_string(), List(vec!()))
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_create_trigger() {
+    assert_format!(
+        parse_create_trigger(
+            "create or replace trigger audit instead of delete on movies_view for statement execute procedure audit_delete()"
+        ),
+        "CREATE OR REPLACE TRIGGER audit
+INSTEAD OF DELETE ON movies_view
+FOR EACH STATEMENT
+EXECUTE PROCEDURE audit_delete()"
+    );
+    assert_format!(
+        parse_create_trigger(
+            "create trigger check_mark before update on marks for each row when (new.mark > 20) execute function reject()"
+        ),
+        "CREATE TRIGGER check_mark
+BEFORE UPDATE ON marks
+FOR EACH ROW
+WHEN (new.mark > 20)
+EXECUTE FUNCTION reject()"
+    );
+}
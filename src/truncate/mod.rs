@@ -0,0 +1,104 @@
+use crate::character::parse_comma;
+use crate::clause::Clause;
+use crate::formatter::Format;
+use crate::formatter::Formatter;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
+use crate::list::List;
+use crate::table::create::parse_table_ref;
+use crate::table::create::TableRef;
+use crate::table::drop_table::parse_drop_behavior;
+use crate::table::drop_table::DropBehavior;
+use crate::ws::ws;
+use nom::branch::alt;
+use nom::combinator::map;
+use nom::combinator::opt;
+use nom::combinator::value;
+use nom::multi::separated_list1;
+use nom::sequence::pair;
+use nom::sequence::tuple;
+use nom::IResult;
+
+// TRUNCATE movies, ratings RESTART IDENTITY CASCADE
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TruncateStatement(
+    pub List<TableRef>,
+    pub Option<IdentityOption>,
+    pub Option<DropBehavior>,
+);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IdentityOption {
+    Restart,
+    Continue,
+}
+
+impl Clause for TruncateStatement {
+    const KEYWORD: &'static Keyword = &Keyword::Truncate;
+}
+
+fn parse_identity_option(input: &str) -> IResult<&str, IdentityOption> {
+    alt((
+        value(
+            IdentityOption::Restart,
+            pair(
+                parse_keyword(Keyword::Restart),
+                parse_keyword(Keyword::Identity),
+            ),
+        ),
+        value(
+            IdentityOption::Continue,
+            pair(
+                parse_keyword(Keyword::Continue),
+                parse_keyword(Keyword::Identity),
+            ),
+        ),
+    ))(input)
+}
+
+pub fn parse_truncate_statement(input: &str) -> IResult<&str, TruncateStatement> {
+    map(
+        tuple((
+            TruncateStatement::parse_keyword,
+            opt(parse_keyword(Keyword::Table)),
+            separated_list1(parse_comma, ws(parse_table_ref)),
+            opt(parse_identity_option),
+            opt(parse_drop_behavior),
+        )),
+        |(_, _, t, i, b)| TruncateStatement(List(t), i, b),
+    )(input)
+}
+
+impl Format for TruncateStatement {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        f.append(self.keyword()).ws();
+        for (pos, t) in self.0 .0.iter().enumerate() {
+            match pos {
+                0 => f.append(t),
+                _ => f.append_str(", ").append(t),
+            };
+        }
+        match &self.1 {
+            Some(IdentityOption::Restart) => f
+                .ws()
+                .append(&Keyword::Restart)
+                .ws()
+                .append(&Keyword::Identity),
+            Some(IdentityOption::Continue) => f
+                .ws()
+                .append(&Keyword::Continue)
+                .ws()
+                .append(&Keyword::Identity),
+            None => f,
+        };
+        match &self.2 {
+            Some(b) => f.ws().append(b),
+            None => f,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,39 @@
+use crate::assert_format;
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::list::List;
+use crate::table::create::TableRef;
+use crate::table::drop_table::DropBehavior;
+use crate::truncate::parse_truncate_statement;
+use crate::truncate::IdentityOption;
+use crate::truncate::TruncateStatement;
+
+#[test]
+fn test_parse_truncate_statement() {
+    assert_eq!(
+        parse_truncate_statement("TRUNCATE TABLE movies, ratings RESTART IDENTITY CASCADE"),
+        Ok((
+            "",
+            TruncateStatement(
+                List(vec!(
+                    TableRef(None, Name::Name("movies".to_string())),
+                    TableRef(None, Name::Name("ratings".to_string()))
+                )),
+                Some(IdentityOption::Restart),
+                Some(DropBehavior::Cascade)
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_format_truncate_statement() {
+    assert_format!(
+        parse_truncate_statement("truncate table movies"),
+        "TRUNCATE movies"
+    );
+    assert_format!(
+        parse_truncate_statement("truncate movies continue identity restrict"),
+        "TRUNCATE movies CONTINUE IDENTITY RESTRICT"
+    );
+}
@@ -4,16 +4,21 @@ mod tests;
 use crate::character::parse_comma;
 use crate::character::parse_left_parenthesis;
 use crate::character::parse_right_parenthesis;
+use crate::data_type::parse_data_type;
 use crate::data_type::parse_predefined_type;
+use crate::data_type::DataType;
 use crate::data_type::PredefinedType;
 use crate::formatter::Format;
 use crate::formatter::Formatter;
+use crate::identifier::parse_name;
 use crate::identifier::parse_schema_qualified_name;
 use crate::identifier::SchemaQualifiedName;
 use crate::keyword::parse_keyword;
 use crate::keyword::Keyword;
 use crate::list::List;
 use crate::term::parse_term;
+use crate::ws::ws;
+use nom::branch::alt;
 use nom::combinator::map;
 use nom::combinator::opt;
 use nom::multi::separated_list1;
@@ -21,8 +26,10 @@ use nom::sequence::delimited;
 use nom::sequence::pair;
 use nom::sequence::tuple;
 use nom::IResult;
+use pad::PadStr;
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserDefinedTypeDefinition(
     pub SchemaQualifiedName,
     pub Option<PredefinedType>,
@@ -36,8 +43,10 @@ pub fn parse_representation(input: &str) -> IResult<&str, PredefinedType> {
     )(input)
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct Member(pub String);
+// Either an enum label or a composite attribute with its data type
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Member(pub String, pub Option<DataType>);
 
 pub fn parse_user_defined_type_definition(input: &str) -> IResult<&str, UserDefinedTypeDefinition> {
     map(
@@ -46,23 +55,47 @@ pub fn parse_user_defined_type_definition(input: &str) -> IResult<&str, UserDefi
             parse_keyword(Keyword::Type),
             parse_schema_qualified_name,
             opt(parse_representation),
+            opt(parse_keyword(Keyword::As)),
             delimited(
                 parse_left_parenthesis,
                 separated_list1(parse_comma, parse_member),
                 parse_right_parenthesis,
             ),
         )),
-        |(_, _, n, t, l)| UserDefinedTypeDefinition(n, t, List(l)),
+        |(_, _, n, t, _, l)| UserDefinedTypeDefinition(n, t, List(l)),
     )(input)
 }
 
 pub fn parse_member(input: &str) -> IResult<&str, Member> {
-    map(parse_term, |t| Member(t.output()))(input)
+    alt((
+        map(pair(ws(parse_name), parse_data_type), |(n, t)| {
+            Member(n.to_string(), Some(t))
+        }),
+        map(parse_term, |t| Member(t.output(), None)),
+    ))(input)
 }
 
 impl Format for Member {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        f.append(&self.0)
+        f.append(&self.0);
+        match &self.1 {
+            Some(t) => f.ws().append_format(t),
+            None => f,
+        }
+    }
+}
+
+struct AlignedMember<'a>(&'a Member, usize);
+
+impl Format for AlignedMember<'_> {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match &self.0 .1 {
+            Some(t) => f
+                .append_str(&self.0 .0.pad_to_width(self.1))
+                .ws()
+                .append_format(t),
+            None => f.append_format(self.0),
+        }
     }
 }
 
@@ -73,16 +106,26 @@ impl Format for UserDefinedTypeDefinition {
             .append(&Keyword::Type)
             .ws()
             .append_format(&self.0);
+        let composite = self.2 .0.iter().any(|m| m.1.is_some());
         match &self.1 {
             Some(s) => f.ws().append(&Keyword::As).ws().append_format(s),
+            None if composite => f.ws().append(&Keyword::As),
             None => f,
         };
         f.ws().append_str("(");
         f.set_pad(4);
+        let width = match f.options.align_columns {
+            true => self.2 .0.iter().map(|m| m.0.chars().count()).max(),
+            false => None,
+        };
         for (pos, p) in self.2 .0.iter().enumerate() {
             match pos {
-                0 => f.new_line().indent(p),
-                _ => f.append_str(",").new_line().indent(p),
+                0 => f.new_line(),
+                _ => f.append_str(",").new_line(),
+            };
+            match width {
+                Some(w) => f.indent(&AlignedMember(p, w)),
+                None => f.indent(p),
             };
         }
         f.new_line().append_str(")")
@@ -1,5 +1,6 @@
 use crate::data_type::PredefinedType;
 use crate::formatter::Format;
+use crate::formatter::FormatOptions;
 use crate::identifier::Name;
 use crate::identifier::SchemaQualifiedName;
 use crate::list::List;
@@ -18,9 +19,9 @@ fn test_parse_user_defined_type_definition() {
                 SchemaQualifiedName(None, Name::Name("status".to_string())),
                 Some(PredefinedType::Enum),
                 List(vec!(
-                    Member("'beta'".to_string()),
-                    Member("'deprecated'".to_string()),
-                    Member("'stable'".to_string())
+                    Member("'beta'".to_string(), None),
+                    Member("'deprecated'".to_string(), None),
+                    Member("'stable'".to_string(), None)
                 )),
             )
         ))
@@ -33,6 +34,23 @@ fn test_format_user_defined_type_definition_as() {
     let (_, t) = parse_user_defined_type_definition(input).unwrap();
     assert_eq!(
         t.output(),
-        "CREATE TYPE 'status' AS ENUM (\n    'beta',\n    'deprecated',\n    'stable'\n)"
+        "CREATE TYPE status AS ENUM (\n    'beta',\n    'deprecated',\n    'stable'\n)"
+    )
+}
+
+#[test]
+fn test_format_composite_type_aligned() {
+    let input = "CREATE TYPE address AS (street TEXT, postal_code VARCHAR(10))";
+    let (_, t) = parse_user_defined_type_definition(input).unwrap();
+    assert_eq!(
+        t.output(),
+        "CREATE TYPE address AS (\n    street TEXT,\n    postal_code VARCHAR(10)\n)"
+    );
+    assert_eq!(
+        t.output_with(FormatOptions {
+            align_columns: true,
+            ..FormatOptions::default()
+        }),
+        "CREATE TYPE address AS (\n    street      TEXT,\n    postal_code VARCHAR(10)\n)"
     )
 }
@@ -11,19 +11,53 @@ use crate::formatter::Formatter;
 use crate::identifier::Name;
 use crate::keyword::Keyword;
 use crate::list::List;
+use crate::returning::ReturningClause;
+use crate::select::FromClause;
+use crate::select::SelectStatement;
 use crate::table::create::TableRef;
+use crate::term::column::ColumnRef;
+use pad::PadStr;
+use std::ops::Deref;
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct UpdateStatement(pub UpdateClause, pub SetClause, pub Option<WhereClause>);
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateStatement(
+    pub UpdateClause,
+    pub SetClause,
+    pub Option<FromClause>,
+    pub Option<WhereClause>,
+    pub Option<ReturningClause>,
+);
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct UpdateClause(pub TableRef);
+// UPDATE movies AS m
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateClause(pub TableRef, pub Option<Name>);
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetClause(pub List<SetExpression>);
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct SetExpression(pub Name, pub Expression);
+// (title, year) = (SELECT title, year FROM drafts)
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetExpression(pub SetTarget, pub SetValue);
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SetTarget {
+    Column(ColumnRef),
+    Columns(List<ColumnRef>),
+}
+
+#[derive(Debug, PartialEq, Clone, Node)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SetValue {
+    Default,
+    Expression(Expression),
+    Subquery(Box<SelectStatement>),
+    Row(List<SetValue>),
+}
 
 impl Clause for UpdateClause {
     const KEYWORD: &'static Keyword = &Keyword::Update;
@@ -40,20 +74,45 @@ impl Format for UpdateStatement {
         match &self.2 {
             Some(i) => f.append_clause(i),
             None => f,
+        };
+        match &self.3 {
+            Some(i) => f.append_clause(i),
+            None => f,
+        };
+        match &self.4 {
+            Some(i) => f.append_clause(i),
+            None => f,
         }
     }
 }
 
 impl Format for UpdateClause {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
-        f.append(self.keyword()).space().append(&self.0)
+        f.append(self.keyword()).space().append(&self.0);
+        match &self.1 {
+            Some(a) => f.space().append(&Keyword::As).space().append(a),
+            None => f,
+        }
     }
 }
 
 impl Format for SetClause {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        let width = match f.options.align_columns {
+            true => self
+                .0
+                 .0
+                .iter()
+                .map(|e| f.render(&e.0).chars().count())
+                .max(),
+            false => None,
+        };
         f.append_with_padding(self.keyword()).space();
         for (pos, e) in self.0 .0.iter().enumerate() {
+            let e: &dyn Format = match width {
+                Some(w) => &AlignedSetExpression(e, w),
+                None => e,
+            };
             match pos {
                 0 => f.append_format(e),
                 _ => f.append_str(",").new_line().right_side(e),
@@ -63,6 +122,19 @@ impl Format for SetClause {
     }
 }
 
+struct AlignedSetExpression<'a>(&'a SetExpression, usize);
+
+impl Format for AlignedSetExpression<'_> {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        let target = f.render(&self.0 .0).pad_to_width(self.1);
+        f.append_str(&target)
+            .ws()
+            .append_str("=")
+            .ws()
+            .append_format(&self.0 .1)
+    }
+}
+
 impl Format for SetExpression {
     fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
         f.append_format(&self.0)
@@ -72,3 +144,32 @@ impl Format for SetExpression {
             .append_format(&self.1)
     }
 }
+
+impl Format for SetTarget {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Column(c) => f.append(c),
+            Self::Columns(l) => f.append_str("(").append_format(l).append_str(")"),
+        }
+    }
+}
+
+impl Format for SetValue {
+    fn format<'a>(&self, f: &'a mut Formatter) -> &'a mut Formatter {
+        match self {
+            Self::Default => f.append(&Keyword::Default),
+            Self::Expression(e) => f.append_format(e),
+            Self::Subquery(s) => f.append_str("(").append_indented(s.deref()).append_str(")"),
+            Self::Row(l) => {
+                f.append_str("(");
+                for (pos, v) in l.0.iter().enumerate() {
+                    match pos {
+                        0 => f.append_format(v),
+                        _ => f.append_str(", ").append_format(v),
+                    };
+                }
+                f.append_str(")")
+            }
+        }
+    }
+}
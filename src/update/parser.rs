@@ -1,19 +1,34 @@
+use crate::character::parse_comma;
+use crate::character::parse_left_parenthesis;
+use crate::character::parse_right_parenthesis;
 use crate::clause::Clause;
 use crate::expression::parse_expression;
 use crate::expression::parse_where_clause;
 use crate::identifier::parse_name;
+use crate::keyword::parse_keyword;
+use crate::keyword::Keyword;
 use crate::list::List;
+use crate::returning::parse_returning_clause;
+use crate::select::parse_from_clause;
+use crate::select::parse_select_statement;
 use crate::table::create::parse_table_ref;
+use crate::term::column::parse_column_ref;
 use crate::update::SetClause;
 use crate::update::SetExpression;
+use crate::update::SetTarget;
+use crate::update::SetValue;
 use crate::update::UpdateClause;
 use crate::update::UpdateStatement;
 use crate::ws::ws;
+use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::combinator::map;
 use nom::combinator::opt;
+use nom::combinator::value;
 use nom::multi::separated_list1;
+use nom::sequence::delimited;
 use nom::sequence::pair;
+use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -22,23 +37,66 @@ pub fn parse_update_statement(input: &str) -> IResult<&str, UpdateStatement> {
         tuple((
             parse_update_clause,
             parse_set_clause,
+            opt(parse_from_clause),
             opt(parse_where_clause),
+            opt(parse_returning_clause),
         )),
-        |(u, s, w)| UpdateStatement(u, s, w),
+        |(u, s, f, w, r)| UpdateStatement(u, s, f, w, r),
     )(input)
 }
 
 pub fn parse_set_expression(input: &str) -> IResult<&str, SetExpression> {
     map(
-        tuple((ws(parse_name), ws(tag("=")), ws(parse_expression))),
-        |(n, _, e)| SetExpression(n, e),
+        tuple((ws(parse_set_target), ws(tag("=")), ws(parse_set_value))),
+        |(t, _, v)| SetExpression(t, v),
     )(input)
 }
 
+pub fn parse_set_target(input: &str) -> IResult<&str, SetTarget> {
+    alt((
+        map(
+            delimited(
+                parse_left_parenthesis,
+                separated_list1(parse_comma, ws(parse_column_ref)),
+                parse_right_parenthesis,
+            ),
+            |l| SetTarget::Columns(List(l)),
+        ),
+        map(parse_column_ref, SetTarget::Column),
+    ))(input)
+}
+
+pub fn parse_set_value(input: &str) -> IResult<&str, SetValue> {
+    alt((
+        value(SetValue::Default, parse_keyword(Keyword::Default)),
+        map(
+            delimited(
+                parse_left_parenthesis,
+                parse_select_statement,
+                parse_right_parenthesis,
+            ),
+            |s| SetValue::Subquery(Box::new(s)),
+        ),
+        map(parse_expression, SetValue::Expression),
+        map(
+            delimited(
+                parse_left_parenthesis,
+                separated_list1(parse_comma, ws(parse_set_value)),
+                parse_right_parenthesis,
+            ),
+            |l| SetValue::Row(List(l)),
+        ),
+    ))(input)
+}
+
 pub fn parse_update_clause(input: &str) -> IResult<&str, UpdateClause> {
     map(
-        pair(UpdateClause::parse_keyword, ws(parse_table_ref)),
-        |(_, t)| UpdateClause(t),
+        tuple((
+            UpdateClause::parse_keyword,
+            ws(parse_table_ref),
+            opt(preceded(opt(parse_keyword(Keyword::As)), ws(parse_name))),
+        )),
+        |(_, t, a)| UpdateClause(t, a),
     )(input)
 }
 
@@ -5,6 +5,7 @@ use crate::expression::Operand;
 use crate::expression::RightOperand;
 use crate::expression::WhereClause;
 use crate::formatter::Format;
+use crate::formatter::FormatOptions;
 use crate::identifier::Name;
 use crate::list::List;
 use crate::numeric::Numeric;
@@ -15,6 +16,8 @@ use crate::term::Term;
 use crate::update::parse_update_statement;
 use crate::update::SetClause;
 use crate::update::SetExpression;
+use crate::update::SetTarget;
+use crate::update::SetValue;
 use crate::update::UpdateClause;
 use crate::update::UpdateStatement;
 
@@ -26,13 +29,15 @@ fn test_update() {
         Ok((
             "",
             UpdateStatement(
-                UpdateClause(TableRef(None, Name::Name(String::from("movies")))),
+                UpdateClause(TableRef(None, Name::Name(String::from("movies"))), None),
                 SetClause(List(vec!(SetExpression(
-                    Name::Name("description".to_string()),
-                    Expression::Condition(Condition::Operand(Operand::Term(Term::Value(
-                        Value::String("".to_string())
+                    SetTarget::Column(ColumnRef::Name(Name::Name("description".to_string()))),
+                    SetValue::Expression(Expression::Condition(Condition::Operand(Operand::Term(
+                        Term::Value(Value::String("".to_string()))
                     ))))
                 )))),
+                None,
+                None,
                 None
             )
         ))
@@ -47,21 +52,24 @@ fn test_update_variant() {
         Ok((
             "",
             UpdateStatement(
-                UpdateClause(TableRef(None, Name::Name(String::from("movies")))),
+                UpdateClause(TableRef(None, Name::Name(String::from("movies"))), None),
                 SetClause(List(vec!(
                     SetExpression(
-                        Name::Name("title".to_string()),
-                        Expression::Condition(Condition::Operand(Operand::Term(Term::Value(
-                            Value::String("Kaamelott".to_string())
-                        ))))
+                        SetTarget::Column(ColumnRef::Name(Name::Name("title".to_string()))),
+                        SetValue::Expression(Expression::Condition(Condition::Operand(
+                            Operand::Term(Term::Value(Value::String("Kaamelott".to_string())))
+                        )))
                     ),
                     SetExpression(
-                        Name::Name("description".to_string()),
-                        Expression::Condition(Condition::Operand(Operand::Term(Term::Value(
-                            Value::String("il revient pas pour trier les lentilles".to_string())
-                        ))))
+                        SetTarget::Column(ColumnRef::Name(Name::Name("description".to_string()))),
+                        SetValue::Expression(Expression::Condition(Condition::Operand(
+                            Operand::Term(Term::Value(Value::String(
+                                "il revient pas pour trier les lentilles".to_string()
+                            )))
+                        )))
                     )
                 ))),
+                None,
                 Some(WhereClause(List(vec!(Expression::Condition(
                     Condition::BinaryExpression(
                         Operand::Term(Term::ColumnRef(ColumnRef::Name(Name::Name(
@@ -72,7 +80,8 @@ fn test_update_variant() {
                             Operand::Term(Term::Value(Value::Num(Numeric::Int(3))))
                         )
                     )
-                )))))
+                ))))),
+                None
             )
         ))
     )
@@ -86,13 +95,14 @@ fn test_update_when() {
         Ok((
             "",
             UpdateStatement(
-                UpdateClause(TableRef(None, Name::Name(String::from("movies")))),
+                UpdateClause(TableRef(None, Name::Name(String::from("movies"))), None),
                 SetClause(List(vec!(SetExpression(
-                    Name::Name("title".to_string()),
-                    Expression::Condition(Condition::Operand(Operand::Term(Term::Value(
-                        Value::String("Coup de tête".to_string())
+                    SetTarget::Column(ColumnRef::Name(Name::Name("title".to_string()))),
+                    SetValue::Expression(Expression::Condition(Condition::Operand(Operand::Term(
+                        Term::Value(Value::String("Coup de tête".to_string()))
                     ))))
                 )))),
+                None,
                 Some(WhereClause(List(vec!(Expression::Condition(
                     Condition::BinaryExpression(
                         Operand::Term(Term::ColumnRef(ColumnRef::Name(Name::Name(
@@ -103,7 +113,8 @@ fn test_update_when() {
                             Operand::Term(Term::Value(Value::Num(Numeric::Int(1))))
                         )
                     )
-                )))))
+                ))))),
+                None
             )
         ))
     )
@@ -125,3 +136,86 @@ fn test_format_update_statement_where() {
         "UPDATE movies\n   SET description = '',\n       title = 'Matrix'\n WHERE id = 5"
     )
 }
+
+#[test]
+fn test_update_alias_qualified_default() {
+    let input = "UPDATE movies m SET m.year = DEFAULT";
+    assert_eq!(
+        parse_update_statement(input),
+        Ok((
+            "",
+            UpdateStatement(
+                UpdateClause(
+                    TableRef(None, Name::Name(String::from("movies"))),
+                    Some(Name::Name("m".to_string()))
+                ),
+                SetClause(List(vec!(SetExpression(
+                    SetTarget::Column(ColumnRef::WithFamily(
+                        Name::Name("m".to_string()),
+                        Name::Name("year".to_string())
+                    )),
+                    SetValue::Default
+                )))),
+                None,
+                None,
+                None
+            )
+        ))
+    )
+}
+
+#[test]
+fn test_update_multi_column_row() {
+    let input = "UPDATE movies SET (title, year) = ('Brazil', 1985)";
+    let (_, t) = parse_update_statement(input).unwrap();
+    assert_eq!(
+        t.1,
+        SetClause(List(vec!(SetExpression(
+            SetTarget::Columns(List(vec!(
+                ColumnRef::Name(Name::Name("title".to_string())),
+                ColumnRef::Name(Name::Name("year".to_string()))
+            ))),
+            SetValue::Row(List(vec!(
+                SetValue::Expression(Expression::Condition(Condition::Operand(Operand::Term(
+                    Term::Value(Value::String("Brazil".to_string()))
+                )))),
+                SetValue::Expression(Expression::Condition(Condition::Operand(Operand::Term(
+                    Term::Value(Value::Num(Numeric::Int(1985)))
+                ))))
+            )))
+        ))))
+    )
+}
+
+#[test]
+fn test_format_update_statement_from_returning() {
+    let input = "update movies as m set m.title = d.title, year = default from drafts d where m.id = d.id returning m.id";
+    let (_, t) = parse_update_statement(input).unwrap();
+    assert_eq!(
+        t.output(),
+        "UPDATE movies AS m\n   SET m.title = d.title,\n       year = DEFAULT\n  FROM drafts AS d\n WHERE m.id = d.id\nRETURNING m.id"
+    )
+}
+
+#[test]
+fn test_format_update_statement_subquery() {
+    let input = "UPDATE movies SET (rating, votes) = (SELECT avg(score), count(score) FROM reviews WHERE reviews.movie_id = movies.id)";
+    let (_, t) = parse_update_statement(input).unwrap();
+    assert_eq!(
+        t.output(),
+        "UPDATE movies\n   SET (rating, votes) = (SELECT avg(score), count(score)\n                            FROM reviews\n                           WHERE reviews.movie_id = movies.id)"
+    )
+}
+
+#[test]
+fn test_format_update_statement_aligned() {
+    let input = "UPDATE movies SET title = 'Matrix', release_year = 1999 WHERE id = 5";
+    let (_, t) = parse_update_statement(input).unwrap();
+    assert_eq!(
+        t.output_with(FormatOptions {
+            align_columns: true,
+            ..FormatOptions::default()
+        }),
+        "UPDATE movies\n   SET title        = 'Matrix',\n       release_year = 1999\n WHERE id = 5"
+    )
+}
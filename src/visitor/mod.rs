@@ -0,0 +1,284 @@
+//! Walk the AST with `Visit` and `VisitMut`.
+//!
+//! Every method defaults to visiting the children of its node: override the ones you
+//! need and call `node.walk(self)` (or `node.walk_mut(self)`) to keep descending.
+use crate::list::List;
+
+pub trait Node {
+    fn accept<V: Visit + ?Sized>(&self, visitor: &mut V);
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V);
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V);
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V);
+}
+
+macro_rules! visitor {
+    ($($visit:ident, $visit_mut:ident: $node:path;)*) => {
+        pub trait Visit {
+            $(
+                fn $visit(&mut self, node: &$node) {
+                    node.walk(self)
+                }
+            )*
+        }
+
+        pub trait VisitMut {
+            $(
+                fn $visit_mut(&mut self, node: &mut $node) {
+                    node.walk_mut(self)
+                }
+            )*
+        }
+    };
+}
+
+// One line per type deriving `Node`.
+visitor! {
+    visit_alter_object_statement, visit_alter_object_statement_mut: crate::alter::AlterObjectStatement;
+    visit_alter_object_action, visit_alter_object_action_mut: crate::alter::AlterObjectAction;
+    visit_comment_on_statement, visit_comment_on_statement_mut: crate::comment::on::CommentOnStatement;
+    visit_comment_target, visit_comment_target_mut: crate::comment::on::CommentTarget;
+    visit_predefined_type, visit_predefined_type_mut: crate::data_type::PredefinedType;
+    visit_date_time_type, visit_date_time_type_mut: crate::data_type::DateTimeType;
+    visit_data_type, visit_data_type_mut: crate::data_type::DataType;
+    visit_date_time_option, visit_date_time_option_mut: crate::data_type::DateTimeOption;
+    visit_create_domain_statement, visit_create_domain_statement_mut: crate::domain::CreateDomainStatement;
+    visit_domain_constraint, visit_domain_constraint_mut: crate::domain::DomainConstraint;
+    visit_domain_constraint_kind, visit_domain_constraint_kind_mut: crate::domain::DomainConstraintKind;
+    visit_condition, visit_condition_mut: crate::expression::condition::Condition;
+    visit_operand, visit_operand_mut: crate::expression::condition::Operand;
+    visit_right_operand, visit_right_operand_mut: crate::expression::condition::RightOperand;
+    visit_in_predicate_value, visit_in_predicate_value_mut: crate::expression::condition::InPredicateValue;
+    visit_compare, visit_compare_mut: crate::expression::condition::Compare;
+    visit_expression, visit_expression_mut: crate::expression::condition::Expression;
+    visit_where_clause, visit_where_clause_mut: crate::expression::condition::WhereClause;
+    visit_extension_statement, visit_extension_statement_mut: crate::extension::ExtensionStatement;
+    visit_extension_option, visit_extension_option_mut: crate::extension::ExtensionOption;
+    visit_create_function_statement, visit_create_function_statement_mut: crate::function::create::CreateFunctionStatement;
+    visit_routine_kind, visit_routine_kind_mut: crate::function::create::RoutineKind;
+    visit_function_parameter, visit_function_parameter_mut: crate::function::create::FunctionParameter;
+    visit_parameter_mode, visit_parameter_mode_mut: crate::function::create::ParameterMode;
+    visit_return_type, visit_return_type_mut: crate::function::create::ReturnType;
+    visit_routine_option, visit_routine_option_mut: crate::function::create::RoutineOption;
+    visit_volatility, visit_volatility_mut: crate::function::create::Volatility;
+    visit_security, visit_security_mut: crate::function::create::Security;
+    visit_routine_body, visit_routine_body_mut: crate::function::create::RoutineBody;
+    visit_function, visit_function_mut: crate::function::Function;
+    visit_aggregate_function, visit_aggregate_function_mut: crate::function::AggregateFunction;
+    visit_window_specification, visit_window_specification_mut: crate::function::WindowSpecification;
+    visit_name, visit_name_mut: crate::identifier::Name;
+    visit_schema_qualified_name, visit_schema_qualified_name_mut: crate::identifier::SchemaQualifiedName;
+    visit_delimitedidentifier, visit_delimitedidentifier_mut: crate::identifier::Delimitedidentifier;
+    visit_insert_statement, visit_insert_statement_mut: crate::insert::InsertStatement;
+    visit_insert_into_clause, visit_insert_into_clause_mut: crate::insert::InsertIntoClause;
+    visit_insert_source, visit_insert_source_mut: crate::insert::InsertSource;
+    visit_values_clause, visit_values_clause_mut: crate::insert::ValuesClause;
+    visit_insert_value, visit_insert_value_mut: crate::insert::InsertValue;
+    visit_on_conflict_clause, visit_on_conflict_clause_mut: crate::insert::OnConflictClause;
+    visit_conflict_target, visit_conflict_target_mut: crate::insert::ConflictTarget;
+    visit_conflict_action, visit_conflict_action_mut: crate::insert::ConflictAction;
+    visit_merge_statement, visit_merge_statement_mut: crate::merge::MergeStatement;
+    visit_merge_into_clause, visit_merge_into_clause_mut: crate::merge::MergeIntoClause;
+    visit_merge_using_clause, visit_merge_using_clause_mut: crate::merge::MergeUsingClause;
+    visit_merge_source, visit_merge_source_mut: crate::merge::MergeSource;
+    visit_merge_when_clause, visit_merge_when_clause_mut: crate::merge::MergeWhenClause;
+    visit_matched_action, visit_matched_action_mut: crate::merge::MatchedAction;
+    visit_not_matched_action, visit_not_matched_action_mut: crate::merge::NotMatchedAction;
+    visit_numeric, visit_numeric_mut: crate::numeric::Numeric;
+    visit_privilege_statement, visit_privilege_statement_mut: crate::privilege::PrivilegeStatement;
+    visit_grant_statement, visit_grant_statement_mut: crate::privilege::GrantStatement;
+    visit_revoke_statement, visit_revoke_statement_mut: crate::privilege::RevokeStatement;
+    visit_grant_role_statement, visit_grant_role_statement_mut: crate::privilege::GrantRoleStatement;
+    visit_revoke_role_statement, visit_revoke_role_statement_mut: crate::privilege::RevokeRoleStatement;
+    visit_alter_default_privileges, visit_alter_default_privileges_mut: crate::privilege::AlterDefaultPrivileges;
+    visit_privileges, visit_privileges_mut: crate::privilege::Privileges;
+    visit_privilege, visit_privilege_mut: crate::privilege::Privilege;
+    visit_object_type, visit_object_type_mut: crate::privilege::ObjectType;
+    visit_privilege_object, visit_privilege_object_mut: crate::privilege::PrivilegeObject;
+    visit_grantee, visit_grantee_mut: crate::privilege::Grantee;
+    visit_role_statement, visit_role_statement_mut: crate::privilege::role::RoleStatement;
+    visit_role_kind, visit_role_kind_mut: crate::privilege::role::RoleKind;
+    visit_role_option, visit_role_option_mut: crate::privilege::role::RoleOption;
+    visit_role_flag, visit_role_flag_mut: crate::privilege::role::RoleFlag;
+    visit_statement, visit_statement_mut: crate::query::Statement;
+    visit_query, visit_query_mut: crate::query::Query;
+    visit_returning_clause, visit_returning_clause_mut: crate::returning::ReturningClause;
+    visit_schema_statement, visit_schema_statement_mut: crate::schema::SchemaStatement;
+    visit_select_clause, visit_select_clause_mut: crate::select::clause::SelectClause;
+    visit_selected_expression, visit_selected_expression_mut: crate::select::clause::SelectedExpression;
+    visit_set_quantifier, visit_set_quantifier_mut: crate::select::clause::SetQuantifier;
+    visit_from_clause, visit_from_clause_mut: crate::select::from::FromClause;
+    visit_table_name, visit_table_name_mut: crate::select::from::TableName;
+    visit_table_expression, visit_table_expression_mut: crate::select::from::TableExpression;
+    visit_group_by_clause, visit_group_by_clause_mut: crate::select::group::GroupByClause;
+    visit_grouping_element, visit_grouping_element_mut: crate::select::group::GroupingElement;
+    visit_having_clause, visit_having_clause_mut: crate::select::group::HavingClause;
+    visit_join_clause, visit_join_clause_mut: crate::select::join::JoinClause;
+    visit_outer_join_type, visit_outer_join_type_mut: crate::select::join::OuterJoinType;
+    visit_outer_join, visit_outer_join_mut: crate::select::join::OuterJoin;
+    visit_join_type, visit_join_type_mut: crate::select::join::JoinType;
+    visit_join_specification, visit_join_specification_mut: crate::select::join::JoinSpecification;
+    visit_limit_clause, visit_limit_clause_mut: crate::select::limit::LimitClause;
+    visit_offset, visit_offset_mut: crate::select::limit::Offset;
+    visit_fetch, visit_fetch_mut: crate::select::limit::Fetch;
+    visit_top, visit_top_mut: crate::select::limit::Top;
+    visit_order_by_clause, visit_order_by_clause_mut: crate::select::order::OrderByClause;
+    visit_order, visit_order_mut: crate::select::order::Order;
+    visit_order_sort, visit_order_sort_mut: crate::select::order::OrderSort;
+    visit_sort_key, visit_sort_key_mut: crate::select::order::SortKey;
+    visit_nulls_sort, visit_nulls_sort_mut: crate::select::order::NullsSort;
+    visit_select_statement, visit_select_statement_mut: crate::select::statement::SelectStatement;
+    visit_table_operator, visit_table_operator_mut: crate::select::table_operator::TableOperator;
+    visit_query_term, visit_query_term_mut: crate::select::table_operator::QueryTerm;
+    visit_combined_tables, visit_combined_tables_mut: crate::select::table_operator::CombinedTables;
+    visit_corresponding, visit_corresponding_mut: crate::select::table_operator::Corresponding;
+    visit_sequence, visit_sequence_mut: crate::sequence::Sequence;
+    visit_alter_sequence, visit_alter_sequence_mut: crate::sequence::AlterSequence;
+    visit_drop_sequence, visit_drop_sequence_mut: crate::sequence::DropSequence;
+    visit_sequence_generator_option, visit_sequence_generator_option_mut: crate::sequence::SequenceGeneratorOption;
+    visit_set_statement, visit_set_statement_mut: crate::set::SetStatement;
+    visit_set_scope, visit_set_scope_mut: crate::set::SetScope;
+    visit_set_operator, visit_set_operator_mut: crate::set::SetOperator;
+    visit_configuration_value, visit_configuration_value_mut: crate::set::ConfigurationValue;
+    visit_reset_statement, visit_reset_statement_mut: crate::set::ResetStatement;
+    visit_alter_table, visit_alter_table_mut: crate::table::alter::AlterTable;
+    visit_alter_table_action, visit_alter_table_action_mut: crate::table::alter::AlterTableAction;
+    visit_alter_column_action, visit_alter_column_action_mut: crate::table::alter::AlterColumnAction;
+    visit_referential_triggered_action, visit_referential_triggered_action_mut: crate::table::alter::ReferentialTriggeredAction;
+    visit_referential_action, visit_referential_action_mut: crate::table::alter::ReferentialAction;
+    visit_unique_specification, visit_unique_specification_mut: crate::table::constraint::UniqueSpecification;
+    visit_table_constraint_definition, visit_table_constraint_definition_mut: crate::table::constraint::TableConstraintDefinition;
+    visit_unique_constraint_definition, visit_unique_constraint_definition_mut: crate::table::constraint::UniqueConstraintDefinition;
+    visit_referential_constraint_definition, visit_referential_constraint_definition_mut: crate::table::constraint::ReferentialConstraintDefinition;
+    visit_references_specification, visit_references_specification_mut: crate::table::constraint::ReferencesSpecification;
+    visit_table_constraint, visit_table_constraint_mut: crate::table::constraint::TableConstraint;
+    visit_constraint_name_definition, visit_constraint_name_definition_mut: crate::table::constraint::ConstraintNameDefinition;
+    visit_column_constraint, visit_column_constraint_mut: crate::table::constraint::ColumnConstraint;
+    visit_generated_column, visit_generated_column_mut: crate::table::constraint::GeneratedColumn;
+    visit_column_constraint_definition, visit_column_constraint_definition_mut: crate::table::constraint::ColumnConstraintDefinition;
+    visit_definition, visit_definition_mut: crate::table::constraint::Definition;
+    visit_create_table_statement, visit_create_table_statement_mut: crate::table::create::CreateTableStatement;
+    visit_partition_clause, visit_partition_clause_mut: crate::table::create::PartitionClause;
+    visit_partition_strategy, visit_partition_strategy_mut: crate::table::create::PartitionStrategy;
+    visit_like_clause, visit_like_clause_mut: crate::table::create::LikeClause;
+    visit_like_option, visit_like_option_mut: crate::table::create::LikeOption;
+    visit_like_property, visit_like_property_mut: crate::table::create::LikeProperty;
+    visit_subquery, visit_subquery_mut: crate::table::create::Subquery;
+    visit_table_contents_source, visit_table_contents_source_mut: crate::table::create::TableContentsSource;
+    visit_table_ref, visit_table_ref_mut: crate::table::create::TableRef;
+    visit_column_def, visit_column_def_mut: crate::table::create::ColumnDef;
+    visit_default_option, visit_default_option_mut: crate::table::create::DefaultOption;
+    visit_default_clause, visit_default_clause_mut: crate::table::create::DefaultClause;
+    visit_table_element, visit_table_element_mut: crate::table::create::TableElement;
+    visit_drop_table, visit_drop_table_mut: crate::table::drop_table::DropTable;
+    visit_drop_behavior, visit_drop_behavior_mut: crate::table::drop_table::DropBehavior;
+    visit_table, visit_table_mut: crate::table::Table;
+    visit_bind_parameter, visit_bind_parameter_mut: crate::term::bind_parameter::BindParameter;
+    visit_case_expression, visit_case_expression_mut: crate::term::case::CaseExpression;
+    visit_case, visit_case_mut: crate::term::case::Case;
+    visit_searched_case, visit_searched_case_mut: crate::term::case::SearchedCase;
+    visit_when, visit_when_mut: crate::term::case::When;
+    visit_else, visit_else_mut: crate::term::case::Else;
+    visit_column_ref, visit_column_ref_mut: crate::term::column::ColumnRef;
+    visit_term, visit_term_mut: crate::term::Term;
+    visit_value, visit_value_mut: crate::term::value::Value;
+    visit_transaction_statement, visit_transaction_statement_mut: crate::transaction::TransactionStatement;
+    visit_transaction_mode, visit_transaction_mode_mut: crate::transaction::TransactionMode;
+    visit_isolation_level, visit_isolation_level_mut: crate::transaction::IsolationLevel;
+    visit_create_trigger_statement, visit_create_trigger_statement_mut: crate::trigger::CreateTriggerStatement;
+    visit_trigger_timing, visit_trigger_timing_mut: crate::trigger::TriggerTiming;
+    visit_trigger_event, visit_trigger_event_mut: crate::trigger::TriggerEvent;
+    visit_trigger_level, visit_trigger_level_mut: crate::trigger::TriggerLevel;
+    visit_truncate_statement, visit_truncate_statement_mut: crate::truncate::TruncateStatement;
+    visit_identity_option, visit_identity_option_mut: crate::truncate::IdentityOption;
+    visit_user_defined_type_definition, visit_user_defined_type_definition_mut: crate::r#type::UserDefinedTypeDefinition;
+    visit_member, visit_member_mut: crate::r#type::Member;
+    visit_update_statement, visit_update_statement_mut: crate::update::UpdateStatement;
+    visit_update_clause, visit_update_clause_mut: crate::update::UpdateClause;
+    visit_set_clause, visit_set_clause_mut: crate::update::SetClause;
+    visit_set_expression, visit_set_expression_mut: crate::update::SetExpression;
+    visit_set_target, visit_set_target_mut: crate::update::SetTarget;
+    visit_set_value, visit_set_value_mut: crate::update::SetValue;
+}
+
+macro_rules! leaf {
+    ($($t:ty),*) => {
+        $(
+            impl Node for $t {
+                fn accept<V: Visit + ?Sized>(&self, _: &mut V) {}
+                fn accept_mut<V: VisitMut + ?Sized>(&mut self, _: &mut V) {}
+                fn walk<V: Visit + ?Sized>(&self, _: &mut V) {}
+                fn walk_mut<V: VisitMut + ?Sized>(&mut self, _: &mut V) {}
+            }
+        )*
+    };
+}
+
+leaf!(String, bool, u8, u16, i32, f32, f64);
+
+macro_rules! container {
+    ($t:ident, $it:ident, $iter:ident, $iter_mut:ident) => {
+        impl<T: Node> Node for $t<T> {
+            fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+                self.walk(visitor)
+            }
+
+            fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+                self.walk_mut(visitor)
+            }
+
+            fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+                for $it in self.$iter() {
+                    $it.accept(visitor);
+                }
+            }
+
+            fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+                for $it in self.$iter_mut() {
+                    $it.accept_mut(visitor);
+                }
+            }
+        }
+    };
+}
+
+container!(Option, node, iter, iter_mut);
+container!(Vec, node, iter, iter_mut);
+
+impl<T: Node> Node for Box<T> {
+    fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        self.as_ref().accept(visitor)
+    }
+
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.as_mut().accept_mut(visitor)
+    }
+
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        self.as_ref().walk(visitor)
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.as_mut().walk_mut(visitor)
+    }
+}
+
+impl<T: Node> Node for List<T> {
+    fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        self.walk(visitor)
+    }
+
+    fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.walk_mut(visitor)
+    }
+
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        self.0.walk(visitor)
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        self.0.walk_mut(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,68 @@
+use crate::formatter::Format;
+use crate::identifier::Name;
+use crate::query::parse_statements;
+use crate::select::from::TableName;
+use crate::term::column::ColumnRef;
+use crate::term::value::Value;
+use crate::visitor::Node;
+use crate::visitor::Visit;
+use crate::visitor::VisitMut;
+
+#[derive(Default)]
+struct Tables(Vec<String>);
+
+impl Visit for Tables {
+    fn visit_table_name(&mut self, node: &TableName) {
+        match node {
+            TableName::Name(n) | TableName::AliasedName(n, _) => self.0.push(n.to_string()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Values(usize);
+
+impl Visit for Values {
+    fn visit_value(&mut self, _: &Value) {
+        self.0 += 1
+    }
+}
+
+#[test]
+fn test_visit_table_names() {
+    let input = "SELECT m.title FROM movies AS m JOIN actors a ON a.id = m.actor_id WHERE m.id IN (SELECT movie_id FROM awards);";
+    let (_, statements) = parse_statements(input).unwrap();
+    let mut tables = Tables::default();
+    statements.accept(&mut tables);
+    assert_eq!(tables.0, vec!("movies", "actors", "awards"))
+}
+
+#[test]
+fn test_visit_values() {
+    let input = "UPDATE movies SET title = 'Matrix', year = 1999 WHERE id = 5; INSERT INTO movies VALUES (1, 'Alien');";
+    let (_, statements) = parse_statements(input).unwrap();
+    let mut values = Values::default();
+    statements.accept(&mut values);
+    assert_eq!(values.0, 5)
+}
+
+struct Qualify(&'static str);
+
+impl VisitMut for Qualify {
+    fn visit_column_ref_mut(&mut self, node: &mut ColumnRef) {
+        if let ColumnRef::Name(n) = node {
+            *node = ColumnRef::WithFamily(Name::Name(self.0.to_string()), n.clone())
+        }
+    }
+}
+
+#[test]
+fn test_visit_mut_column_refs() {
+    let input = "SELECT title, year FROM movies WHERE id = 5;";
+    let (_, mut statements) = parse_statements(input).unwrap();
+    statements.accept_mut(&mut Qualify("movies"));
+    assert_eq!(
+        statements.output(),
+        "SELECT movies.title, movies.year\n  FROM movies\n WHERE movies.id = 5;"
+    )
+}
@@ -0,0 +1,143 @@
+use sql_lint::format;
+use sql_lint::format_tolerant;
+use sql_lint::function::create::RoutineBody;
+use sql_lint::list::List;
+use sql_lint::query::parse_statements;
+use sql_lint::query::Statement;
+use sql_lint::visitor::Node;
+use sql_lint::visitor::VisitMut;
+use sql_lint::FormatOptions;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+// Set UPDATE_SNAPSHOTS=1 to (re)write the files in tests/snapshots.
+const UPDATE_SNAPSHOTS: &str = "UPDATE_SNAPSHOTS";
+
+fn examples() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().is_some_and(|e| e == "sql"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn snapshot_path(example: &Path) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(example.file_name().unwrap())
+}
+
+// SQL routine bodies are reformatted, they are compared by their formatted content.
+struct NormalizeBodies;
+
+impl VisitMut for NormalizeBodies {
+    fn visit_routine_body_mut(&mut self, node: &mut RoutineBody) {
+        let content = node
+            .format_sql(FormatOptions::default())
+            .unwrap_or_else(|| node.content().to_string());
+        *node = RoutineBody::Quoted(content);
+    }
+}
+
+fn normalized_ast(input: &str) -> List<Statement> {
+    let (_, mut ast) = parse_statements(input).unwrap();
+    ast.accept_mut(&mut NormalizeBodies);
+    ast
+}
+
+// Some examples leave out the semicolon of their last statement.
+fn read_example(example: &Path) -> String {
+    let input = fs::read_to_string(example).unwrap();
+    match input.trim_end().ends_with(';') {
+        true => input,
+        false => format!("{};", input.trim_end()),
+    }
+}
+
+fn check_example(example: &Path) -> Result<(), String> {
+    let name = example.display();
+    let input = read_example(example);
+    let formatted = format(&input).map_err(|e| format!("{}: {}", name, e))?;
+
+    if let Some(line) = formatted.lines().find(|l| l.ends_with(char::is_whitespace)) {
+        return Err(format!("{}: trailing whitespace in {:?}", name, line));
+    }
+
+    let reformatted =
+        format(&formatted).map_err(|e| format!("{}: formatted output: {}", name, e))?;
+    if reformatted != formatted {
+        return Err(format!(
+            "{}: formatting is not idempotent\n--- first pass\n{}\n--- second pass\n{}",
+            name, formatted, reformatted
+        ));
+    }
+
+    let (tolerant, warnings) = format_tolerant(&input, FormatOptions::default());
+    if tolerant != formatted || !warnings.is_empty() {
+        return Err(format!(
+            "{}: error-recovery mode differs\n--- format\n{}\n--- format_tolerant\n{}\n{:?}",
+            name, formatted, tolerant, warnings
+        ));
+    }
+
+    let ast = normalized_ast(&input);
+    let formatted_ast = normalized_ast(&formatted);
+    if ast != formatted_ast {
+        return Err(format!(
+            "{}: formatting changes the AST\n--- before\n{:?}\n--- after\n{:?}",
+            name, ast, formatted_ast
+        ));
+    }
+
+    let snapshot = snapshot_path(example);
+    let expected = format!("{}\n", formatted);
+    if env::var_os(UPDATE_SNAPSHOTS).is_some() {
+        fs::write(&snapshot, &expected).unwrap();
+        return Ok(());
+    }
+    match fs::read_to_string(&snapshot) {
+        Ok(s) if s == expected => Ok(()),
+        Ok(s) => Err(format!(
+            "{}: output differs from {}\n--- expected\n{}--- actual\n{}",
+            name,
+            snapshot.display(),
+            s,
+            expected
+        )),
+        Err(_) => Err(format!(
+            "{}: missing snapshot {}, run with {}=1",
+            name,
+            snapshot.display(),
+            UPDATE_SNAPSHOTS
+        )),
+    }
+}
+
+#[test]
+fn test_examples_round_trip() {
+    let failures: Vec<String> = examples()
+        .iter()
+        .filter_map(|e| check_example(e).err())
+        .collect();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_examples_ast_json() {
+    use sql_lint::formatter::Format;
+
+    for example in examples() {
+        let ast = sql_lint::parse(&read_example(&example)).unwrap();
+        let json = serde_json::to_string(&ast).unwrap();
+        let deserialized: List<Statement> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, ast, "{}", example.display());
+        assert_eq!(deserialized.output(), ast.output());
+    }
+}
@@ -0,0 +1,219 @@
+use proptest::prelude::*;
+use sql_lint::expression::condition;
+use sql_lint::expression::Compare;
+use sql_lint::expression::Condition;
+use sql_lint::expression::Expression;
+use sql_lint::expression::InPredicateValue;
+use sql_lint::expression::Operand;
+use sql_lint::expression::RightOperand;
+use sql_lint::expression::WhereClause;
+use sql_lint::formatter::Format;
+use sql_lint::function::AggregateFunction;
+use sql_lint::function::Function;
+use sql_lint::identifier::Name;
+use sql_lint::keyword::Keyword;
+use sql_lint::list::List;
+use sql_lint::numeric::Numeric;
+use sql_lint::select::clause::SelectClause;
+use sql_lint::select::clause::SelectedExpression;
+use sql_lint::select::clause::SetQuantifier;
+use sql_lint::select::from::TableExpression;
+use sql_lint::select::from::TableName;
+use sql_lint::select::parse_select_statement;
+use sql_lint::select::FromClause;
+use sql_lint::select::SelectStatement;
+use sql_lint::term::column::ColumnRef;
+use sql_lint::term::parse_term;
+use sql_lint::term::value::Value;
+use sql_lint::term::Term;
+use std::str::FromStr;
+
+// Strategies are boxed, the nested types overflow the test thread stack in debug builds.
+fn identifier() -> BoxedStrategy<String> {
+    "[a-z][a-z0-9_]{0,7}"
+        .prop_filter("keywords are not identifiers", |s| {
+            Keyword::from_str(s).is_err()
+        })
+        .boxed()
+}
+
+fn name() -> BoxedStrategy<Name> {
+    identifier().prop_map(Name::Name).boxed()
+}
+
+fn table_name() -> BoxedStrategy<Name> {
+    prop_oneof![
+        3 => name(),
+        1 => "[A-Za-z][A-Za-z0-9]{0,7}".prop_map(Name::QuotedName),
+    ]
+    .boxed()
+}
+
+fn numeric() -> BoxedStrategy<Numeric> {
+    prop_oneof![
+        any::<i32>().prop_map(Numeric::Int),
+        (-1000i32..1000, 1u8..4).prop_map(|(i, q)| Numeric::Float(i as f32 + q as f32 / 4.0)),
+    ]
+    .boxed()
+}
+
+fn value() -> BoxedStrategy<Value> {
+    prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        numeric().prop_map(Value::Num),
+        "[a-zA-Z0-9 %_]{0,12}".prop_map(Value::String),
+    ]
+    .boxed()
+}
+
+fn column_ref() -> BoxedStrategy<ColumnRef> {
+    prop_oneof![
+        name().prop_map(ColumnRef::Name),
+        (name(), name()).prop_map(|(t, c)| ColumnRef::WithFamily(t, c)),
+    ]
+    .boxed()
+}
+
+fn simple_term() -> BoxedStrategy<Term> {
+    prop_oneof![
+        value().prop_map(Term::Value),
+        column_ref().prop_map(Term::ColumnRef)
+    ]
+    .boxed()
+}
+
+fn term() -> BoxedStrategy<Term> {
+    prop_oneof![
+        3 => simple_term(),
+        1 => Just(Term::Function(AggregateFunction::CountAll)),
+        1 => (identifier(), prop::collection::vec(simple_term(), 0..3)).prop_map(|(n, args)| {
+            Term::Function(AggregateFunction::Function(Function(n, List(args))))
+        }),
+    ]
+    .boxed()
+}
+
+fn compare() -> BoxedStrategy<Compare> {
+    prop_oneof![
+        Just(Compare::GreaterThan),
+        Just(Compare::GreaterOrEqual),
+        Just(Compare::LowerThan),
+        Just(Compare::LowerOrEqual),
+        Just(Compare::Equal),
+        Just(Compare::NotEqual),
+    ]
+    .boxed()
+}
+
+fn operand() -> BoxedStrategy<Operand> {
+    term().prop_map(Operand::Term).boxed()
+}
+
+fn right_operand() -> BoxedStrategy<RightOperand> {
+    prop_oneof![
+        (compare(), operand()).prop_map(|(c, o)| RightOperand::Compare(c, o)),
+        operand().prop_map(RightOperand::Like),
+        (operand(), operand()).prop_map(|(l, r)| RightOperand::Between(l, r)),
+        any::<bool>().prop_map(RightOperand::Null),
+        prop::collection::vec(operand(), 1..4)
+            .prop_map(|o| RightOperand::In(InPredicateValue::InValueList(List(o)))),
+    ]
+    .boxed()
+}
+
+fn condition_strategy() -> BoxedStrategy<Condition> {
+    prop_oneof![
+        operand().prop_map(Condition::Operand),
+        (operand(), right_operand()).prop_map(|(l, r)| Condition::BinaryExpression(l, r)),
+    ]
+    .boxed()
+}
+
+fn where_clause() -> BoxedStrategy<WhereClause> {
+    (
+        condition_strategy(),
+        prop::collection::vec((any::<bool>(), condition_strategy()), 0..3),
+    )
+        .prop_map(|(first, rest)| {
+            let mut expressions = vec![Expression::Condition(first)];
+            expressions.extend(rest.into_iter().map(|(and, c)| match and {
+                true => Expression::AndExpression(c),
+                false => Expression::OrExpression(c),
+            }));
+            WhereClause(List(expressions))
+        })
+        .boxed()
+}
+
+fn selected_expression() -> BoxedStrategy<SelectedExpression> {
+    prop_oneof![
+        1 => Just(SelectedExpression::All),
+        1 => name().prop_map(SelectedExpression::AllWithFamilyName),
+        4 => term().prop_map(SelectedExpression::Term),
+        2 => (term(), name())
+            .prop_map(|(t, a)| SelectedExpression::Term(Term::AliasedTerm(Box::new(t), a))),
+    ]
+    .boxed()
+}
+
+fn select_clause() -> BoxedStrategy<SelectClause> {
+    (
+        prop::option::of(prop_oneof![
+            Just(SetQuantifier::Distinct),
+            Just(SetQuantifier::All)
+        ]),
+        prop::collection::vec(selected_expression(), 1..4),
+    )
+        .prop_map(|(q, e)| SelectClause(q, None, List(e)))
+        .boxed()
+}
+
+fn from_clause() -> BoxedStrategy<FromClause> {
+    prop::collection::vec(
+        prop_oneof![
+            table_name().prop_map(TableName::Name),
+            (table_name(), name()).prop_map(|(t, a)| TableName::AliasedName(t, a)),
+        ],
+        1..3,
+    )
+    .prop_map(|t| FromClause(List(t.into_iter().map(TableExpression).collect()), None))
+    .boxed()
+}
+
+fn select_statement() -> BoxedStrategy<SelectStatement> {
+    (
+        select_clause(),
+        prop::option::of(from_clause()),
+        prop::option::of(where_clause()),
+    )
+        .prop_map(|(select, from, r#where)| SelectStatement {
+            select,
+            from,
+            r#where,
+            group_by: None,
+            order_by: None,
+            limit: None,
+        })
+        .boxed()
+}
+
+proptest! {
+    #[test]
+    fn test_term_round_trip(t in term()) {
+        let output = t.output();
+        prop_assert_eq!(parse_term(&output), Ok(("", t)), "{}", output);
+    }
+
+    #[test]
+    fn test_condition_round_trip(c in condition_strategy()) {
+        let output = c.output();
+        prop_assert_eq!(condition(&output), Ok(("", c)), "{}", output);
+    }
+
+    #[test]
+    fn test_select_statement_round_trip(s in select_statement()) {
+        let output = s.output();
+        prop_assert_eq!(parse_select_statement(&output), Ok(("", s)), "{}", output);
+    }
+}
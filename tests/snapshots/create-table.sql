@@ -0,0 +1,7 @@
+CREATE TABLE staff (
+        PRIMARY KEY(staff_num),
+        staff_num INT(5) NOT NULL,
+        first_name VARCHAR(100) NOT NULL,
+        pens_in_drawer INT(2) NOT NULL,
+        CONSTRAINT pens_in_drawer_range CHECK(pens_in_drawer BETWEEN 1 AND 99)
+);
@@ -0,0 +1,5 @@
+CREATE TYPE public.status AS ENUM (
+    'candidate',
+    'deprecated',
+    'accepted'
+);
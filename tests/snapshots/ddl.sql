@@ -0,0 +1,19 @@
+CREATE EXTENSION IF NOT EXISTS pgcrypto WITH SCHEMA public;
+
+CREATE SCHEMA IF NOT EXISTS staging AUTHORIZATION admin;
+
+COMMENT ON TABLE movies IS 'All the movies';
+
+COMMENT ON COLUMN public.movies.title IS 'Original title';
+
+TRUNCATE movies, ratings RESTART IDENTITY CASCADE;
+
+ALTER TABLE movies RENAME TO films;
+
+ALTER SEQUENCE movie_ids OWNER TO admin;
+
+CREATE DOMAIN postcode AS VARCHAR(5) DEFAULT '00000' CONSTRAINT valid_postcode CHECK(length(value) = 5) NOT NULL;
+
+DROP EXTENSION IF EXISTS pgcrypto;
+
+DROP SCHEMA staging CASCADE;
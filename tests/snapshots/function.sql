@@ -0,0 +1,29 @@
+CREATE OR REPLACE FUNCTION add_numbers(a INTEGER, b INTEGER DEFAULT 0)
+RETURNS INTEGER
+LANGUAGE sql
+IMMUTABLE
+AS $$
+SELECT a
+  FROM movies
+ WHERE id = b;
+$$;
+
+CREATE FUNCTION touch()
+RETURNS TRIGGER
+LANGUAGE plpgsql
+AS $body$
+BEGIN
+  NEW.updated_at := now();
+  RETURN NEW;
+END;
+$body$;
+
+CREATE PROCEDURE archive(IN days INTEGER)
+LANGUAGE sql
+AS $$ delete from movies where age > days $$;
+
+CREATE TRIGGER touch_movies
+BEFORE INSERT OR UPDATE OF title ON movies
+FOR EACH ROW
+WHEN (new.title IS NOT NULL)
+EXECUTE FUNCTION touch();
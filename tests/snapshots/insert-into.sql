@@ -0,0 +1,3 @@
+INSERT INTO albums (title, release_date, recording_date)
+VALUES ('Charcoal Lane',  '1990-01-01 01:01:01.00000', '1990-01-01 01:01:01.00000'),
+       ('The New Danger', '2008-01-01 01:01:01.00000', '1990-01-01 01:01:01.00000');
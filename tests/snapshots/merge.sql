@@ -0,0 +1,9 @@
+MERGE INTO customers AS c
+USING new_customers AS n
+   ON c.id = n.id
+  AND c.region = n.region
+ WHEN MATCHED AND n.deleted = true THEN DELETE
+ WHEN MATCHED THEN UPDATE
+  SET name = n.name,
+      email = n.email
+ WHEN NOT MATCHED THEN INSERT (id, name) VALUES (n.id, n.name);
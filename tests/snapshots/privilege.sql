@@ -0,0 +1,21 @@
+CREATE ROLE reader WITH LOGIN PASSWORD 'secret';
+
+GRANT SELECT, INSERT
+   ON TABLE movies
+   TO reader
+ WITH GRANT OPTION;
+
+GRANT USAGE
+   ON ALL SEQUENCES IN SCHEMA public
+   TO PUBLIC;
+
+REVOKE ALL PRIVILEGES
+    ON movies
+  FROM reader CASCADE;
+
+ALTER DEFAULT PRIVILEGES IN SCHEMA public
+GRANT SELECT
+   ON TABLES
+   TO reader;
+
+DROP ROLE IF EXISTS reader;
@@ -0,0 +1,2 @@
+SELECT to_date('hello', 'world')
+  FROM users;
@@ -0,0 +1,4 @@
+SELECT first_name AS fn
+  FROM staff AS s1
+  JOIN students AS s2
+       ON s2.mentor_id = s1.staff_num;
@@ -0,0 +1,3 @@
+SELECT file_hash
+  FROM file_system
+ WHERE filename = '.vimrc';
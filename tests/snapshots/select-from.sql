@@ -0,0 +1,2 @@
+SELECT file__Hash
+  FROM filesystem;
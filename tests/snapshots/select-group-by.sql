@@ -0,0 +1,6 @@
+SELECT f.species_name, AVG(f.height) AS average_height, AVG(f.diameter) AS average_diameter
+  FROM flora AS f
+ WHERE f.species_name = 'Banksia'
+    OR f.species_name = 'Sheoak'
+    OR f.species_name = 'Wattle'
+ GROUP BY f.species_name, f.observation_date;
@@ -0,0 +1,5 @@
+SELECT COUNT(CustomerID), Country
+  FROM Customers
+ GROUP BY Country
+HAVING COUNT(CustomerID) > 5
+ ORDER BY country;
@@ -0,0 +1,4 @@
+SELECT a.title, a.release_date, a.recording_date
+  FROM albums AS a
+ WHERE a.title = 'Charcoal Lane'
+    OR a.title = 'The New Danger';
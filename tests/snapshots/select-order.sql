@@ -0,0 +1,3 @@
+SELECT to_date('hello', 'world')
+  FROM users
+ ORDER BY age DESC;
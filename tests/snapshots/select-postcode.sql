@@ -0,0 +1,8 @@
+SELECT CASE postcode
+       WHEN 'BN1' THEN 'Brighton'
+       WHEN 'EH1' THEN 'Edinburgh'
+       END AS city
+  FROM office_locations
+ WHERE country = 'United Kingdom'
+   AND opening_time BETWEEN 8 AND 9
+   AND postcode IN ('EH1', 'BN1', 'NN1', 'KW1');
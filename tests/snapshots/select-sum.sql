@@ -0,0 +1,2 @@
+SELECT SUM(s.monitor_tally) AS monitor_total
+  FROM staff AS s;
@@ -0,0 +1,18 @@
+(SELECT title, year
+   FROM movies
+  WHERE year > 2000
+  ORDER BY year
+ LIMIT 10)
+
+ UNION
+
+(SELECT title, year
+   FROM series
+  WHERE year > 2000
+
+  UNION
+
+ SELECT title, year
+   FROM shorts
+  WHERE year > 2010)
+ ORDER BY title;
@@ -0,0 +1,3 @@
+SELECT model_num
+  FROM phones AS p
+ WHERE p.release_date > '2014-09-30';
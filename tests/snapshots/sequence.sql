@@ -0,0 +1,6 @@
+CREATE SEQUENCE public.test_suites_id_seq
+    START WITH 1
+    INCREMENT BY 1
+    NO MINVALUE
+    NO MAXVALUE
+    CACHE 1;
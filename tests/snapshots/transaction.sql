@@ -0,0 +1,8 @@
+BEGIN;
+
+SET LOCAL search_path TO public;
+
+UPDATE t
+   SET a = 1;
+
+COMMIT;
@@ -0,0 +1,4 @@
+UPDATE file_system
+   SET file_modified_date = '1980-02-22 13:19:01.00000',
+       file_size = 209732
+ WHERE file_name = '.vimrc';